@@ -0,0 +1,139 @@
+// bindings/node/src/lib.rs
+//! Node.js bindings (napi-rs) for the TSQC kernel.
+//!
+//! ```js
+//! const { Graph } = require('tsqc');
+//! const g = Graph.fromDimacs('g.clq');
+//! const r = await g.solveMax(0.9, 1);   // { size, density, vertices }
+//! ```
+//!
+//! The solvers run on the libuv thread pool and return Promises, so the
+//! event loop keeps going during a solve.  Seeds are `u32` (JavaScript
+//! numbers); vertex ids are 0-based.
+
+use napi::{bindgen_prelude::AsyncTask, Env, Error, Result, Task};
+use napi_derive::napi;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use std::sync::Arc;
+use tsqc::{solve_fixed_k, solve_maxk, Params, Solution};
+
+fn to_js_err(e: std::io::Error) -> Error {
+    Error::from_reason(e.to_string())
+}
+
+fn check_gamma(gamma: f64) -> Result<Params> {
+    if gamma > 0.0 && gamma <= 1.0 {
+        Ok(Params { gamma_target: gamma, ..Params::default() })
+    } else {
+        Err(Error::from_reason(format!("gamma must be in (0, 1], got {gamma}")))
+    }
+}
+
+/// Set found by a solver.
+#[napi(object)]
+pub struct SolveResult {
+    pub size:     u32,
+    pub density:  f64,
+    /// 0-based, ascending.
+    pub vertices: Vec<u32>,
+}
+
+impl From<&Solution<'_>> for SolveResult {
+    fn from(sol: &Solution<'_>) -> Self {
+        Self {
+            size:     sol.size() as u32,
+            density:  sol.density(),
+            vertices: sol.bitset().iter_ones().map(|v| v as u32).collect(),
+        }
+    }
+}
+
+/// A solve run off the main thread; `k` = None for max-k.
+pub struct Solve {
+    graph: Arc<tsqc::Graph>,
+    k:     Option<usize>,
+    p:     Params,
+    seed:  u32,
+}
+
+impl Task for Solve {
+    type Output = SolveResult;
+    type JsValue = SolveResult;
+
+    fn compute(&mut self) -> Result<SolveResult> {
+        let mut rng = ChaCha8Rng::seed_from_u64(self.seed as u64);
+        let sol = match self.k {
+            Some(k) => solve_fixed_k(&self.graph, k, &mut rng, &self.p),
+            None => solve_maxk(&self.graph, &mut rng, &self.p),
+        };
+        Ok((&sol).into())
+    }
+
+    fn resolve(&mut self, _env: Env, output: SolveResult) -> Result<SolveResult> {
+        Ok(output)
+    }
+}
+
+/// Parsed graph, kept on the Rust side between calls.
+#[napi]
+pub struct Graph {
+    inner: Arc<tsqc::Graph>,
+}
+
+#[napi]
+impl Graph {
+    /// Parse a DIMACS file.
+    #[napi(factory)]
+    pub fn from_dimacs(path: String) -> Result<Self> {
+        Ok(Self { inner: Arc::new(tsqc::Graph::read_dimacs_file(path).map_err(to_js_err)?) })
+    }
+
+    /// Load a NumPy `.npy` / `.npz` adjacency or edge array.
+    #[napi(factory)]
+    pub fn from_numpy(path: String) -> Result<Self> {
+        Ok(Self { inner: Arc::new(tsqc::Graph::load_numpy(path).map_err(to_js_err)?) })
+    }
+
+    /// Graph on `n` vertices from `[u, v]` pairs (0-based).
+    #[napi(factory)]
+    pub fn from_edges(n: u32, edges: Vec<Vec<u32>>) -> Result<Self> {
+        let mut pairs = Vec::with_capacity(edges.len());
+        for e in &edges {
+            match e.as_slice() {
+                &[u, v] if u < n && v < n => pairs.push((u as usize, v as usize)),
+                _ => return Err(Error::from_reason(format!("invalid edge {e:?} for n = {n}"))),
+            }
+        }
+        Ok(Self { inner: Arc::new(tsqc::Graph::from_edge_list(n as usize, &pairs)) })
+    }
+
+    #[napi(getter)]
+    pub fn n(&self) -> u32 {
+        self.inner.n() as u32
+    }
+
+    #[napi(getter)]
+    pub fn m(&self) -> u32 {
+        self.inner.m() as u32
+    }
+
+    /// Best γ-quasi-clique of size `k` (a Promise).
+    #[napi(ts_return_type = "Promise<SolveResult>")]
+    pub fn solve_k(&self, k: u32, gamma: f64, seed: Option<u32>) -> Result<AsyncTask<Solve>> {
+        let p = check_gamma(gamma)?;
+        if k < 2 || k as usize > self.inner.n() {
+            return Err(Error::from_reason(format!("k must be in 2..={}, got {k}", self.inner.n())));
+        }
+        let graph = Arc::clone(&self.inner);
+        Ok(AsyncTask::new(Solve { graph, k: Some(k as usize), p, seed: seed.unwrap_or(0) }))
+    }
+
+    /// Largest γ-quasi-clique found (a Promise).
+    #[napi(ts_return_type = "Promise<SolveResult>")]
+    pub fn solve_max(&self, gamma: f64, seed: Option<u32>) -> Result<AsyncTask<Solve>> {
+        let p = check_gamma(gamma)?;
+        let graph = Arc::clone(&self.inner);
+        Ok(AsyncTask::new(Solve { graph, k: None, p, seed: seed.unwrap_or(0) }))
+    }
+}
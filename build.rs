@@ -0,0 +1,24 @@
+// build.rs
+//! Embeds the git commit (`TSQC_GIT_HASH`, "-dirty" with local changes,
+//! "unknown" outside a checkout) for the reproducibility manifest.
+
+use std::process::Command;
+
+fn git(args: &[&str]) -> Option<String> {
+    let out = Command::new("git").args(args).output().ok()?;
+    out.status.success().then(|| String::from_utf8_lossy(&out.stdout).trim().to_string())
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=.git/HEAD");
+    println!("cargo:rerun-if-changed=.git/index");
+    println!("cargo:rerun-if-changed=.git/refs");
+    let hash = match git(&["rev-parse", "--short=12", "HEAD"]) {
+        Some(h) if git(&["status", "--porcelain", "--untracked-files=no"]).is_some_and(|s| !s.is_empty()) => {
+            format!("{h}-dirty")
+        }
+        Some(h) => h,
+        None => "unknown".to_string(),
+    };
+    println!("cargo:rustc-env=TSQC_GIT_HASH={hash}");
+}
@@ -0,0 +1,23 @@
+//! Arbitrary bytes through the DIMACS parsers: no panics, and whatever
+//! parses is a simple undirected graph on which both parsers agree.
+//!
+//!     cargo +nightly fuzz run parse_dimacs
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use tsqc::{parse, Layout};
+
+fuzz_target!(|data: &[u8]| {
+    // huge vertex ids must fail cleanly, not exhaust memory
+    tsqc::set_memory_limit(Some(1 << 24));
+    let Ok(rows) = parse::parse_dimacs_bytes_with(data, Layout::Rows) else { return };
+    let tri = parse::parse_dimacs_bytes_with(data, Layout::Triangular).expect("rows parsed");
+    assert_eq!(rows.n(), tri.n());
+    let edges = rows.edge_list();
+    assert_eq!(edges, tri.edge_list());
+    assert_eq!(edges.len(), rows.m());
+    for &(u, v) in &edges {
+        assert!(u < v && v < rows.n());
+        assert!(rows.has_edge(v, u));
+    }
+});
@@ -0,0 +1,49 @@
+//! Random graphs and move sequences: `Solution`'s cached size, edge
+//! count, hash and internal degrees must match recounts after every move.
+//!
+//!     cargo +nightly fuzz run solution_moves
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use tsqc::{edges_of, Graph, Solution};
+
+fuzz_target!(|data: &[u8]| {
+    let Some((&n, rest)) = data.split_first() else { return };
+    let n = (n as usize % 48) + 1;
+    let (graph_bytes, moves) = rest.split_at(rest.len() / 2);
+    let edges: Vec<(usize, usize)> = graph_bytes
+        .chunks_exact(2)
+        .map(|p| (p[0] as usize % n, p[1] as usize % n))
+        .filter(|e| e.0 != e.1)
+        .collect();
+    let g = Graph::from_edge_list(n, &edges);
+
+    let mut sol = Solution::new(&g);
+    for &b in moves {
+        let v = (b >> 3) as usize % n;
+        match b & 7 {
+            0 | 1 => sol.add(v),
+            2 => sol.remove(v),
+            3 => {
+                sol.toggle(v);
+            }
+            4 => sol.checkpoint(),
+            5 => {
+                sol.rollback();
+            }
+            6 => {
+                sol.commit();
+            }
+            _ => sol.track_frontier(),
+        }
+        let members: Vec<usize> = sol.bitset().iter_ones().collect();
+        assert_eq!(sol.size(), members.len());
+        assert_eq!(sol.edges(), edges_of(&g, &members));
+        let fresh = Solution::from_bitset(&g, sol.bitset());
+        assert_eq!(sol.zobrist(), fresh.zobrist());
+        let degrees = sol.internal_degrees();
+        for (w, &d) in degrees.iter().enumerate() {
+            assert_eq!(d as usize, members.iter().filter(|&&u| g.has_edge(u, w)).count());
+        }
+    }
+});
@@ -0,0 +1,528 @@
+// src/bin/tsqc/main.rs
+//! Command-line front end.
+//!
+//! ```text
+//! tsqc GRAPH --gamma G [--min-degree θ] [--costs FILE --budget B] [--k K]
+//!      [--seed S | --seeds A..B [--aggregate] [--parallel]]
+//!      [--emit-dot FILE] [--emit-graphml FILE] [--neighbourhood] [--manifest FILE]
+//!      [--no-cache]
+//!      [--threads N] [--mem-limit SIZE] [--layout auto|rows|triangular|csr]
+//!      [--scan full|frontier|sampled:N|auto] [--rng chacha8|xoshiro]
+//!      [-q | -v | -vv] [--log-format text|json]
+//! tsqc serve [--addr HOST:PORT]
+//! ```
+//!
+//! GRAPH is a DIMACS file, or a NumPy `.npy` / `.npz` array.  Without
+//! `--k` the maximum quasi-clique is searched.  `--min-degree θ` also
+//! requires ⌈θ(k−1)⌉ neighbours inside the set for every member.
+//! `--costs` reads one vertex cost per line (vertex order, `#` comments)
+//! and `--budget B` caps their sum over the set.  `--seeds` repeats the run
+//! for every seed of an inclusive range (or a comma list); `--aggregate`
+//! replaces the per-seed lines by min / median / max density, the success
+//! rate at γ and time-to-target statistics.  Exports use the best run.
+//! `--manifest` writes one JSON line per run: its result plus the crate
+//! version, git commit, full params, seed and graph fingerprint.
+//!
+//! Results are cached on disk (`$TSQC_CACHE_DIR`, else the user cache
+//! directory) under a hash of graph, params, seed and k, so a repeated
+//! invocation returns the stored set at once.  `--no-cache` always
+//! solves; `--aggregate` runs never use the cache.
+//!
+//! `--scan` picks the outsiders each swap step considers: all (default),
+//! the frontier N(S), N random ones, or `auto` (full on small graphs,
+//! frontier or samples on large ones depending on |N(S)| and step time).
+//!
+//! `--rng xoshiro` swaps the default ChaCha8 generator for the faster
+//! xoshiro256++ (also reproducible, but a given seed gives another run).
+//!
+//! `--threads` sizes the solver's thread pool (default: physical cores).  `--mem-limit` (bytes, or with a
+//! K/M/G suffix) sizes the adjacency matrix from the DIMACS header before
+//! anything is read and refuses inputs that would not fit, pointing to the
+//! half-size triangular layout when that one would.  `--layout auto` (the
+//! default) keeps large sparse graphs in neighbour lists (`csr`) and the
+//! rest in the row matrix.
+//!
+//! Improvements and restarts are logged to stderr through `tracing`:
+//! warnings only by default, improvements with `-v`, restarts too with
+//! `-vv`, errors only with `-q`; `--log-format json` writes one JSON
+//! object per event.
+//!
+//! `tsqc serve` (built with the `serve` feature) runs an HTTP job server
+//! instead; see `serve.rs` for the routes.  It listens on 127.0.0.1:8080
+//! unless `--addr` says otherwise.
+
+#[cfg(feature = "serve")]
+mod serve;
+
+use rayon::prelude::*;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+use tracing::level_filters::LevelFilter;
+use tsqc::observer::LogObserver;
+use tsqc::{export, instance_key, parse, solve_fixed_k_with, solve_maxk_with, Budget, Graph, Found, Layout, Manifest, Observer, Params, ResultCache, RunCounter, RngKind, Scan, Solution, SolverRng};
+
+const USAGE: &str = "usage: tsqc GRAPH --gamma G [--min-degree THETA] [--costs FILE --budget B] [--k K] \
+[--seed S | --seeds A..B [--aggregate] [--parallel]] \
+[--emit-dot FILE] [--emit-graphml FILE] [--neighbourhood] [--manifest FILE] [--no-cache] \
+[--threads N] [--mem-limit SIZE] [--layout auto|rows|triangular|csr] \
+[--scan full|frontier|sampled:N|auto] [--rng chacha8|xoshiro] \
+[-q | -v | -vv] [--log-format text|json]
+       tsqc serve [--addr HOST:PORT]";
+
+/// Parsed command line.
+#[derive(Debug, Default)]
+struct Args {
+    graph:         PathBuf,
+    gamma:         f64,
+    min_degree:    f64,
+    costs:         Option<PathBuf>,
+    budget:        Option<f64>,
+    k:             Option<usize>,
+    seeds:         Vec<u64>,
+    aggregate:     bool,
+    parallel:      bool,
+    emit_dot:      Option<PathBuf>,
+    emit_graphml:  Option<PathBuf>,
+    neighbourhood: bool,
+    manifest:      Option<PathBuf>,
+    no_cache:      bool,
+    threads:       Option<usize>,
+    mem_limit:     Option<usize>,
+    /// `None`: [`Layout::auto`].
+    layout:        Option<Layout>,
+    scan:          Scan,
+    rng:           RngKind,
+    /// -1 = quiet, 0 = warnings, 1 = info, 2 = debug.
+    verbosity:     i8,
+    json_logs:     bool,
+}
+
+impl Args {
+    fn parse<I: Iterator<Item = String>>(mut it: I) -> Result<Self, String> {
+        let mut args = Args::default();
+        let mut graph = None;
+        let mut gamma = None;
+        while let Some(arg) = it.next() {
+            let mut value = |name: &str| it.next().ok_or(format!("{name} needs a value"));
+            match arg.as_str() {
+                "--gamma" => gamma = Some(parse_num(&value("--gamma")?, "--gamma")?),
+                "--min-degree" => args.min_degree = parse_num(&value("--min-degree")?, "--min-degree")?,
+                "--costs" => args.costs = Some(value("--costs")?.into()),
+                "--budget" => args.budget = Some(parse_num(&value("--budget")?, "--budget")?),
+                "--k" => args.k = Some(parse_num(&value("--k")?, "--k")?),
+                "--seed" => args.seeds = vec![parse_num(&value("--seed")?, "--seed")?],
+                "--seeds" => args.seeds = parse_seeds(&value("--seeds")?)?,
+                "--aggregate" => args.aggregate = true,
+                "--parallel" => args.parallel = true,
+                "--emit-dot" => args.emit_dot = Some(value("--emit-dot")?.into()),
+                "--emit-graphml" => args.emit_graphml = Some(value("--emit-graphml")?.into()),
+                "--neighbourhood" | "--neighborhood" => args.neighbourhood = true,
+                "--manifest" => args.manifest = Some(value("--manifest")?.into()),
+                "--no-cache" => args.no_cache = true,
+                "--threads" => args.threads = Some(parse_num(&value("--threads")?, "--threads")?),
+                "--mem-limit" => args.mem_limit = Some(parse_size(&value("--mem-limit")?)?),
+                "--layout" => {
+                    args.layout = match value("--layout")?.as_str() {
+                        "auto" => None,
+                        "rows" => Some(Layout::Rows),
+                        "triangular" => Some(Layout::Triangular),
+                        "csr" => Some(Layout::Csr),
+                        other => return Err(format!("--layout: unknown layout '{other}'")),
+                    }
+                }
+                "--scan" => {
+                    args.scan = match value("--scan")?.as_str() {
+                        "full" => Scan::Full,
+                        "frontier" => Scan::Frontier,
+                        "auto" => Scan::Auto,
+                        other => match other.strip_prefix("sampled:") {
+                            Some(size) => Scan::Sampled(parse_num(size, "--scan")?),
+                            None => return Err(format!("--scan: unknown mode '{other}'")),
+                        },
+                    }
+                }
+                "--rng" => {
+                    args.rng = match value("--rng")?.as_str() {
+                        "chacha8" => RngKind::ChaCha8,
+                        "xoshiro" => RngKind::Xoshiro,
+                        other => return Err(format!("--rng: unknown generator '{other}'")),
+                    }
+                }
+                "-q" | "--quiet" => args.verbosity = -1,
+                "-v" => args.verbosity = args.verbosity.max(0) + 1,
+                "-vv" => args.verbosity = args.verbosity.max(0) + 2,
+                "--log-format" => {
+                    args.json_logs = match value("--log-format")?.as_str() {
+                        "text" => false,
+                        "json" => true,
+                        other => return Err(format!("--log-format: unknown format '{other}'")),
+                    }
+                }
+                "-h" | "--help" => return Err(USAGE.to_string()),
+                flag if flag.starts_with('-') => return Err(format!("unknown option '{flag}'")),
+                path if graph.is_none() => graph = Some(PathBuf::from(path)),
+                extra => return Err(format!("unexpected argument '{extra}'")),
+            }
+        }
+        args.graph = graph.ok_or("missing GRAPH")?;
+        args.gamma = gamma.ok_or("missing --gamma")?;
+        if !(args.gamma > 0.0 && args.gamma <= 1.0) {
+            return Err(format!("--gamma must be in (0, 1], got {}", args.gamma));
+        }
+        if !(0.0..=1.0).contains(&args.min_degree) {
+            return Err(format!("--min-degree must be in [0, 1], got {}", args.min_degree));
+        }
+        if args.costs.is_some() != args.budget.is_some() {
+            return Err("--costs and --budget go together".to_string());
+        }
+        if args.seeds.is_empty() {
+            args.seeds.push(0);
+        }
+        Ok(args)
+    }
+}
+
+fn parse_num<T: std::str::FromStr>(s: &str, name: &str) -> Result<T, String> {
+    s.parse().map_err(|_| format!("{name}: invalid value '{s}'"))
+}
+
+/// Byte count with an optional K / M / G (binary) suffix.
+fn parse_size(s: &str) -> Result<usize, String> {
+    let upper = s.trim().to_ascii_uppercase();
+    let body = upper.strip_suffix('B').unwrap_or(&upper);
+    let (digits, shift) = match body.chars().last() {
+        Some('K') => (&body[..body.len() - 1], 10),
+        Some('M') => (&body[..body.len() - 1], 20),
+        Some('G') => (&body[..body.len() - 1], 30),
+        _ => (body, 0),
+    };
+    let n: usize = parse_num(digits, "--mem-limit")?;
+    n.checked_mul(1 << shift).ok_or(format!("--mem-limit: '{s}' is too large"))
+}
+
+/// `A..B` (inclusive) or `a,b,c`.
+fn parse_seeds(s: &str) -> Result<Vec<u64>, String> {
+    let seeds: Vec<u64> = match s.split_once("..") {
+        Some((a, b)) => {
+            let a: u64 = parse_num(a, "--seeds")?;
+            let b: u64 = parse_num(b.trim_start_matches('='), "--seeds")?;
+            (a..=b).collect()
+        }
+        None => s.split(',').map(|x| parse_num(x.trim(), "--seeds")).collect::<Result<_, _>>()?,
+    };
+    if seeds.is_empty() {
+        return Err(format!("--seeds: empty range '{s}'"));
+    }
+    Ok(seeds)
+}
+
+/// Remembers when the incumbent last improved.
+struct LastImprovement {
+    started: Instant,
+    at:      Duration,
+}
+
+impl Observer for LastImprovement {
+    fn on_incumbent(&mut self, _sol: &Solution<'_>) {
+        self.at = self.started.elapsed();
+    }
+}
+
+/// One seed's result.
+struct Outcome {
+    seed:     u64,
+    members:  Vec<usize>,
+    density:  f64,
+    feasible: bool,
+    seconds:  f64,
+    /// Time until the returned set was found (feasible runs only).
+    to_target: Option<f64>,
+    manifest:  Manifest,
+    /// [`Found`] summary printed by default.
+    summary:   String,
+}
+
+fn solve_one(graph: &Graph, args: &Args, budget: Option<&Budget>, cache: Option<&ResultCache>, seed: u64) -> Outcome {
+    let p = Params {
+        gamma_target: args.gamma,
+        min_degree_ratio: args.min_degree,
+        budget: budget.cloned(),
+        scan: args.scan,
+        rng: args.rng,
+        ..Params::default()
+    };
+    let mut rng = SolverRng::seeded(p.rng, seed);
+    let clock = LastImprovement { started: Instant::now(), at: Duration::ZERO };
+    let span = tracing::info_span!("run", seed);
+    let _guard = span.enter();
+    let mut obs = (clock, (LogObserver, RunCounter::start(None)));
+    let mut solve = || {
+        let sol = match args.k {
+            Some(k) => solve_fixed_k_with(graph, k, &mut rng, &p, &mut obs),
+            None => solve_maxk_with(graph, &mut rng, &p, &mut obs),
+        };
+        (sol, true)
+    };
+    let (sol, cached) = match cache {
+        Some(c) => c.get_or_solve(graph, instance_key(graph, &p, seed, args.k), solve),
+        None => (solve().0, false),
+    };
+    let (clock, (_, counter)) = obs;
+    let manifest = Manifest::new(graph, &p, seed, 0);
+    let report = counter.report(&sol, &p, manifest.clone());
+    let feasible = report.feasible;
+    let mut summary = Found { solution: sol.clone(), report }.to_string();
+    if cached {
+        summary.push_str("\n(cached result)");
+    }
+    Outcome {
+        seed,
+        members: sol.bitset().iter_ones().collect(),
+        density: sol.density(),
+        feasible,
+        seconds: clock.started.elapsed().as_secs_f64(),
+        to_target: feasible.then_some(clock.at.as_secs_f64()),
+        manifest,
+        summary,
+    }
+}
+
+/// Median of a non-empty slice (sorted in place).
+fn median(xs: &mut [f64]) -> f64 {
+    xs.sort_by(f64::total_cmp);
+    let mid = xs.len() / 2;
+    if xs.len() % 2 == 1 { xs[mid] } else { (xs[mid - 1] + xs[mid]) / 2.0 }
+}
+
+fn print_aggregate(args: &Args, runs: &[Outcome]) {
+    let mut dens: Vec<f64> = runs.iter().map(|r| r.density).collect();
+    let sizes: Vec<usize> = runs.iter().map(|r| r.members.len()).collect();
+    let succ = runs.iter().filter(|r| r.feasible).count();
+    println!("runs     {}", runs.len());
+    println!(
+        "size     min {} / max {}",
+        sizes.iter().min().unwrap(),
+        sizes.iter().max().unwrap()
+    );
+    let med = median(&mut dens);
+    println!("density  min {:.6} / median {med:.6} / max {:.6}", dens[0], dens[dens.len() - 1]);
+    println!(
+        "success  {succ}/{} ({:.1}%) at gamma {}",
+        runs.len(),
+        100.0 * succ as f64 / runs.len() as f64,
+        args.gamma
+    );
+    let mut ttt: Vec<f64> = runs.iter().filter_map(|r| r.to_target).collect();
+    if !ttt.is_empty() {
+        let mean = ttt.iter().sum::<f64>() / ttt.len() as f64;
+        let med = median(&mut ttt);
+        println!(
+            "ttt      min {:.3}s / median {med:.3}s / mean {mean:.3}s / max {:.3}s",
+            ttt[0],
+            ttt[ttt.len() - 1]
+        );
+    }
+}
+
+/// Refuse a DIMACS input whose file plus adjacency would exceed `limit`.
+fn check_memory(path: &Path, layout: Option<Layout>, limit: usize) -> io::Result<()> {
+    let Some((n, m)) = parse::peek_dimacs_header(path)? else {
+        return Ok(()); // no header: size unknown up front
+    };
+    let layout = layout.unwrap_or_else(|| Layout::auto(n, m));
+    let file = std::fs::metadata(path)?.len() as usize;
+    let need = file + Graph::estimated_bytes(n, m, layout);
+    if need <= limit {
+        return Ok(());
+    }
+    let mib = |b: usize| b as f64 / (1u64 << 20) as f64;
+    let mut msg = format!(
+        "n = {n} needs about {:.1} MiB ({layout:?} adjacency + file), over the {:.1} MiB limit",
+        mib(need),
+        mib(limit)
+    );
+    if layout != Layout::Csr && file + Graph::estimated_bytes(n, m, Layout::Csr) <= limit {
+        msg.push_str("; retry with --layout csr");
+    } else if layout == Layout::Rows && file + Graph::estimated_bytes(n, m, Layout::Triangular) <= limit {
+        msg.push_str("; retry with --layout triangular");
+    }
+    Err(io::Error::new(io::ErrorKind::OutOfMemory, msg))
+}
+
+/// One cost per line, checked against the `n` vertices by [`Budget::check`].
+fn read_costs(path: &Path, n: usize, limit: f64) -> io::Result<Budget> {
+    let invalid = |msg: String| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {msg}", path.display()));
+    let costs: Vec<f64> = std::fs::read_to_string(path)?
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .map(|l| l.parse::<f64>().map_err(|_| invalid(format!("invalid cost '{l}'"))))
+        .collect::<io::Result<_>>()?;
+    let budget = Budget { costs, limit };
+    budget.check(n).map_err(|e| invalid(e.to_string()))?;
+    Ok(budget)
+}
+
+/// DIMACS, or NumPy by extension.
+fn load(path: &Path, layout: Option<Layout>) -> io::Result<Graph> {
+    match path.extension().and_then(|e| e.to_str()) {
+        Some(ext) if ext.eq_ignore_ascii_case("npy") || ext.eq_ignore_ascii_case("npz") => {
+            Graph::load_numpy(path)
+        }
+        _ => match layout {
+            Some(layout) => Graph::read_dimacs_file_with(path, layout),
+            None => Graph::read_dimacs_file(path),
+        },
+    }
+}
+
+fn run(args: &Args) -> io::Result<()> {
+    if let Some(n) = args.threads {
+        tsqc::set_threads(n).map_err(io::Error::other)?;
+    }
+    if let Some(limit) = args.mem_limit {
+        tsqc::set_memory_limit(Some(limit));
+        check_memory(&args.graph, args.layout, limit)?;
+    }
+    let graph = load(&args.graph, args.layout)?;
+    if let Some(k) = args.k.filter(|&k| k < 2 || k > graph.n()) {
+        let msg = format!("--k must be in 2..={}, got {k}", graph.n());
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, msg));
+    }
+    let budget = match (&args.costs, args.budget) {
+        (Some(path), Some(limit)) => Some(read_costs(path, graph.n(), limit)?),
+        _ => None,
+    };
+    let budget = budget.as_ref();
+    // timing statistics need real runs
+    let cache = (!args.no_cache && !args.aggregate).then(ResultCache::default_dir).flatten().map(ResultCache::new);
+    let cache = cache.as_ref();
+    let runs: Vec<Outcome> = if args.parallel {
+        tsqc::threads::install(0, || args.seeds.par_iter().map(|&s| solve_one(&graph, args, budget, cache, s)).collect())
+    } else {
+        args.seeds.iter().map(|&s| solve_one(&graph, args, budget, cache, s)).collect()
+    };
+
+    if args.aggregate {
+        print_aggregate(args, &runs);
+    } else {
+        for r in &runs {
+            if runs.len() > 1 {
+                println!("seed     {}", r.seed);
+            }
+            println!("{}", r.summary);
+            println!(
+                "vertices {}",
+                r.members.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(" ")
+            );
+        }
+    }
+
+    // exports use the best run (largest, then densest)
+    let best = runs
+        .iter()
+        .max_by(|a, b| (a.members.len(), a.density).partial_cmp(&(b.members.len(), b.density)).unwrap())
+        .unwrap();
+    let members = &best.members;
+
+    if let Some(path) = &args.emit_dot {
+        let w = BufWriter::new(File::create(path)?);
+        export::write_dot(&graph, members, args.neighbourhood, w)?;
+    }
+    if let Some(path) = &args.emit_graphml {
+        let w = BufWriter::new(File::create(path)?);
+        export::write_graphml(&graph, members, args.neighbourhood, w)?;
+    }
+    if let Some(path) = &args.manifest {
+        let mut w = BufWriter::new(File::create(path)?);
+        for r in &runs {
+            writeln!(
+                w,
+                "{{\"seed\":{},\"size\":{},\"density\":{},\"feasible\":{},\"seconds\":{:.3},\"manifest\":{}}}",
+                r.seed,
+                r.members.len(),
+                r.density,
+                r.feasible,
+                r.seconds,
+                r.manifest.to_json()
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Install the stderr subscriber for the requested verbosity / format.
+fn init_logging(args: &Args) {
+    let level = match args.verbosity {
+        ..0 => LevelFilter::ERROR,
+        0 => LevelFilter::WARN,
+        1 => LevelFilter::INFO,
+        _ => LevelFilter::DEBUG,
+    };
+    let builder = tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_writer(io::stderr)
+        .with_ansi(io::IsTerminal::is_terminal(&io::stderr()));
+    if args.json_logs {
+        builder.json().init();
+    } else {
+        builder.init();
+    }
+}
+
+/// `tsqc serve [--addr HOST:PORT]`.
+fn serve_main<I: Iterator<Item = String>>(mut it: I) -> ExitCode {
+    let mut addr = "127.0.0.1:8080".to_string();
+    while let Some(arg) = it.next() {
+        match (arg.as_str(), it.next()) {
+            ("--addr", Some(a)) => addr = a,
+            _ => {
+                eprintln!("usage: tsqc serve [--addr HOST:PORT]");
+                return ExitCode::from(2);
+            }
+        }
+    }
+    #[cfg(feature = "serve")]
+    {
+        init_logging(&Args { verbosity: 1, ..Args::default() });
+        if let Err(e) = serve::serve(&addr) {
+            eprintln!("tsqc: {addr}: {e}");
+            return ExitCode::FAILURE;
+        }
+        ExitCode::SUCCESS
+    }
+    #[cfg(not(feature = "serve"))]
+    {
+        eprintln!("tsqc: cannot serve {addr}: built without the `serve` feature");
+        ExitCode::FAILURE
+    }
+}
+
+fn main() -> ExitCode {
+    if std::env::args().nth(1).as_deref() == Some("serve") {
+        return serve_main(std::env::args().skip(2));
+    }
+    let args = match Args::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(msg) => {
+            eprintln!("{msg}");
+            return ExitCode::from(2);
+        }
+    };
+    init_logging(&args);
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        // arguments that only turn out wrong against the graph
+        Err(e) if e.kind() == io::ErrorKind::InvalidInput => {
+            eprintln!("{e}");
+            ExitCode::from(2)
+        }
+        Err(e) => {
+            eprintln!("tsqc: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
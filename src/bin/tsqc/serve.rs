@@ -0,0 +1,242 @@
+// src/bin/tsqc/serve.rs
+//! `tsqc serve`: a small HTTP job server (feature `serve`).
+//!
+//! ```text
+//! POST   /graphs/NAME            body = DIMACS text   → {"name", "n", "m"}
+//! GET    /graphs                                      → [{"name", "n", "m"}, …]
+//! POST   /jobs?graph=NAME&gamma=G[&k=K][&seed=S][&time_limit=SECS]
+//!                                                     → {"id"}
+//! GET    /jobs/ID                                     → status + incumbent size
+//!                                                       (+ reproducibility manifest)
+//! GET    /jobs/ID/incumbent                           → {"size", "density", "vertices"}
+//! DELETE /jobs/ID                                     → cancel (best-so-far is kept)
+//! ```
+//!
+//! Every job runs on its own thread and reports through a [`Monitor`];
+//! polling never blocks the solver.  Responses are JSON.
+
+use std::collections::HashMap;
+use std::io::Read;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tiny_http::{Header, Method, Request, Response, Server};
+use tsqc::observer::{IterRecord, Observer, StopReason};
+use tsqc::{parse, solve_fixed_k_with, solve_maxk_with, Graph, Manifest, Monitor, Params, Solution, SolverRng};
+
+/// Shared view of one job.
+struct Job {
+    graph:     String,
+    monitor:   Monitor,
+    incumbent: Mutex<Vec<usize>>,
+    /// Stop reason and wall time once the solver returned.
+    finished:  Mutex<Option<(StopReason, f64)>>,
+    /// [`Manifest`] of the run, as JSON.
+    manifest:  String,
+}
+
+/// Observer of a running job: forwards to the monitor, keeps the incumbent
+/// vertices and enforces the optional deadline.
+struct JobObserver {
+    job:      Arc<Job>,
+    monitor:  Monitor,
+    deadline: Option<Instant>,
+}
+
+impl Observer for JobObserver {
+    fn wants_iters(&self) -> bool {
+        true
+    }
+
+    fn on_iter(&mut self, rec: &IterRecord) {
+        self.monitor.on_iter(rec);
+    }
+
+    fn on_incumbent(&mut self, sol: &Solution<'_>) {
+        self.monitor.on_incumbent(sol);
+        *self.job.incumbent.lock().unwrap() = sol.bitset().iter_ones().collect();
+    }
+
+    fn should_stop(&mut self) -> bool {
+        self.monitor.is_cancelled() || self.deadline.is_some_and(|d| Instant::now() >= d)
+    }
+
+    fn on_finish(&mut self, reason: StopReason) {
+        let secs = self.monitor.elapsed().as_secs_f64();
+        *self.job.finished.lock().unwrap() = Some((reason, secs));
+    }
+}
+
+#[derive(Default)]
+struct State {
+    graphs: Mutex<HashMap<String, Arc<Graph>>>,
+    jobs:   Mutex<Vec<Arc<Job>>>,
+}
+
+type Reply = (u16, String);
+
+/// Largest graph upload accepted, in bytes.
+const MAX_BODY: u64 = 1 << 30;
+
+fn error(code: u16, msg: &str) -> Reply {
+    (code, format!("{{\"error\":\"{}\"}}", msg.replace('"', "'")))
+}
+
+fn query(url: &str) -> HashMap<&str, &str> {
+    url.split_once('?')
+        .map(|(_, q)| q.split('&').filter_map(|kv| kv.split_once('=')).collect())
+        .unwrap_or_default()
+}
+
+fn get<T: std::str::FromStr>(q: &HashMap<&str, &str>, key: &str) -> Result<Option<T>, Reply> {
+    q.get(key)
+        .map(|v| v.parse().map_err(|_| error(400, &format!("invalid value for '{key}'"))))
+        .transpose()
+}
+
+fn graph_json(name: &str, g: &Graph) -> String {
+    format!("{{\"name\":\"{name}\",\"n\":{},\"m\":{}}}", g.n(), g.m())
+}
+
+fn add_graph(state: &State, name: &str, req: &mut Request) -> Reply {
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || "._-".contains(c)) {
+        return error(400, "graph names may only use [A-Za-z0-9._-]");
+    }
+    let mut body = Vec::new();
+    if let Err(e) = req.as_reader().take(MAX_BODY + 1).read_to_end(&mut body) {
+        return error(400, &e.to_string());
+    }
+    if body.len() as u64 > MAX_BODY {
+        return error(413, &format!("graph uploads are limited to {MAX_BODY} bytes"));
+    }
+    match parse::parse_dimacs_bytes(&body) {
+        Ok(g) => {
+            let json = graph_json(name, &g);
+            state.graphs.lock().unwrap().insert(name.to_string(), Arc::new(g));
+            (201, json)
+        }
+        Err(e) => error(400, &e.to_string()),
+    }
+}
+
+fn submit(state: &State, url: &str) -> Result<Reply, Reply> {
+    let q = query(url);
+    let name = *q.get("graph").ok_or_else(|| error(400, "missing 'graph'"))?;
+    let graph = state.graphs.lock().unwrap().get(name).cloned()
+        .ok_or_else(|| error(404, &format!("unknown graph '{name}'")))?;
+    let gamma: f64 = get(&q, "gamma")?.ok_or_else(|| error(400, "missing 'gamma'"))?;
+    if !(gamma > 0.0 && gamma <= 1.0) {
+        return Err(error(400, "gamma must be in (0, 1]"));
+    }
+    let k: Option<usize> = get(&q, "k")?;
+    if k.is_some_and(|k| k < 2 || k > graph.n()) {
+        return Err(error(400, "k out of range"));
+    }
+    let seed: u64 = get(&q, "seed")?.unwrap_or(0);
+    let limit: Option<f64> = get(&q, "time_limit")?;
+    let deadline = match limit.map(Duration::try_from_secs_f64) {
+        Some(Ok(t)) => Some(Instant::now().checked_add(t).ok_or_else(|| error(400, "time_limit is too large"))?),
+        Some(Err(_)) => return Err(error(400, "time_limit must be a non-negative number of seconds in range")),
+        None => None,
+    };
+
+    let p = Params { gamma_target: gamma, ..Params::default() };
+    let job = Arc::new(Job {
+        graph:     name.to_string(),
+        monitor:   Monitor::new(),
+        incumbent: Mutex::new(Vec::new()),
+        finished:  Mutex::new(None),
+        manifest:  Manifest::new(&graph, &p, seed, 0).to_json(),
+    });
+    let id = {
+        let mut jobs = state.jobs.lock().unwrap();
+        jobs.push(Arc::clone(&job));
+        jobs.len() - 1
+    };
+    std::thread::spawn(move || {
+        let mut rng = SolverRng::seeded(p.rng, seed);
+        let monitor = job.monitor.clone();
+        let mut obs = JobObserver { job, monitor, deadline };
+        match k {
+            Some(k) => solve_fixed_k_with(&graph, k, &mut rng, &p, &mut obs),
+            None => solve_maxk_with(&graph, &mut rng, &p, &mut obs),
+        };
+    });
+    Ok((202, format!("{{\"id\":{id}}}")))
+}
+
+fn job_status(id: usize, job: &Job) -> String {
+    let finished = *job.finished.lock().unwrap();
+    let status = match finished {
+        None if job.monitor.is_cancelled() => "cancelling",
+        None => "running",
+        Some((StopReason::Interrupted, _)) if job.monitor.is_cancelled() => "cancelled",
+        Some(_) => "done",
+    };
+    format!(
+        "{{\"id\":{id},\"graph\":\"{}\",\"status\":\"{status}\",\"stop_reason\":{},\
+\"size\":{},\"density\":{},\"iterations\":{},\"seconds\":{:.3},\"manifest\":{}}}",
+        job.graph,
+        finished.map_or("null".to_string(), |(r, _)| format!("\"{}\"", r.as_str())),
+        job.monitor.best_size(),
+        job.monitor.best_density(),
+        job.monitor.iterations(),
+        finished.map_or(job.monitor.elapsed().as_secs_f64(), |(_, secs)| secs),
+        job.manifest,
+    )
+}
+
+fn incumbent(job: &Job) -> String {
+    let verts = job.incumbent.lock().unwrap();
+    let list: Vec<String> = verts.iter().map(|v| v.to_string()).collect();
+    format!(
+        "{{\"size\":{},\"density\":{},\"vertices\":[{}]}}",
+        verts.len(),
+        job.monitor.best_density(),
+        list.join(",")
+    )
+}
+
+fn route(state: &State, req: &mut Request) -> Reply {
+    let url = req.url().to_string();
+    let path = url.split('?').next().unwrap_or("");
+    let parts: Vec<&str> = path.trim_matches('/').split('/').collect();
+    let job = |id: &str| -> Result<Arc<Job>, Reply> {
+        let id: usize = id.parse().map_err(|_| error(400, "invalid job id"))?;
+        state.jobs.lock().unwrap().get(id).cloned().ok_or_else(|| error(404, "unknown job"))
+    };
+    let reply = match (req.method(), parts.as_slice()) {
+        (Method::Get, ["graphs"]) => {
+            let graphs = state.graphs.lock().unwrap();
+            let mut list: Vec<String> = graphs.iter().map(|(n, g)| graph_json(n, g)).collect();
+            list.sort();
+            Ok((200, format!("[{}]", list.join(","))))
+        }
+        (Method::Post, ["graphs", name]) => Ok(add_graph(state, name, req)),
+        (Method::Post, ["jobs"]) => submit(state, &url),
+        (Method::Get, ["jobs", id]) => job(id).map(|j| (200, job_status(id.parse().unwrap(), &j))),
+        (Method::Get, ["jobs", id, "incumbent"]) => job(id).map(|j| (200, incumbent(&j))),
+        (Method::Delete, ["jobs", id]) => job(id).map(|j| {
+            j.monitor.cancel();
+            (202, job_status(id.parse().unwrap(), &j))
+        }),
+        _ => Err(error(404, "no such route")),
+    };
+    reply.unwrap_or_else(|e| e)
+}
+
+/// Serve until the process is killed.
+pub fn serve(addr: &str) -> std::io::Result<()> {
+    let server = Server::http(addr).map_err(std::io::Error::other)?;
+    tracing::warn!(target: "tsqc", addr, "listening");
+    let state = State::default();
+    let json: Header = "Content-Type: application/json".parse().unwrap();
+    for mut req in server.incoming_requests() {
+        let (code, body) = route(&state, &mut req);
+        tracing::info!(target: "tsqc", method = %req.method(), url = req.url(), code, "request");
+        let resp = Response::from_string(body).with_status_code(code).with_header(json.clone());
+        if let Err(e) = req.respond(resp) {
+            tracing::warn!(target: "tsqc", error = %e, "response failed");
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,143 @@
+// src/builder.rs
+//! Incremental [`Graph`] construction.
+//!
+//! Edges are streamed in without knowing `n` up front; the builder keeps a
+//! compact edge buffer, tracks the largest vertex id, and allocates the
+//! adjacency matrix exactly once in [`GraphBuilder::build`] (after
+//! normalising and deduplicating the buffer).  Buffered endpoints are
+//! stored as `u32`, halving the buffer on 64-bit targets.  Without an
+//! explicit [`GraphBuilder::layout`] the layout follows [`Layout::auto`].
+
+use crate::{graph::{Graph, Layout}, memory::{self, MemoryError}};
+
+#[derive(Clone, Debug, Default)]
+pub struct GraphBuilder {
+    edges:    Vec<(u32, u32)>,
+    declared: usize,
+    max_id:   Option<usize>,
+    layout:   Option<Layout>,
+}
+
+impl GraphBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builder with room for `m` edges.
+    pub fn with_capacity(m: usize) -> Self {
+        Self { edges: Vec::with_capacity(m), ..Self::default() }
+    }
+
+    /// Reserve room for `additional` more edges.
+    pub fn reserve(&mut self, additional: usize) {
+        self.edges.reserve(additional);
+    }
+
+    /// Declare at least `n` vertices (e.g. from a file header), so trailing
+    /// isolated vertices are kept.
+    pub fn vertices(&mut self, n: usize) -> &mut Self {
+        self.declared = self.declared.max(n);
+        self
+    }
+
+    /// Adjacency layout of the built graph (default: [`Layout::auto`] on
+    /// the vertex and distinct edge counts).
+    pub fn layout(&mut self, layout: Layout) -> &mut Self {
+        self.layout = Some(layout);
+        self
+    }
+
+    /// Add undirected edge (u,v), 0-based.  Self-loops are ignored;
+    /// duplicates are removed at build time.
+    ///
+    /// Panics if a vertex id does not fit in `u32`.
+    pub fn add_edge(&mut self, u: usize, v: usize) -> &mut Self {
+        if u != v {
+            let (lo, hi) = (u.min(v), u.max(v));
+            let hi32 = u32::try_from(hi).expect("vertex id exceeds u32 range");
+            self.edges.push((lo as u32, hi32));
+            self.max_id = Some(self.max_id.map_or(hi, |m| m.max(hi)));
+        }
+        self
+    }
+
+    /// Add every edge of `edges`.
+    pub fn extend<I: IntoIterator<Item = (usize, usize)>>(&mut self, edges: I) -> &mut Self {
+        for (u, v) in edges {
+            self.add_edge(u, v);
+        }
+        self
+    }
+
+    /// Vertex count the built graph will have.
+    pub fn num_vertices(&self) -> usize {
+        self.declared.max(self.max_id.map_or(0, |m| m + 1))
+    }
+
+    /// Buffered edges so far (duplicates included).
+    pub fn buffered_edges(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Deduplicate and materialise the graph in one pass.
+    pub fn build(mut self) -> Graph {
+        let layout = self.normalise();
+        self.fill(layout)
+    }
+
+    /// [`GraphBuilder::build`], refused with a [`MemoryError`] before the
+    /// adjacency is allocated if it would not fit (see [`crate::memory`]).
+    pub fn try_build(mut self) -> Result<Graph, MemoryError> {
+        let layout = self.normalise();
+        memory::check_edges(self.num_vertices(), self.edges.len(), layout)?;
+        Ok(self.fill(layout))
+    }
+
+    /// Sort and deduplicate the buffer; the layout to build.
+    fn normalise(&mut self) -> Layout {
+        self.edges.sort_unstable();
+        self.edges.dedup();
+        self.layout.unwrap_or_else(|| Layout::auto(self.num_vertices(), self.edges.len()))
+    }
+
+    fn fill(self, layout: Layout) -> Graph {
+        let n = self.num_vertices();
+        let edges = self.edges.iter().map(|&(u, v)| (u as usize, v as usize));
+        if layout == Layout::Csr {
+            return Graph::csr_from_edges(n, edges);
+        }
+        let mut g = Graph::with_layout(n, layout);
+        for (u, v) in edges {
+            g.add_edge(u, v);
+        }
+        g
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dedup_and_grow() {
+        let mut b = GraphBuilder::with_capacity(4);
+        b.add_edge(0, 1).add_edge(1, 0).add_edge(2, 2).add_edge(3, 1);
+        assert_eq!(b.num_vertices(), 4);
+        b.vertices(6);
+        let g = b.build();
+        assert_eq!(g.n(), 6);
+        assert_eq!(g.edge_list(), vec![(0, 1), (1, 3)]);
+
+        let mut b = GraphBuilder::new();
+        b.layout(Layout::Triangular).extend([(2, 0), (0, 2), (1, 2)]);
+        let g = b.build();
+        assert_eq!(g.layout(), Layout::Triangular);
+        assert_eq!(g.edge_list(), vec![(0, 2), (1, 2)]);
+
+        let mut b = GraphBuilder::new();
+        b.layout(Layout::Csr).extend([(3, 1), (1, 3), (0, 3)]);
+        let g = b.try_build().unwrap();
+        assert_eq!(g.layout(), Layout::Csr);
+        assert_eq!(g.edge_list(), vec![(0, 3), (1, 3)]);
+    }
+}
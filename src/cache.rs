@@ -0,0 +1,146 @@
+// src/cache.rs
+//! On-disk cache of solver results, keyed by instance and configuration.
+//!
+//! [`instance_key`] hashes everything a result depends on: the graph
+//! [`Fingerprint`], the full [`Params`] (their `Debug` form), the seed,
+//! the problem (fixed k or max-k) and the build (crate version and git
+//! commit), so a new solver never serves an old solver's results.  A [`ResultCache`] is a directory with
+//! one small text file per key holding the Zobrist hash of the found set
+//! and its vertices; writes go through a temporary file and a rename, so
+//! concurrent runs never see a partial entry.  Unreadable entries, or
+//! entries whose vertices do not match their hash, count as misses.
+
+use crate::{
+    graph::Graph,
+    manifest::{fnv1a, Fingerprint, FNV_OFFSET, GIT_HASH, VERSION},
+    params::Params,
+    pool::zobrist_of,
+    solution::Solution,
+};
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Stable 64-bit key of (build, graph, params, seed, k); `k = None` is
+/// max-k.
+pub fn instance_key(graph: &Graph, p: &Params, seed: u64, k: Option<usize>) -> u64 {
+    let fp = Fingerprint::of(graph);
+    let mut h = fnv1a(FNV_OFFSET, VERSION.as_bytes());
+    h = fnv1a(h, &[0]);
+    h = fnv1a(h, GIT_HASH.as_bytes());
+    h = fnv1a(h, &[0]);
+    for x in [fp.n as u64, fp.m as u64, fp.edge_hash, seed, k.map_or(u64::MAX, |k| k as u64)] {
+        h = fnv1a(h, &x.to_le_bytes());
+    }
+    fnv1a(h, format!("{p:?}").as_bytes())
+}
+
+/// Directory of cached results.
+#[derive(Clone, Debug)]
+pub struct ResultCache {
+    dir: PathBuf,
+}
+
+impl ResultCache {
+    /// Cache in `dir` (created on the first write).
+    pub fn new<P: AsRef<Path>>(dir: P) -> Self {
+        Self { dir: dir.as_ref().to_path_buf() }
+    }
+
+    /// `$TSQC_CACHE_DIR`, else `$XDG_CACHE_HOME/tsqc`, else
+    /// `$HOME/.cache/tsqc`; `None` if none of them is set.
+    pub fn default_dir() -> Option<PathBuf> {
+        let var = |name| std::env::var_os(name).filter(|v| !v.is_empty()).map(PathBuf::from);
+        var("TSQC_CACHE_DIR")
+            .or_else(|| var("XDG_CACHE_HOME").map(|d| d.join("tsqc")))
+            .or_else(|| var("HOME").map(|d| d.join(".cache").join("tsqc")))
+    }
+
+    fn path(&self, key: u64) -> PathBuf {
+        self.dir.join(format!("{key:016x}.txt"))
+    }
+
+    /// Cached vertices for `key`, if any (distinct, all below `n`, and
+    /// matching the stored hash).
+    pub fn get(&self, key: u64, n: usize) -> Option<Vec<usize>> {
+        let text = std::fs::read_to_string(self.path(key)).ok()?;
+        let mut tokens = text.split_whitespace();
+        let hash = u64::from_str_radix(tokens.next()?, 16).ok()?;
+        let vertices: Vec<usize> = tokens.map(str::parse).collect::<Result<_, _>>().ok()?;
+        let ok = vertices.is_sorted_by(|a, b| a < b) && vertices.last().is_none_or(|&v| v < n);
+        (ok && zobrist_of(vertices.iter().copied()) == hash).then_some(vertices)
+    }
+
+    /// Store `vertices` (ascending) under `key`.
+    pub fn put(&self, key: u64, vertices: &[usize]) -> io::Result<()> {
+        std::fs::create_dir_all(&self.dir)?;
+        let path = self.path(key);
+        let tmp = path.with_extension(format!("tmp{}", std::process::id()));
+        let mut line = format!("{:016x}", zobrist_of(vertices.iter().copied()));
+        for v in vertices {
+            line.push_str(&format!(" {v}"));
+        }
+        std::fs::write(&tmp, line + "\n")?;
+        std::fs::rename(&tmp, &path)
+    }
+
+    /// The cached set for `key` (`true`), or the set `solve` returns
+    /// (`false`), stored if `solve` marks it complete (e.g. not cut short
+    /// by a time limit).  A failed write is logged and otherwise ignored.
+    pub fn get_or_solve<'g>(
+        &self,
+        graph: &'g Graph,
+        key: u64,
+        solve: impl FnOnce() -> (Solution<'g>, bool),
+    ) -> (Solution<'g>, bool) {
+        if let Some(vertices) = self.get(key, graph.n()) {
+            let mut sol = Solution::new(graph);
+            for v in vertices {
+                sol.add(v);
+            }
+            return (sol, true);
+        }
+        let (sol, complete) = solve();
+        if complete {
+            let vertices: Vec<usize> = sol.bitset().iter_ones().collect();
+            if let Err(e) = self.put(key, &vertices) {
+                tracing::warn!(target: "tsqc", error = %e, dir = %self.dir.display(), "result cache write failed");
+            }
+        }
+        (sol, false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keys_and_round_trip() {
+        let g = Graph::from_edge_list(4, &[(0, 1), (1, 2), (2, 3)]);
+        let p = Params::default();
+        let key = instance_key(&g, &p, 1, Some(3));
+        assert_eq!(key, instance_key(&g, &p.clone(), 1, Some(3)));
+        assert_ne!(key, instance_key(&g, &p, 2, Some(3)));
+        assert_ne!(key, instance_key(&g, &p, 1, None));
+        assert_ne!(key, instance_key(&g, &Params { gamma_target: 0.8, ..p.clone() }, 1, Some(3)));
+        assert_ne!(key, instance_key(&Graph::from_edge_list(4, &[(0, 1), (1, 2)]), &p, 1, Some(3)));
+
+        let dir = std::env::temp_dir().join(format!("tsqc-cache-test-{}", std::process::id()));
+        let cache = ResultCache::new(&dir);
+        assert_eq!(cache.get(key, 4), None);
+        cache.put(key, &[0, 1, 2]).unwrap();
+        assert_eq!(cache.get(key, 4), Some(vec![0, 1, 2]));
+        assert_eq!(cache.get(key, 2), None);
+        // a damaged entry is a miss
+        std::fs::write(cache.path(key), format!("{:016x} 0 1 3\n", zobrist_of([0, 1, 2]))).unwrap();
+        assert_eq!(cache.get(key, 4), None);
+        cache.put(key, &[0, 1, 2]).unwrap();
+
+        let (sol, hit) = cache.get_or_solve(&g, key, || unreachable!());
+        assert!(hit && sol.size() == 3 && sol.edges() == 2);
+        let other = key ^ 1;
+        let (_, hit) = cache.get_or_solve(&g, other, || (Solution::new(&g), false));
+        assert!(!hit && cache.get(other, 4).is_none());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
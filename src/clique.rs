@@ -0,0 +1,463 @@
+// src/clique.rs
+//! Maximum-clique mode: the γ = 1 special case with its own local search.
+//!
+//! At γ = 1 a feasible set is a clique, so instead of swapping towards a
+//! density target the search keeps C a clique at every step and moves
+//! between cliques (multi-neighbourhood tabu search):
+//!
+//! • ADD an outsider adjacent to all of C (the set PA), preferring the
+//!   one with the most neighbours in PA;
+//! • SWAP an outsider adjacent to all of C but one member (OM) for that
+//!   member, which becomes tabu for 7 + rand(|OM|) moves;
+//! • DROP a random member (tabu likewise) when PA and OM are empty.
+//!
+//! Candidate pruning: PA and OM lie in N(a) ∪ N(b) for any two members a,
+//! b, so only the neighbours of the two lowest-degree members are scanned;
+//! and a vertex of core number c lies in no clique of more than c + 1
+//! vertices, so vertices with c < |best| are never candidates.
+//!
+//! Bound: a greedy colouring in smallest-last order ([`clique_bound`],
+//! at most the degeneracy + 1 colours).  The search ends once the best
+//! clique reaches it, or when every vertex is pruned.
+//!
+//! A run restarts from the least-used vertex (frequency memory, as in
+//! § 3.5) after L moves without improving its best clique.  `p.max_iter`,
+//! `p.time_limit` and [`Observer::should_stop`] bound the whole search.
+//!
+//! The observer sees the search like a fixed-k one: every move as an
+//! [`IterRecord`] of kind [`MoveKind::Swap`] ([`MoveKind::Aspiration`] for
+//! a tabu ADD), so ADD / SWAP / DROP count as improving / sideways /
+//! worsening; every run as a [`RestartRecord`] followed by its best clique
+//! (grown to k for a fixed k, so only a k-clique counts as found);
+//! incumbents and the stop reason.
+//!
+//! [`Params::clique_mode`] routes max-k and fixed-k solves at γ = 1
+//! without budget or penalties here (see [`Params::uses_clique_mode`]).
+
+use crate::{
+    construct::{greedy_clique, resize_to_k},
+    graph::Graph,
+    neighbour::MoveKind,
+    observer::{IterRecord, NoObserver, Observer, RestartRecord, StopReason},
+    params::Params,
+    solution::Solution,
+};
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::time::Instant;
+
+/// Fixed part of the tenure of a vertex dropped or swapped out.
+const CLIQUE_TENURE: usize = 7;
+
+/// One run of the clique search, from its start to the next restart.
+struct Run<'g> {
+    index:         usize,
+    seed_vertex:   usize,
+    start_density: f64,
+    start_moves:   usize,
+    /// Largest clique of the run.
+    best:          Solution<'g>,
+}
+
+impl<'g> Run<'g> {
+    fn new(index: usize, cur: &Solution<'g>, moves: usize) -> Self {
+        Self {
+            index,
+            seed_vertex: cur.bitset().first_one().unwrap_or(0),
+            start_density: cur.density(),
+            start_moves: moves,
+            best: cur.clone(),
+        }
+    }
+
+    /// Report the finished run to `obs`: its [`RestartRecord`], then its
+    /// best clique, grown to `target` vertices by [`resize_to_k`] for a
+    /// fixed k.  Empty runs are skipped.
+    fn report<R, O>(&self, k: usize, target: usize, moves: usize, rng: &mut R, obs: &mut O)
+    where
+        R: Rng + ?Sized,
+        O: Observer + ?Sized,
+    {
+        if self.best.size() == 0 {
+            return;
+        }
+        let mut best = self.best.clone();
+        if target < usize::MAX {
+            resize_to_k(&mut best, target, rng);
+        }
+        obs.on_restart(&RestartRecord {
+            k,
+            restart: self.index,
+            seed_vertex: self.seed_vertex,
+            start_density: self.start_density,
+            best_density: best.density(),
+            iterations: moves - self.start_moves,
+            perturbations: 0,
+        });
+        obs.on_run_best(&best);
+    }
+}
+
+/// Forward one move to `obs` (only called when `obs.wants_iters()`);
+/// `before` is m(C) before the move.
+fn emit_iter<O: Observer + ?Sized>(
+    obs: &mut O,
+    k: usize,
+    iter: usize,
+    cur: &Solution<'_>,
+    kind: MoveKind,
+    before: usize,
+    tabu_until: &[usize],
+) {
+    obs.on_iter(&IterRecord {
+        k,
+        iter,
+        size: cur.size(),
+        edges: cur.edges(),
+        delta: cur.edges() as isize - before as isize,
+        density: cur.density(),
+        kind,
+        tu: CLIQUE_TENURE,
+        tv: 0,
+        tabu_u: tabu_until.iter().filter(|&&t| t > iter).count(),
+        tabu_v: 0,
+    });
+}
+
+/// Core number of every vertex and the smallest-last order (vertices in
+/// the order they are peeled), by bucket peeling in O(n + m).
+fn cores(graph: &Graph) -> (Vec<usize>, Vec<usize>) {
+    let n = graph.n();
+    let mut deg: Vec<usize> = (0..n).map(|v| graph.degree(v)).collect();
+    let max_deg = deg.iter().copied().max().unwrap_or(0);
+    let mut buckets: Vec<Vec<usize>> = vec![Vec::new(); max_deg + 1];
+    for v in 0..n {
+        buckets[deg[v]].push(v);
+    }
+    let mut core = vec![0; n];
+    let mut done = vec![false; n];
+    let mut order = Vec::with_capacity(n);
+    let mut d = 0;
+    while order.len() < n {
+        // stale entries (degree since lowered) are skipped
+        let Some(v) = buckets[d].pop() else {
+            d += 1;
+            continue;
+        };
+        if done[v] || deg[v] != d {
+            continue;
+        }
+        done[v] = true;
+        core[v] = d;
+        order.push(v);
+        for w in graph.neighbors(v) {
+            if !done[w] && deg[w] > d {
+                deg[w] -= 1;
+                buckets[deg[w]].push(w);
+            }
+        }
+        d = d.saturating_sub(1);
+    }
+    (core, order)
+}
+
+/// Colours of a greedy colouring in `order`, last vertex first.
+fn colours(graph: &Graph, order: &[usize]) -> usize {
+    let n = graph.n();
+    let mut colour = vec![usize::MAX; n];
+    // stamp[c] == v: colour c is taken by a neighbour of v
+    let mut stamp = vec![usize::MAX; n + 1];
+    let mut used = 0;
+    for &v in order.iter().rev() {
+        for w in graph.neighbors(v) {
+            if colour[w] != usize::MAX {
+                stamp[colour[w]] = v;
+            }
+        }
+        let c = (0..).find(|&c| stamp[c] != v).unwrap();
+        colour[v] = c;
+        used = used.max(c + 1);
+    }
+    used
+}
+
+/// Upper bound on the clique number: the colours of a greedy colouring in
+/// smallest-last order (never more than the degeneracy + 1).
+pub fn clique_bound(graph: &Graph) -> usize {
+    colours(graph, &cores(graph).1)
+}
+
+/// Largest clique found, by the search of the module docs.  Uses `p.max_iter`,
+/// `p.time_limit`, `p.stagnation_iter` (L) and `p.clique_starts` (the
+/// greedy first run); γ and the other constraints are ignored.
+pub fn solve_clique<'g, R>(graph: &'g Graph, rng: &mut R, p: &Params) -> Solution<'g>
+where
+    R: Rng + ?Sized,
+{
+    solve_clique_with(graph, rng, p, &mut NoObserver)
+}
+
+/// [`solve_clique`] reporting incumbents and the stop reason to `obs`.
+pub fn solve_clique_with<'g, R, O>(graph: &'g Graph, rng: &mut R, p: &Params, obs: &mut O) -> Solution<'g>
+where
+    R: Rng + ?Sized,
+    O: Observer + ?Sized,
+{
+    clique_search(graph, None, usize::MAX, rng, p, obs)
+}
+
+/// Clique search that stops as soon as a clique of `target` vertices is
+/// found (returned with exactly `target` members); `start` (a clique) is
+/// the first run's set instead of the greedy clique.
+pub(crate) fn clique_search<'g, R, O>(
+    graph: &'g Graph,
+    start: Option<Solution<'g>>,
+    target: usize,
+    rng: &mut R,
+    p: &Params,
+    obs: &mut O,
+) -> Solution<'g>
+where
+    R: Rng + ?Sized,
+    O: Observer + ?Sized,
+{
+    let n = graph.n();
+    let deadline = p.time_limit.and_then(|t| Instant::now().checked_add(t));
+    let (core, order) = cores(graph);
+    let degree: Vec<usize> = (0..n).map(|v| graph.degree(v)).collect();
+    let bound = colours(graph, &order).min(target);
+
+    let mut cur = start
+        .filter(|s| s.edges() == s.size() * s.size().saturating_sub(1) / 2)
+        .unwrap_or_else(|| greedy_clique(graph, p.clique_starts));
+    while cur.size() > target {
+        let v = cur.bitset().first_one().unwrap();
+        cur.remove(v);
+    }
+    cur.track_degrees();
+    let mut best = cur.clone();
+    if best.size() > 0 {
+        obs.on_incumbent(&best);
+    }
+
+    let mut freq = vec![0usize; n];
+    let mut tabu_until = vec![0usize; n];
+    // stamps of the current move: scanned vertices and members of PA
+    let mut seen = vec![usize::MAX; n];
+    let mut in_pa = vec![usize::MAX; n];
+    let (mut pa, mut om, mut pick) = (Vec::new(), Vec::new(), Vec::new());
+    let mut moves = 0usize;
+    let mut run = Run::new(0, &cur, 0);
+    let mut since = 0usize;
+    // size sought: the target, or one more than the best in max mode
+    let k_of = |best: &Solution<'_>| if target == usize::MAX { best.size() + 1 } else { target };
+
+    let reason = loop {
+        if best.size() >= bound {
+            break if best.size() >= target { StopReason::Feasible } else { StopReason::Bound };
+        }
+        if moves >= p.max_iter {
+            break StopReason::MaxIter;
+        }
+        if obs.should_stop() || deadline.is_some_and(|d| Instant::now() >= d) {
+            break StopReason::Interrupted;
+        }
+
+        // restart from the least-used vertex that may still beat the best
+        if since >= p.stagnation_limit(n, best.size() + 1, None) || cur.size() == 0 {
+            let mut seeds: Vec<usize> = (0..n).filter(|&v| core[v] >= best.size()).collect();
+            let Some(min_f) = seeds.iter().map(|&v| freq[v]).min() else {
+                break StopReason::Bound; // every vertex pruned: best is maximum
+            };
+            seeds.retain(|&v| freq[v] == min_f);
+            let seed = *seeds.choose(rng).unwrap();
+            run.report(k_of(&best), target, moves, rng, obs);
+            cur.clear();
+            cur.add(seed);
+            freq[seed] += 1;
+            tabu_until.fill(0);
+            run = Run::new(run.index + 1, &cur, moves);
+            since = 0;
+        }
+
+        // PA and OM among the neighbours of the two lowest-degree members
+        let step = moves;
+        moves += 1;
+        since += 1;
+        let s = cur.size();
+        let mut members: Vec<usize> = cur.bitset().iter_ones().collect();
+        members.sort_unstable_by_key(|&v| degree[v]);
+        pa.clear();
+        om.clear();
+        for &a in members.iter().take(2) {
+            for w in graph.neighbors(a) {
+                if seen[w] == step || cur.bitset()[w] || core[w] < best.size() {
+                    continue;
+                }
+                seen[w] = step;
+                match s - cur.deg_in(w) {
+                    0 => {
+                        pa.push(w);
+                        in_pa[w] = step;
+                    }
+                    1 if s > 1 => om.push(w),
+                    _ => {}
+                }
+            }
+        }
+
+        // ADD: tabu unless it beats the best clique
+        let aspired = s + 1 > best.size();
+        pick.clear();
+        let mut top = 0;
+        for &v in &pa {
+            if tabu_until[v] > moves && !aspired {
+                continue;
+            }
+            let score = graph.neighbors(v).filter(|&w| in_pa[w] == step).count() + 1;
+            if score > top {
+                top = score;
+                pick.clear();
+            }
+            if score == top {
+                pick.push(v);
+            }
+        }
+        let before = cur.edges();
+        if let Some(&v) = pick.choose(rng) {
+            let kind = if tabu_until[v] > moves { MoveKind::Aspiration } else { MoveKind::Swap };
+            cur.add(v);
+            freq[v] += 1;
+            if obs.wants_iters() {
+                emit_iter(obs, k_of(&best), moves, &cur, kind, before, &tabu_until);
+            }
+            if cur.size() > run.best.size() {
+                run.best = cur.clone();
+                since = 0;
+            }
+            if cur.size() > best.size() {
+                best = cur.clone();
+                obs.on_incumbent(&best);
+            }
+            continue;
+        }
+
+        // SWAP: the one non-neighbour in C leaves
+        om.retain(|&v| tabu_until[v] <= moves);
+        if let Some(&v) = om.choose(rng) {
+            let u = cur.bitset().iter_ones().find(|&u| !graph.has_edge(u, v)).unwrap();
+            cur.remove(u);
+            cur.add(v);
+            freq[v] += 1;
+            tabu_until[u] = moves + CLIQUE_TENURE + rng.gen_range(0..=om.len());
+            if obs.wants_iters() {
+                emit_iter(obs, k_of(&best), moves, &cur, MoveKind::Swap, before, &tabu_until);
+            }
+            continue;
+        }
+
+        // DROP
+        let u = *members.choose(rng).unwrap();
+        cur.remove(u);
+        tabu_until[u] = moves + CLIQUE_TENURE;
+        if obs.wants_iters() {
+            emit_iter(obs, k_of(&best), moves, &cur, MoveKind::Swap, before, &tabu_until);
+        }
+    };
+
+    run.report(k_of(&best), target, moves, rng, obs);
+    obs.on_finish(reason);
+    best
+}
+
+/// Fixed-k at γ = 1 in clique mode: a k-clique if the search finds one,
+/// else its best clique grown to k vertices by internal degree.
+pub(crate) fn fixed_k_clique<'g, R, O>(graph: &'g Graph, k: usize, rng: &mut R, p: &Params, obs: &mut O) -> Solution<'g>
+where
+    R: Rng + ?Sized,
+    O: Observer + ?Sized,
+{
+    let mut sol = clique_search(graph, None, k, rng, p, obs);
+    if sol.size() < k {
+        resize_to_k(&mut sol, k, rng);
+    }
+    sol
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::facade::Tsqc;
+    use crate::restart::{solve_fixed_k, solve_fixed_k_parallel};
+    use crate::testgraphs::{clique_edges, cycle_edges, k5_with_tail};
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    /// K6 on {10..16} in a 40-cycle with chords v – v+7.
+    fn planted() -> Graph {
+        let mut edges = cycle_edges(40);
+        edges.extend((0..40).map(|v| (v, (v + 7) % 40)));
+        edges.extend(clique_edges(10..16));
+        Graph::from_edge_list(40, &edges)
+    }
+
+    #[test]
+    fn bounds_from_colourings() {
+        let cycle = |n: usize| Graph::from_edge_list(n, &cycle_edges(n));
+        assert_eq!(clique_bound(&cycle(7)), 3);
+        assert_eq!(clique_bound(&cycle(8)), 2);
+        assert_eq!(clique_bound(&Graph::from_edge_list(5, &clique_edges(0..5))), 5);
+        assert_eq!(clique_bound(&Graph::with_vertices(3)), 1);
+        assert_eq!(clique_bound(&Graph::with_vertices(0)), 0);
+        let (core, _) = cores(&planted());
+        assert!((10..16).all(|v| core[v] == 5));
+    }
+
+    #[test]
+    fn finds_the_planted_clique() {
+        #[derive(Default)]
+        struct Finish(Option<StopReason>);
+        impl Observer for Finish {
+            fn on_finish(&mut self, reason: StopReason) { self.0 = Some(reason); }
+        }
+
+        let g = planted();
+        let p = Params { gamma_target: 1.0, clique_starts: 0, max_iter: 100_000, ..Params::default() };
+        let mut obs = Finish::default();
+        let sol = solve_clique_with(&g, &mut ChaCha8Rng::seed_from_u64(0), &p, &mut obs);
+        assert_eq!(sol.bitset().iter_ones().collect::<Vec<_>>(), (10..16).collect::<Vec<_>>());
+        // proven maximum: by the colouring or once every vertex is pruned
+        assert_eq!(obs.0, Some(StopReason::Bound));
+
+        // max-k and fixed-k go through the clique search in clique mode
+        let p = Params { clique_mode: true, ..p };
+        assert!(p.uses_clique_mode());
+        let sol = crate::maxk::solve_maxk(&g, &mut ChaCha8Rng::seed_from_u64(1), &p);
+        assert_eq!(sol.size(), 6);
+        let sol = solve_fixed_k(&g, 4, &mut ChaCha8Rng::seed_from_u64(2), &p);
+        assert_eq!((sol.size(), sol.edges()), (4, 6));
+        // no 7-clique: the best 7-set is returned, not feasible
+        let sol = solve_fixed_k(&g, 7, &mut ChaCha8Rng::seed_from_u64(3), &p);
+        assert_eq!(sol.size(), 7);
+        assert!(!sol.is_gamma_feasible(1.0));
+    }
+
+    #[test]
+    fn reports_moves_runs_and_run_bests() {
+        // no greedy start: the search has to move to the K5
+        let g = k5_with_tail();
+        let p = Params { gamma_target: 1.0, clique_mode: true, clique_starts: 0, ..Params::default() };
+
+        // the parallel workers only see run bests
+        let sol = solve_fixed_k_parallel(&g, 5, 4, 1, &p);
+        assert_eq!(sol.bitset().iter_ones().collect::<Vec<_>>(), (0..5).collect::<Vec<_>>());
+
+        let tsqc = Tsqc::new(g).params(p);
+        let found = tsqc.solve_k(5).unwrap();
+        let report = &found.report;
+        assert_eq!(found.solution.size(), 5);
+        assert!(report.feasible);
+        assert!(report.iterations > 0 && report.restarts > 0, "{report}");
+        assert!((0..5).all(|v| report.participation[v] > 0), "{:?}", report.participation);
+    }
+}
@@ -0,0 +1,100 @@
+// src/community.rs
+//! Label-propagation community detection (Raghavan et al., 2007).
+//!
+//! Every vertex starts with its own label; in each round the vertices are
+//! visited in random order and adopt the most frequent label among their
+//! neighbours (random tie-break), until no label changes or `max_rounds`
+//! is reached.  Near-linear per round; used to seed constructors.
+
+use crate::graph::Graph;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Community label of every vertex, compacted to `0..c`.
+pub fn label_propagation<R>(graph: &Graph, max_rounds: usize, rng: &mut R) -> Vec<usize>
+where
+    R: Rng + ?Sized,
+{
+    let n = graph.n();
+    let mut labels: Vec<usize> = (0..n).collect();
+    let mut order: Vec<usize> = (0..n).collect();
+    let mut count = vec![0usize; n];
+    let mut touched = Vec::new();
+    let mut best = Vec::new();
+
+    for _ in 0..max_rounds {
+        order.shuffle(rng);
+        let mut changed = false;
+        for &v in &order {
+            for w in graph.neighbors(v) {
+                let l = labels[w];
+                if count[l] == 0 {
+                    touched.push(l);
+                }
+                count[l] += 1;
+            }
+            if touched.is_empty() {
+                continue; // isolated vertex keeps its label
+            }
+            let top = touched.iter().map(|&l| count[l]).max().unwrap();
+            best.clear();
+            best.extend(touched.iter().copied().filter(|&l| count[l] == top));
+            // keep the current label when it is among the winners (converges)
+            if !best.contains(&labels[v]) {
+                labels[v] = *best.choose(rng).unwrap();
+                changed = true;
+            }
+            for l in touched.drain(..) {
+                count[l] = 0;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    // compact labels to 0..c in order of first appearance
+    let mut map = vec![usize::MAX; n];
+    let mut next = 0;
+    for l in labels.iter_mut() {
+        if map[*l] == usize::MAX {
+            map[*l] = next;
+            next += 1;
+        }
+        *l = map[*l];
+    }
+    labels
+}
+
+/// Vertex lists of the communities found by [`label_propagation`].
+pub fn communities<R>(graph: &Graph, max_rounds: usize, rng: &mut R) -> Vec<Vec<usize>>
+where
+    R: Rng + ?Sized,
+{
+    let labels = label_propagation(graph, max_rounds, rng);
+    let c = labels.iter().max().map_or(0, |&m| m + 1);
+    let mut out = vec![Vec::new(); c];
+    for (v, &l) in labels.iter().enumerate() {
+        out[l].push(v);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testgraphs::clique_edges;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn two_cliques_joined_by_an_edge() {
+        let mut edges = [clique_edges(0..5), clique_edges(5..10)].concat();
+        edges.push((4, 5));
+        let g = Graph::from_edge_list(10, &edges);
+        let mut rng = ChaCha8Rng::seed_from_u64(3);
+        let mut comms = communities(&g, 20, &mut rng);
+        comms.sort();
+        assert_eq!(comms, vec![(0..5).collect::<Vec<_>>(), (5..10).collect()]);
+    }
+}
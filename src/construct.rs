@@ -3,13 +3,37 @@
 //! • `random_k`
 //! • `greedy_k`
 //! • `greedy_random_k`
+//! • `greedy_from_seed` – greedy fill from a given seed vertex
+//! • `greedy_from_seed_avoiding` – same, preferring vertices outside a mask
+//! • `high_degree_seed` – random seed among the top-degree vertices
 //! • `greedy_until_gamma` – grow until density ≥ γ and can’t be enlarged
+//! • `greedy_clique` – deterministic greedy clique (a lower bound for
+//!   every γ)
+//! • `community_k` – best label-propagation community, fitted to k
+//! • `densest_core` / `densest_core_k` – peeling 2-approximation of the
+//!   densest subgraph (best prefix), fitted to k
+//! • `resize_to_k` – peel / greedily extend any start to size k
+//! • `fit_budget` – swap costly members for cheaper outsiders until the
+//!   vertex-cost budget holds
+//! • `Constructor::build` / `construct_parallel` – dispatch by kind; P
+//!   starts built on the rayon pool, densest first
+//! • `evaluate_all` – mean / best start density of every constructor
 //!
 //! All functions return a ready-to-use [`Solution`].
 
-use crate::{graph::Graph, solution::Solution};
+use crate::{
+    community::communities,
+    graph::Graph,
+    params::{Budget, Params},
+    rng::SolverRng,
+    solution::{density_of, Solution},
+};
+use rayon::prelude::*;
+use bitvec::prelude::*;
 use rand::seq::SliceRandom;
 use rand::Rng;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
 
 /*───────────────────────────────────────────────────────────*/
 /*  Random-k                                                 */
@@ -51,24 +75,270 @@ pub fn greedy_k<'g>(graph: &'g Graph, k: usize) -> Solution<'g> {
 /*───────────────────────────────────────────────────────────*/
 
 pub fn greedy_random_k<'g, R>(graph: &'g Graph, k: usize, rng: &mut R) -> Solution<'g>
+where
+    R: Rng + ?Sized,
+{
+    assert!(k <= graph.n());
+    let seed = rng.gen_range(0..graph.n()); // random seed
+    greedy_from_seed(graph, seed, k, rng)
+}
+
+/// Random seed vertex whose degree is at least the `pct`-th degree
+/// percentile.  `pct ≤ 0` draws uniformly from all vertices.
+pub fn high_degree_seed<R>(graph: &Graph, pct: f64, rng: &mut R) -> usize
+where
+    R: Rng + ?Sized,
+{
+    if pct <= 0.0 {
+        return rng.gen_range(0..graph.n());
+    }
+    let min_deg = graph.degree_percentile(pct);
+    let pool: Vec<usize> = (0..graph.n()).filter(|&v| graph.degree(v) >= min_deg).collect();
+    *pool.choose(rng).expect("percentile degree is attained by some vertex")
+}
+
+/// Start from `seed` and repeatedly add an outsider with the most
+/// neighbours inside *S* (random tie-break) until |S| = k.
+pub fn greedy_from_seed<'g, R>(
+    graph: &'g Graph,
+    seed: usize,
+    k: usize,
+    rng: &mut R,
+) -> Solution<'g>
+where
+    R: Rng + ?Sized,
+{
+    greedy_fill(graph, seed, k, None, rng)
+}
+
+/// [`greedy_from_seed`] that only adds vertices outside `avoid` while any
+/// remain (then falls back to all outsiders), so the start lies mostly in
+/// unexplored territory.
+pub fn greedy_from_seed_avoiding<'g, R>(
+    graph: &'g Graph,
+    seed: usize,
+    k: usize,
+    avoid: &BitSlice,
+    rng: &mut R,
+) -> Solution<'g>
+where
+    R: Rng + ?Sized,
+{
+    greedy_fill(graph, seed, k, Some(avoid), rng)
+}
+
+fn greedy_fill<'g, R>(
+    graph: &'g Graph,
+    seed: usize,
+    k: usize,
+    avoid: Option<&BitSlice>,
+    rng: &mut R,
+) -> Solution<'g>
 where
     R: Rng + ?Sized,
 {
     assert!(k <= graph.n());
 
     let mut sol = Solution::new(graph);
-    sol.add(rng.gen_range(0..graph.n())); // random seed
+    sol.add(seed);
+    // internal degrees, updated from each newcomer's row
+    let mut deg = graph.degrees_into(sol.bitset());
 
+    let mut avoid = avoid;
     while sol.size() < k {
         let mut best_edges = 0usize;
         let mut cand       = Vec::new();
 
         for v in 0..graph.n() {
             if sol.bitset()[v] { continue; }
-            let edges = graph.neigh_row(v)
-                .iter_ones()
-                .filter(|&u| sol.bitset()[u])
-                .count();
+            if avoid.is_some_and(|a| a[v]) { continue; }
+            let edges = deg[v] as usize;
+            if edges > best_edges {
+                best_edges = edges;
+                cand.clear();
+                cand.push(v);
+            } else if edges == best_edges {
+                cand.push(v);
+            }
+        }
+        if cand.is_empty() {
+            avoid = None; // every unavoided vertex is used up
+            continue;
+        }
+        let v = *cand.choose(rng).unwrap();
+        sol.add(v);
+        for w in graph.neighbors(v) {
+            deg[w] += 1;
+        }
+    }
+    sol
+}
+
+/*───────────────────────────────────────────────────────────*/
+/*  Set-based starts                                         */
+/*───────────────────────────────────────────────────────────*/
+
+/// Which constructor the first run of a fixed-k search starts from.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Constructor {
+    /// Greedy fill from a (percentile-filtered) random seed (§ 3.3).
+    #[default]
+    GreedyRandom,
+    /// Densest label-propagation community, fitted to k.
+    Community,
+    /// Peeling densest core, fitted to k.
+    DensestCore,
+}
+
+impl Constructor {
+    /// Every constructor, in the order [`construct_parallel`] cycles them.
+    pub const ALL: [Constructor; 3] =
+        [Constructor::GreedyRandom, Constructor::Community, Constructor::DensestCore];
+
+    /// Build a k-vertex start and the seed vertex to report (the lowest
+    /// member for set-based constructors).
+    pub fn build<'g, R>(self, graph: &'g Graph, k: usize, p: &Params, rng: &mut R) -> (usize, Solution<'g>)
+    where
+        R: Rng + ?Sized,
+    {
+        let (seed, mut sol) = match self {
+            Constructor::GreedyRandom => {
+                // optionally from a high-degree seed
+                let seed = high_degree_seed(graph, p.seed_degree_percentile, rng);
+                (Some(seed), greedy_from_seed(graph, seed, k, rng))
+            }
+            Constructor::Community => (None, community_k(graph, k, rng)),
+            Constructor::DensestCore => (None, densest_core_k(graph, k, rng)),
+        };
+        if let Some(b) = &p.budget {
+            fit_budget(&mut sol, b);
+        }
+        (seed.unwrap_or_else(|| sol.bitset().first_one().unwrap_or(0)), sol)
+    }
+}
+
+/// `count` starts built in parallel, sorted densest first.  Start `i` uses
+/// the `i`-th constructor of [`Constructor::ALL`] counting from
+/// `p.constructor`, with its own RNG seeded from `rng`; a single start is
+/// built directly from `rng` (same stream as a plain `build`).
+pub fn construct_parallel<'g, R>(
+    graph: &'g Graph,
+    k: usize,
+    p: &Params,
+    count: usize,
+    rng: &mut R,
+) -> Vec<(usize, Solution<'g>)>
+where
+    R: Rng + ?Sized,
+{
+    if count <= 1 {
+        return vec![p.constructor.build(graph, k, p, rng)];
+    }
+    let first = Constructor::ALL.iter().position(|&c| c == p.constructor).unwrap();
+    let seeds: Vec<u64> = (0..count).map(|_| rng.r#gen()).collect();
+    let mut starts: Vec<(usize, Solution<'g>)> = crate::threads::install(p.threads, || {
+        seeds
+            .into_par_iter()
+            .enumerate()
+            .map(|(i, seed)| {
+                let kind = Constructor::ALL[(first + i) % Constructor::ALL.len()];
+                kind.build(graph, k, p, &mut SolverRng::seeded(p.rng, seed))
+            })
+            .collect()
+    });
+    starts.sort_by(|a, b| b.1.density().total_cmp(&a.1.density()));
+    starts
+}
+
+/// Start quality of one constructor over a set of seeds.
+#[derive(Clone, Debug)]
+pub struct ConstructorStats {
+    pub constructor:  Constructor,
+    pub mean_density: f64,
+    pub best_density: f64,
+}
+
+/// Build one k-vertex start per constructor and seed (default [`Params`])
+/// and report mean / best density per constructor, in [`Constructor::ALL`]
+/// order.  Densities are 0 for an empty `seeds`.
+pub fn evaluate_all(graph: &Graph, k: usize, seeds: &[u64]) -> Vec<ConstructorStats> {
+    let p = Params::default();
+    Constructor::ALL
+        .iter()
+        .map(|&constructor| {
+            let dens: Vec<f64> = crate::threads::install(p.threads, || {
+                seeds
+                    .par_iter()
+                    .map(|&seed| {
+                        let mut rng = SolverRng::seeded(p.rng, seed);
+                        constructor.build(graph, k, &p, &mut rng).1.density()
+                    })
+                    .collect()
+            });
+            let mean_density = if dens.is_empty() { 0.0 } else { dens.iter().sum::<f64>() / dens.len() as f64 };
+            let best_density = dens.iter().copied().fold(0.0, f64::max);
+            ConstructorStats { constructor, mean_density, best_density }
+        })
+        .collect()
+}
+
+/// Keep |S| but bring its vertex cost within `budget`: repeatedly swap the
+/// costliest member (ties → fewest internal neighbours) for the outsider
+/// with the most internal neighbours among the strictly cheaper ones
+/// (ties → cheaper, then lower id).  Stops early if no cheaper outsider
+/// exists.  Deterministic.
+pub fn fit_budget(sol: &mut Solution<'_>, budget: &Budget) {
+    let n = sol.graph().n();
+    let mut cost = budget.cost_of(sol.bitset());
+    while !budget.allows(cost) {
+        let u = sol.bitset()
+            .iter_ones()
+            .max_by(|&a, &b| {
+                budget.costs[a].total_cmp(&budget.costs[b]).then(sol.deg_in(b).cmp(&sol.deg_in(a)))
+            })
+            .expect("a set over budget is non-empty");
+        let cu = budget.costs[u];
+        let v = (0..n)
+            .filter(|&v| !sol.bitset()[v] && budget.costs[v] < cu)
+            .max_by(|&a, &b| {
+                sol.deg_in(a).cmp(&sol.deg_in(b))
+                    .then(budget.costs[b].total_cmp(&budget.costs[a]))
+                    .then(b.cmp(&a))
+            });
+        let Some(v) = v else { break };
+        sol.remove(u);
+        sol.add(v);
+        cost += budget.costs[v] - cu;
+    }
+}
+
+/// Label-propagation rounds used by [`community_k`].
+const LPA_ROUNDS: usize = 20;
+
+/// Bring `sol` to exactly `k` vertices: repeatedly drop a member of minimum
+/// internal degree, or add an outsider of maximum internal degree (random
+/// tie-break in both cases).
+pub fn resize_to_k<R>(sol: &mut Solution<'_>, k: usize, rng: &mut R)
+where
+    R: Rng + ?Sized,
+{
+    let graph = sol.graph();
+    assert!(k <= graph.n());
+    let mut cand = Vec::new();
+    while sol.size() > k {
+        let weakest = sol.weakest(1)[0].1;
+        cand.clear();
+        cand.extend(
+            sol.bitset().iter_ones().filter(|&u| graph.deg_into(u, sol.bitset()) == weakest),
+        );
+        sol.remove(*cand.choose(rng).unwrap());
+    }
+    while sol.size() < k {
+        let mut best_edges = 0usize;
+        cand.clear();
+        for v in 0..graph.n() {
+            if sol.bitset()[v] { continue; }
+            let edges = graph.deg_into(v, sol.bitset());
             if edges > best_edges {
                 best_edges = edges;
                 cand.clear();
@@ -79,6 +349,83 @@ where
         }
         sol.add(*cand.choose(rng).unwrap());
     }
+}
+
+/// Start from the label-propagation community that promises the most
+/// edges at size k — density × C(min(|C|, k), 2), so tiny dense groups do
+/// not win over a large block — then peel or extend it to size k.
+pub fn community_k<'g, R>(graph: &'g Graph, k: usize, rng: &mut R) -> Solution<'g>
+where
+    R: Rng + ?Sized,
+{
+    assert!(k <= graph.n());
+    let score = |c: &[usize]| {
+        let s = c.len().min(k) as f64;
+        density_of(graph, c) * s * (s - 1.0) / 2.0
+    };
+    let best = communities(graph, LPA_ROUNDS, rng)
+        .into_iter()
+        .max_by(|a, b| score(a).total_cmp(&score(b)).then(a.len().cmp(&b.len())))
+        .unwrap_or_default();
+
+    let mut sol = Solution::new(graph);
+    for v in best {
+        sol.add(v);
+    }
+    resize_to_k(&mut sol, k, rng);
+    sol
+}
+
+/// Charikar's peeling: repeatedly delete a minimum-degree vertex (ties →
+/// smaller index) and return the remaining set with the highest m/|S|
+/// seen along the way (a 2-approximation of the densest subgraph),
+/// ascending.  Deterministic; O(m log n).
+pub fn densest_core(graph: &Graph) -> Vec<usize> {
+    let n = graph.n();
+    let mut deg: Vec<usize> = (0..n).map(|v| graph.degree(v)).collect();
+    let mut alive = vec![true; n];
+    let mut heap: BinaryHeap<Reverse<(usize, usize)>> =
+        (0..n).map(|v| Reverse((deg[v], v))).collect();
+
+    let mut edges = graph.m();
+    let mut best = (if n == 0 { 0.0 } else { edges as f64 / n as f64 }, 0usize);
+    let mut order = Vec::with_capacity(n);
+
+    while let Some(Reverse((d, v))) = heap.pop() {
+        if !alive[v] || d != deg[v] {
+            continue; // stale entry
+        }
+        alive[v] = false;
+        order.push(v);
+        edges -= d;
+        for w in graph.neighbors(v) {
+            if alive[w] {
+                deg[w] -= 1;
+                heap.push(Reverse((deg[w], w)));
+            }
+        }
+        let left = n - order.len();
+        if left > 0 && edges as f64 / left as f64 > best.0 {
+            best = (edges as f64 / left as f64, order.len());
+        }
+    }
+
+    let mut core = order.split_off(best.1);
+    core.sort_unstable();
+    core
+}
+
+/// [`densest_core`] peeled or greedily extended to size k.
+pub fn densest_core_k<'g, R>(graph: &'g Graph, k: usize, rng: &mut R) -> Solution<'g>
+where
+    R: Rng + ?Sized,
+{
+    assert!(k <= graph.n());
+    let mut sol = Solution::new(graph);
+    for v in densest_core(graph) {
+        sol.add(v);
+    }
+    resize_to_k(&mut sol, k, rng);
     sol
 }
 
@@ -108,7 +455,7 @@ where
     let mut edge_found = false;
     'edge: for &u in &verts {
         for &v in &verts {
-            if u < v && graph.neigh_row(u)[v] {
+            if u < v && graph.has_edge(u, v) {
                 sol.add(u);
                 sol.add(v);
                 edge_found = true;
@@ -132,20 +479,14 @@ where
         // compute max neighbour count inside S
         let mut best_edges = 0usize;
         for &v in &outsiders {
-            let e = graph.neigh_row(v)
-                .iter_ones()
-                .filter(|&u| sol.bitset()[u])
-                .count();
+            let e = graph.deg_into(v, sol.bitset());
             best_edges = best_edges.max(e);
         }
 
         // collect all outsiders achieving that max
-        let mut cand: Vec<usize> = outsiders.into_iter()
+        let cand: Vec<usize> = outsiders.into_iter()
             .filter(|&v| {
-                graph.neigh_row(v)
-                    .iter_ones()
-                    .filter(|&u| sol.bitset()[u])
-                    .count() == best_edges
+                graph.deg_into(v, sol.bitset()) == best_edges
             })
             .collect();
         if cand.is_empty() { break; }
@@ -172,11 +513,51 @@ where
     sol
 }
 
+/*───────────────────────────────────────────────────────────*/
+/*  Greedy clique                                            */
+/*───────────────────────────────────────────────────────────*/
+
+/// Greedy clique grown from each of the `starts` highest-degree vertices:
+/// repeatedly add the candidate adjacent to the most other candidates.
+/// Deterministic (no RNG); returns the largest clique found.
+pub fn greedy_clique(graph: &Graph, starts: usize) -> Solution<'_> {
+    let mut order: Vec<usize> = (0..graph.n()).collect();
+    order.sort_by_key(|&v| Reverse(graph.degree(v)));
+
+    let mut best = Solution::new(graph);
+    let mut cand = bitvec![0; graph.n()];
+    for &s in order.iter().take(starts) {
+        // no clique through s can beat the incumbent
+        if graph.degree(s) < best.size() {
+            break;
+        }
+        let mut sol = Solution::new(graph);
+        sol.add(s);
+        cand.fill(false);
+        for w in graph.neighbors(s) {
+            cand.set(w, true);
+        }
+        while let Some(c) = cand.iter_ones().max_by_key(|&c| graph.deg_into(c, &cand)) {
+            sol.add(c);
+            cand.set(c, false);
+            let drop: Vec<usize> = cand.iter_ones().filter(|&w| !graph.has_edge(c, w)).collect();
+            for w in drop {
+                cand.set(w, false);
+            }
+        }
+        if sol.size() > best.size() {
+            best = sol;
+        }
+    }
+    best
+}
+
 /*──────────────────────── tests ───────────────────────────*/
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testgraphs::clique_edges;
     use rand_chacha::ChaCha8Rng;
     use rand::SeedableRng;
     use std::io::Cursor;
@@ -186,6 +567,97 @@ mod tests {
         Graph::parse_dimacs(Cursor::new(dimacs)).unwrap()
     }
 
+    #[test]
+    fn community_start_is_the_dense_block() {
+        // K5 on 0..5 plus a sparse path 5-6-7-8-9 attached at 4
+        let mut edges = clique_edges(0..5);
+        edges.extend([(4, 5), (5, 6), (6, 7), (7, 8), (8, 9)]);
+        let g = Graph::from_edge_list(10, &edges);
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        let sol = community_k(&g, 4, &mut rng);
+        assert_eq!(sol.size(), 4);
+        assert!((sol.density() - 1.0).abs() < 1e-12);
+
+        let mut grown = community_k(&g, 6, &mut rng);
+        assert_eq!(grown.size(), 6);
+        resize_to_k(&mut grown, 2, &mut rng);
+        assert_eq!(grown.size(), 2);
+    }
+
+    #[test]
+    fn peeling_finds_the_clique() {
+        // K4 on 0..4, a pendant path 3-4-5 and an isolated vertex 6
+        let mut edges = clique_edges(0..4);
+        edges.extend([(3, 4), (4, 5)]);
+        let g = Graph::from_edge_list(7, &edges);
+        assert_eq!(densest_core(&g), vec![0, 1, 2, 3]);
+        assert!(densest_core(&Graph::with_vertices(0)).is_empty());
+
+        let mut rng = ChaCha8Rng::seed_from_u64(2);
+        let sol = densest_core_k(&g, 3, &mut rng);
+        assert_eq!((sol.size(), sol.edges()), (3, 3));
+
+        let clique = greedy_clique(&g, 16);
+        assert_eq!(clique.bitset().iter_ones().collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+        assert_eq!(greedy_clique(&Graph::with_vertices(0), 16).size(), 0);
+    }
+
+    #[test]
+    fn parallel_starts_sorted() {
+        let g = Graph::from_edge_list(6, &[(0, 1), (1, 2), (0, 2), (2, 3), (3, 4), (4, 5)]);
+        let mut rng = ChaCha8Rng::seed_from_u64(4);
+        let starts = construct_parallel(&g, 3, &Params::default(), 5, &mut rng);
+        assert_eq!(starts.len(), 5);
+        assert!(starts.iter().all(|(_, s)| s.size() == 3));
+        assert!(starts.windows(2).all(|w| w[0].1.density() >= w[1].1.density()));
+        assert!((starts[0].1.density() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn evaluate_every_constructor() {
+        let g = Graph::from_edge_list(6, &[(0, 1), (1, 2), (0, 2), (2, 3), (3, 4), (4, 5)]);
+        let stats = evaluate_all(&g, 3, &[1, 2, 3]);
+        assert_eq!(stats.len(), Constructor::ALL.len());
+        for st in &stats {
+            assert!(st.mean_density <= st.best_density + 1e-12);
+            assert!(st.best_density <= 1.0);
+        }
+        assert_eq!(stats[2].constructor, Constructor::DensestCore);
+        assert!((stats[2].best_density - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn avoiding_fill_prefers_uncovered() {
+        // two triangles 0-1-2 and 3-4-5 joined by 2-3
+        let g = Graph::from_edge_list(6, &[(0, 1), (1, 2), (0, 2), (3, 4), (4, 5), (3, 5), (2, 3)]);
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        let mut covered = bitvec![0; 6];
+        for v in [0, 1, 2] { covered.set(v, true); }
+
+        let sol = greedy_from_seed_avoiding(&g, 3, 3, &covered, &mut rng);
+        assert_eq!(sol.bitset().iter_ones().collect::<Vec<_>>(), vec![3, 4, 5]);
+        let sol = greedy_from_seed_avoiding(&g, 3, 4, &covered, &mut rng);
+        assert_eq!(sol.size(), 4);
+    }
+
+    #[test]
+    fn budget_swaps_out_costly_members() {
+        // two triangles 0-1-2 and 3-4-5 joined by 2-3; 0 and 1 are costly
+        let g = Graph::from_edge_list(6, &[(0, 1), (1, 2), (0, 2), (3, 4), (4, 5), (3, 5), (2, 3)]);
+        let budget = Budget { costs: vec![5.0, 5.0, 1.0, 1.0, 1.0, 1.0], limit: 3.0 };
+        let mut sol = Solution::new(&g);
+        for v in [0, 1, 2] { sol.add(v); }
+
+        fit_budget(&mut sol, &budget);
+        assert_eq!(sol.bitset().iter_ones().collect::<Vec<_>>(), vec![2, 3, 4]);
+        assert!(budget.allows(budget.cost_of(sol.bitset())));
+
+        let p = Params { budget: Some(budget.clone()), ..Params::default() };
+        let mut rng = ChaCha8Rng::seed_from_u64(3);
+        let (_, start) = Constructor::GreedyRandom.build(&g, 3, &p, &mut rng);
+        assert!(p.within_budget(&start));
+    }
+
     #[test]
     fn until_gamma_maximal() {
         let g = triangle();
@@ -0,0 +1,122 @@
+// src/crosscheck.rs
+//! Brute-force cross-checks of the fixed-k search (feature `cross-check`).
+//!
+//! On graphs of at most [`MAX_N`] vertices, [`search`](crate::restart)
+//! verifies
+//!
+//! • every U1-tight cut: no single swap of the run-best set reaches the
+//!   required edge count (otherwise the bound gave up on a run too early);
+//! • every returned set: its cached edge count matches a recount and does
+//!   not exceed the exhaustive optimum over all k-subsets;
+//! • a run that ends without a feasible set while one exists is logged as
+//!   a warning (the search is a heuristic, so this alone is not an error).
+//!
+//! Violations panic.  Meant for debug and test builds only: enumeration is
+//! exponential in n.
+
+use crate::{graph::Graph, solution::{edges_of, Solution}};
+
+/// Largest graph checked; C(20, 10) ≈ 1.8·10⁵ subsets.
+pub const MAX_N: usize = 20;
+
+/// Adjacency rows as bitmasks.
+fn masks(graph: &Graph) -> Vec<u32> {
+    (0..graph.n()).map(|v| graph.neighbors(v).fold(0u32, |m, w| m | 1 << w)).collect()
+}
+
+/// Most edges any k-subset of `graph` spans (n ≤ [`MAX_N`]).
+pub fn max_edges_exhaustive(graph: &Graph, k: usize) -> usize {
+    let n = graph.n();
+    assert!(n <= MAX_N, "exhaustive enumeration needs n ≤ {MAX_N}");
+    if k == 0 || k > n {
+        return 0;
+    }
+    let rows = masks(graph);
+    let end = 1u32 << n;
+    let mut set: u32 = (1 << k) - 1;
+    let mut best = 0;
+    while set < end {
+        let mut twice = 0;
+        let mut rest = set;
+        while rest != 0 {
+            let v = rest.trailing_zeros() as usize;
+            twice += (rows[v] & set).count_ones() as usize;
+            rest &= rest - 1;
+        }
+        best = best.max(twice / 2);
+        // next subset of the same size (Gosper's hack)
+        let low = set & set.wrapping_neg();
+        let ripple = set + low;
+        set = (((ripple ^ set) >> 2) / low) | ripple;
+    }
+    best
+}
+
+/// A U1-tight cut of `best_run`: assert that no swap reaches `needed`.
+pub fn u1_cut(best_run: &Solution<'_>, needed: usize) {
+    let graph = best_run.graph();
+    if graph.n() > MAX_N {
+        return;
+    }
+    let set = best_run.bitset();
+    for u in set.iter_ones() {
+        for v in set.iter_zeros() {
+            let edges = best_run.edges() - best_run.deg_in(u) + best_run.deg_in(v) - graph.has_edge(u, v) as usize;
+            assert!(
+                edges < needed,
+                "U1 cut at {} edges, but swapping {u} for {v} gives {edges} ≥ {needed}",
+                best_run.edges()
+            );
+        }
+    }
+}
+
+/// The set `search` returns for size `k`.
+pub fn result(graph: &Graph, k: usize, needed: usize, sol: &Solution<'_>) {
+    if graph.n() > MAX_N {
+        return;
+    }
+    let members: Vec<usize> = sol.bitset().iter_ones().collect();
+    assert_eq!(sol.edges(), edges_of(graph, &members), "cached edge count differs from a recount");
+    let best = max_edges_exhaustive(graph, k);
+    assert!(sol.edges() <= best, "{} edges found, but no {k}-subset spans more than {best}", sol.edges());
+    if sol.edges() < needed && best >= needed {
+        tracing::warn!(
+            target: "tsqc",
+            k,
+            found = sol.edges(),
+            optimum = best,
+            needed,
+            "cross-check: a feasible set exists but the search ended without one"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{params::Params, restart::solve_fixed_k};
+    use crate::testgraphs::clique_edges;
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn exhaustive_optimum_and_checked_solves() {
+        // K4 plus a pendant path
+        let g = Graph::from_edge_list(6, &[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3), (3, 4), (4, 5)]);
+        assert_eq!(max_edges_exhaustive(&g, 4), 6);
+        assert_eq!(max_edges_exhaustive(&g, 5), 7);
+        assert_eq!(max_edges_exhaustive(&g, 7), 0);
+
+        // random small graphs: every cut and result is checked inside
+        let mut rng = ChaCha8Rng::seed_from_u64(3);
+        for _ in 0..20 {
+            let n = rng.gen_range(6..=12);
+            let edges: Vec<_> = clique_edges(0..n).into_iter().filter(|_| rng.gen_bool(0.5)).collect();
+            let g = Graph::from_edge_list(n, &edges);
+            let k = rng.gen_range(3..=n);
+            let p = Params { gamma_target: 0.8, max_iter: 500, ..Params::default() };
+            solve_fixed_k(&g, k, &mut rng, &p);
+        }
+    }
+}
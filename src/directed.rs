@@ -0,0 +1,343 @@
+// src/directed.rs
+//! Directed γ-quasi-cliques (citation and follower networks).
+//!
+//! A [`DiGraph`] stores out- and in-neighbour rows, plus the underlying
+//! undirected [`Graph`] (an edge wherever an arc exists in either
+//! direction).  Density is taken over ordered pairs:
+//!
+//! ```text
+//! ρ(S) = m(S) / (k(k−1))          m(S) = arcs with both ends in S
+//! ```
+//!
+//! so only fully reciprocal sets reach ρ = 1.  The three rows take 3·n²
+//! bits; [`DiGraph::try_from_arc_list`] and the parsers check that against
+//! the memory limit first (see [`crate::memory`]).  The solvers run the tabu
+//! search of `setsearch` with the weight of v equal to its in- plus
+//! out-degree inside S; found sets are [`Solution`]s over
+//! [`DiGraph::underlying`].
+
+use crate::{
+    graph::Graph,
+    memory,
+    observer::{NoObserver, Observer, StopReason},
+    params::Params,
+    setsearch::{self, ScoredSet},
+    solution::Solution,
+};
+use bitvec::prelude::*;
+use rand::Rng;
+use std::io::{self, BufRead};
+use std::path::Path;
+
+/// Directed graph without self-loops or parallel arcs.
+#[derive(Clone, Debug)]
+pub struct DiGraph {
+    out: Vec<BitVec>,
+    inc: Vec<BitVec>,
+    und: Graph,
+    m:   usize,
+}
+
+/// |row ∩ set| by word-level AND + popcount.
+#[inline]
+fn count_into(row: &BitSlice, set: &BitSlice) -> usize {
+    const W: usize = usize::BITS as usize;
+    row.chunks(W)
+        .zip(set.chunks(W))
+        .map(|(a, b)| (a.load_le::<usize>() & b.load_le::<usize>()).count_ones() as usize)
+        .sum()
+}
+
+impl DiGraph {
+    /// Graph on `n` vertices from `(tail, head)` arcs; self-loops and
+    /// repeated arcs are ignored.
+    pub fn from_arc_list(n: usize, arcs: &[(usize, usize)]) -> Self {
+        let mut out = vec![bitvec![0; n]; n];
+        let mut inc = vec![bitvec![0; n]; n];
+        let mut und = Graph::with_vertices(n);
+        let mut m = 0;
+        for &(u, v) in arcs {
+            if u == v || out[u][v] {
+                continue;
+            }
+            out[u].set(v, true);
+            inc[v].set(u, true);
+            und.add_edge(u, v);
+            m += 1;
+        }
+        Self { out, inc, und, m }
+    }
+
+    /// [`DiGraph::from_arc_list`], refused with an `OutOfMemory` error when
+    /// the out, in and underlying rows exceed the memory limit or what is
+    /// available.
+    pub fn try_from_arc_list(n: usize, arcs: &[(usize, usize)]) -> io::Result<Self> {
+        const W: usize = usize::BITS as usize;
+        let needed = n.div_ceil(W).saturating_mul(W / 8).saturating_mul(n).saturating_mul(3);
+        if let Some(limit) = memory::allowance(needed)
+            && needed > limit
+        {
+            let mib = |b: usize| b as f64 / (1u64 << 20) as f64;
+            let msg = format!(
+                "n = {n} needs {:.1} MiB for the directed adjacency, only {:.1} MiB available",
+                mib(needed),
+                mib(limit)
+            );
+            return Err(io::Error::new(io::ErrorKind::OutOfMemory, msg));
+        }
+        Ok(Self::from_arc_list(n, arcs))
+    }
+
+    /// Parse an arc list: `u v` lines with 0-based ids (SNAP style, `#` /
+    /// `%` comments), or DIMACS-style `p` / `a u v` lines with 1-based ids.
+    /// Without a `p` line n is the largest id + 1.
+    pub fn parse_arc_list<R: BufRead>(reader: R) -> io::Result<Self> {
+        let invalid = |line: &str| {
+            io::Error::new(io::ErrorKind::InvalidData, format!("malformed arc: '{line}'"))
+        };
+        let mut arcs = Vec::new();
+        let mut n = 0;
+        for line in reader.lines() {
+            let line = line?;
+            let line = line.trim();
+            let mut it = line.split_whitespace();
+            let (dimacs, first) = match it.next() {
+                None => continue,
+                Some(t) if t.starts_with(['#', '%', 'c']) => continue,
+                Some("p") => {
+                    let n_tok = it.nth(1).ok_or_else(|| invalid(line))?;
+                    n = n.max(n_tok.parse().map_err(|_| invalid(line))?);
+                    continue;
+                }
+                Some("a") => (true, it.next()),
+                Some(t) => (false, Some(t)),
+            };
+            let (u, v) = match (first, it.next()) {
+                (Some(u), Some(v)) => (u, v),
+                _ => return Err(invalid(line)),
+            };
+            let mut u: usize = u.parse().map_err(|_| invalid(line))?;
+            let mut v: usize = v.parse().map_err(|_| invalid(line))?;
+            if dimacs {
+                if u == 0 || v == 0 {
+                    return Err(invalid(line));
+                }
+                u -= 1;
+                v -= 1;
+            }
+            n = n.max(u + 1).max(v + 1);
+            arcs.push((u, v));
+        }
+        Self::try_from_arc_list(n, &arcs)
+    }
+
+    /// [`DiGraph::parse_arc_list`] on a file.
+    pub fn read_arc_list<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::parse_arc_list(io::BufReader::new(std::fs::File::open(path)?))
+    }
+
+    pub fn n(&self) -> usize {
+        self.out.len()
+    }
+
+    /// Number of arcs.
+    pub fn m(&self) -> usize {
+        self.m
+    }
+
+    pub fn has_arc(&self, u: usize, v: usize) -> bool {
+        self.out[u][v]
+    }
+
+    pub fn out_degree(&self, v: usize) -> usize {
+        self.out[v].count_ones()
+    }
+
+    pub fn in_degree(&self, v: usize) -> usize {
+        self.inc[v].count_ones()
+    }
+
+    /// Undirected graph with an edge wherever an arc exists either way.
+    pub fn underlying(&self) -> &Graph {
+        &self.und
+    }
+
+    /// Arcs with both ends in `vertices` (duplicates are ignored).
+    pub fn arcs_of(&self, vertices: &[usize]) -> usize {
+        let mut mask = bitvec![0; self.n()];
+        for &v in vertices {
+            mask.set(v, true);
+        }
+        mask.iter_ones().map(|v| count_into(&self.out[v], &mask)).sum()
+    }
+
+    /// m(S) / (k(k−1)) over distinct vertices; 0 for fewer than two.
+    pub fn density_of(&self, vertices: &[usize]) -> f64 {
+        let mut distinct = vertices.to_vec();
+        distinct.sort_unstable();
+        distinct.dedup();
+        let k = distinct.len();
+        if k < 2 { 0.0 }
+        else { self.arcs_of(&distinct) as f64 / (k * (k - 1)) as f64 }
+    }
+}
+
+/// Vertex set with its internal arc count.
+#[derive(Clone)]
+struct Arcs<'g> {
+    graph: &'g DiGraph,
+    sol:   Solution<'g>,
+    arcs:  usize,
+}
+
+impl<'g> Arcs<'g> {
+    fn new(graph: &'g DiGraph) -> Self {
+        Self { graph, sol: Solution::new(&graph.und), arcs: 0 }
+    }
+
+    /// In- plus out-degree of `v` inside the set.
+    fn degree_in(&self, v: usize) -> usize {
+        let set = self.sol.bitset();
+        count_into(&self.graph.out[v], set) + count_into(&self.graph.inc[v], set)
+    }
+}
+
+impl<'g> ScoredSet<'g> for Arcs<'g> {
+    fn n(&self) -> usize {
+        self.graph.n()
+    }
+
+    fn contains(&self, v: usize) -> bool {
+        self.sol.bitset()[v]
+    }
+
+    fn size(&self) -> usize {
+        self.sol.size()
+    }
+
+    fn add(&mut self, v: usize) {
+        self.arcs += self.degree_in(v);
+        self.sol.add(v);
+    }
+
+    fn remove(&mut self, v: usize) {
+        self.sol.remove(v);
+        self.arcs -= self.degree_in(v);
+    }
+
+    fn score(&self) -> f64 {
+        self.arcs as f64
+    }
+
+    fn weight(&self, v: usize) -> f64 {
+        self.degree_in(v) as f64
+    }
+
+    fn pair(&self, u: usize, v: usize) -> f64 {
+        (self.graph.has_arc(u, v) as usize + self.graph.has_arc(v, u) as usize) as f64
+    }
+
+    fn solution(&self) -> &Solution<'g> {
+        &self.sol
+    }
+}
+
+/// Arcs a k-set needs for directed density γ.
+fn target(k: usize, gamma: f64) -> f64 {
+    (gamma * (k * k.saturating_sub(1)) as f64).ceil()
+}
+
+/// Densest k-set (ordered-pair density) found; returned as a [`Solution`]
+/// over [`DiGraph::underlying`] (empty if k is out of range).
+pub fn solve_directed_k<'g, R>(graph: &'g DiGraph, k: usize, rng: &mut R, p: &Params) -> Solution<'g>
+where
+    R: Rng + ?Sized,
+{
+    solve_directed_k_with(graph, k, rng, p, &mut NoObserver)
+}
+
+/// [`solve_directed_k`] reporting to `obs`.
+pub fn solve_directed_k_with<'g, R, O>(
+    graph: &'g DiGraph,
+    k: usize,
+    rng: &mut R,
+    p: &Params,
+    obs: &mut O,
+) -> Solution<'g>
+where
+    R: Rng + ?Sized,
+    O: Observer + ?Sized,
+{
+    if k < 2 || k > graph.n() {
+        obs.on_finish(StopReason::Infeasible);
+        return Solution::new(&graph.und);
+    }
+    setsearch::solve_k(&Arcs::new(graph), k, target(k, p.gamma_target), rng, p, obs).sol
+}
+
+/// Largest directed γ-quasi-clique found.
+pub fn solve_directed_maxk<'g, R>(graph: &'g DiGraph, rng: &mut R, p: &Params) -> Solution<'g>
+where
+    R: Rng + ?Sized,
+{
+    solve_directed_maxk_with(graph, rng, p, &mut NoObserver)
+}
+
+/// [`solve_directed_maxk`] with an observer (feasible incumbents only).
+pub fn solve_directed_maxk_with<'g, R, O>(graph: &'g DiGraph, rng: &mut R, p: &Params, obs: &mut O) -> Solution<'g>
+where
+    R: Rng + ?Sized,
+    O: Observer + ?Sized,
+{
+    setsearch::solve_maxk(&Arcs::new(graph), |k| target(k, p.gamma_target), rng, p, obs).sol
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testgraphs::clique_edges;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn reciprocal_core_beats_one_way_clique() {
+        // 0..4 follow each other both ways; 4..9 is a one-way tournament
+        let mut arcs: Vec<_> = clique_edges(0..4).into_iter().flat_map(|(u, v)| [(u, v), (v, u)]).collect();
+        arcs.extend(clique_edges(4..9));
+        arcs.push((3, 4));
+        let g = DiGraph::from_arc_list(9, &arcs);
+        assert_eq!(g.m(), 12 + 10 + 1);
+        assert_eq!(g.underlying().m(), 6 + 10 + 1);
+        assert_eq!(g.density_of(&[4, 5, 6, 7, 8]), 0.5);
+        assert_eq!((g.out_degree(3), g.in_degree(4)), (4, 1));
+
+        let p = Params { gamma_target: 0.9, max_iter: 10_000, ..Params::default() };
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        let sol = solve_directed_maxk(&g, &mut rng, &p);
+        assert_eq!(sol.bitset().iter_ones().collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+
+        let sol = solve_directed_k(&g, 5, &mut rng, &Params { gamma_target: 0.5, ..p });
+        let members: Vec<usize> = sol.bitset().iter_ones().collect();
+        assert!(g.density_of(&members) >= 0.5);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn refuses_adjacency_beyond_memory() {
+        let e = DiGraph::parse_arc_list("p edge 1000000000 1\na 1 2\n".as_bytes()).unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::OutOfMemory);
+        assert!(e.to_string().contains("directed adjacency"));
+    }
+
+    #[test]
+    fn parse_both_arc_formats() {
+        let snap = DiGraph::parse_arc_list("# FromNodeId ToNodeId\n0 1\n1 0\n2 1\n".as_bytes()).unwrap();
+        assert_eq!((snap.n(), snap.m()), (3, 3));
+        assert!(snap.has_arc(2, 1) && !snap.has_arc(1, 2));
+
+        let dimacs = DiGraph::parse_arc_list("p sp 4 2\na 1 2\na 3 1\n".as_bytes()).unwrap();
+        assert_eq!((dimacs.n(), dimacs.m()), (4, 2));
+        assert!(dimacs.has_arc(2, 0));
+        assert!(DiGraph::parse_arc_list("a 0 1\n".as_bytes()).is_err());
+    }
+}
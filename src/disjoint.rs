@@ -0,0 +1,241 @@
+// src/disjoint.rs
+//! d vertex-disjoint γ-quasi-cliques of maximum total size.
+//!
+//! Sequential extraction (solve max-k, delete the set, repeat) lets the
+//! first set take vertices the later ones needed.  [`solve_disjoint`]
+//! starts from it and then alternates tabu phases under exclusion
+//! constraints (every set lives in the subgraph induced by the vertices
+//! no other set holds):
+//!
+//! 1. Sequential extraction gives S₁ … S_d.
+//! 2. For each i, a *release* phase frees S_i; every other set is grown
+//!    one vertex at a time by warm-started fixed-k searches
+//!    ([`solve_fixed_k_from`]) into what became free (each step keeping at
+//!    least half of the set it grew from), then S_i is solved
+//!    anew (max-k) in the vertices left.  The trial is kept if the total
+//!    size grew.
+//! 3. Rounds of step 2 repeat until one brings no gain or `rounds` is hit.
+//!
+//! A budget (`p.budget`) applies to each set on its own.
+//!
+//! [`membership_scores`] runs the sequential extraction alone and reports,
+//! per vertex, the quasi-clique it ended up in (a node-level feature).
+
+use crate::{
+    construct::resize_to_k,
+    graph::Graph,
+    maxk::solve_maxk,
+    observer::NoObserver,
+    params::Params,
+    restart::solve_fixed_k_from,
+    solution::Solution,
+};
+use bitvec::prelude::*;
+use rand::Rng;
+
+/// Vertices outside every set of `sets` except `keep`.
+fn available(n: usize, sets: &[Vec<usize>], keep: usize) -> Vec<usize> {
+    let mut taken = bitvec![0; n];
+    for (j, s) in sets.iter().enumerate() {
+        if j != keep {
+            for &v in s {
+                taken.set(v, true);
+            }
+        }
+    }
+    taken.iter_zeros().collect()
+}
+
+/// Largest set found in the subgraph induced by `avail`, in original ids.
+fn solve_within<R>(graph: &Graph, avail: &[usize], rng: &mut R, p: &Params) -> Vec<usize>
+where
+    R: Rng + ?Sized,
+{
+    if avail.is_empty() {
+        return Vec::new();
+    }
+    let sub = graph.induced_subgraph(avail);
+    let sol = solve_maxk(&sub, rng, &p.restricted(avail));
+    sol.bitset().iter_ones().map(|v| avail[v]).collect()
+}
+
+/// Grow the feasible set `current` ⊆ `avail` while warm-started fixed-k
+/// searches in the subgraph induced by `avail` stay feasible and keep at
+/// least half of the set they grew from (so a set does not drift into the
+/// one that was just released).
+fn grow_within<R>(graph: &Graph, avail: &[usize], current: &[usize], rng: &mut R, p: &Params) -> Vec<usize>
+where
+    R: Rng + ?Sized,
+{
+    if current.is_empty() {
+        return solve_within(graph, avail, rng, p);
+    }
+    let sub = graph.induced_subgraph(avail);
+    let p = &p.restricted(avail);
+    let mut best = Solution::new(&sub);
+    for &v in current {
+        best.add(avail.binary_search(&v).expect("set lies inside the available vertices"));
+    }
+    while best.size() < sub.n() {
+        let mut start = best.clone();
+        resize_to_k(&mut start, best.size() + 1, rng);
+        let sol = solve_fixed_k_from(start, rng, p, &mut NoObserver);
+        let kept = (sol.bitset().clone() & best.bitset()).count_ones();
+        if !p.accepts(&sol) || 2 * kept < best.size() {
+            break;
+        }
+        best = sol;
+    }
+    best.bitset().iter_ones().map(|v| avail[v]).collect()
+}
+
+/// Sequential extraction: up to `d` sets, each the largest one found
+/// among the vertices the previous ones left; stops early at a set of
+/// fewer than `min_size` vertices (which is not kept).
+fn extract<R>(graph: &Graph, d: usize, min_size: usize, rng: &mut R, p: &Params) -> Vec<Vec<usize>>
+where
+    R: Rng + ?Sized,
+{
+    let mut sets: Vec<Vec<usize>> = Vec::with_capacity(d);
+    while sets.len() < d {
+        let avail = available(graph.n(), &sets, usize::MAX);
+        if avail.len() < min_size {
+            break;
+        }
+        let set = solve_within(graph, &avail, rng, p);
+        if set.len() < min_size {
+            break;
+        }
+        sets.push(set);
+    }
+    sets
+}
+
+fn total(sets: &[Vec<usize>]) -> usize {
+    sets.iter().map(Vec::len).sum()
+}
+
+/// Up to `d` vertex-disjoint γ-quasi-cliques (per `p`) of large total
+/// size, after at most `rounds` rounds of release phases; largest first.
+/// Sets may be empty when the graph runs out of vertices.
+pub fn solve_disjoint<'g, R>(graph: &'g Graph, d: usize, rounds: usize, rng: &mut R, p: &Params) -> Vec<Solution<'g>>
+where
+    R: Rng + ?Sized,
+{
+    let n = graph.n();
+    // 1) sequential extraction
+    let mut sets = extract(graph, d, 0, rng, p);
+
+    // 2–3) release phases
+    for _ in 0..rounds {
+        let mut improved = false;
+        for i in 0..d {
+            let mut trial = sets.clone();
+            trial[i].clear();
+            for j in (0..d).filter(|&j| j != i) {
+                let avail = available(n, &trial, j);
+                trial[j] = grow_within(graph, &avail, &trial[j], rng, p);
+            }
+            let avail = available(n, &trial, i);
+            trial[i] = solve_within(graph, &avail, rng, p);
+            if total(&trial) > total(&sets) {
+                sets = trial;
+                improved = true;
+            }
+        }
+        if !improved {
+            break;
+        }
+    }
+
+    sets.sort_by_key(|s| std::cmp::Reverse(s.len()));
+    sets.into_iter()
+        .map(|s| {
+            let mut sol = Solution::new(graph);
+            for v in s {
+                sol.add(v);
+            }
+            sol
+        })
+        .collect()
+}
+
+/// The quasi-clique a vertex was extracted with.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Membership {
+    /// 0-based extraction round (sets come out largest-first in practice).
+    pub set:     usize,
+    pub size:    usize,
+    pub density: f64,
+}
+
+/// For every vertex, the γ-quasi-clique (per `p`) it lies in after at most
+/// `max_sets` rounds of sequential extraction (`None` if none took it).
+/// Extraction stops early once the best set left has fewer than two
+/// vertices.
+pub fn membership_scores<R>(graph: &Graph, max_sets: usize, rng: &mut R, p: &Params) -> Vec<Option<Membership>>
+where
+    R: Rng + ?Sized,
+{
+    let mut scores = vec![None; graph.n()];
+    for (set, members) in extract(graph, max_sets, 2, rng, p).iter().enumerate() {
+        let m = Membership { set, size: members.len(), density: crate::solution::density_of(graph, members) };
+        for &v in members {
+            scores[v] = Some(m);
+        }
+    }
+    scores
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn clique(edges: &mut Vec<(usize, usize)>, vs: &[usize]) {
+        for (i, &u) in vs.iter().enumerate() {
+            for &v in &vs[i + 1..] {
+                edges.push((u, v));
+            }
+        }
+    }
+
+    #[test]
+    fn release_beats_sequential_extraction() {
+        // K6 {0..6} overlaps K5 {0,1,6,7,8} and K5 {2,3,9,10,11}: taking the
+        // K6 first leaves two triangles (6 + 3), the two K5s give 10
+        let mut edges = Vec::new();
+        clique(&mut edges, &[0, 1, 2, 3, 4, 5]);
+        clique(&mut edges, &[0, 1, 6, 7, 8]);
+        clique(&mut edges, &[2, 3, 9, 10, 11]);
+        let g = Graph::from_edge_list(12, &edges);
+        let p = Params { gamma_target: 1.0, max_iter: 2_000, ..Params::default() };
+
+        let seq = solve_disjoint(&g, 2, 0, &mut ChaCha8Rng::seed_from_u64(0), &p);
+        assert_eq!(seq.iter().map(|s| s.size()).collect::<Vec<_>>(), vec![6, 3]);
+
+        let sets = solve_disjoint(&g, 2, 5, &mut ChaCha8Rng::seed_from_u64(0), &p);
+        assert_eq!(sets.iter().map(|s| s.size()).sum::<usize>(), 10);
+        assert!((sets[0].bitset().clone() & sets[1].bitset()).not_any());
+        assert!(sets.iter().all(|s| s.is_gamma_feasible(1.0)));
+    }
+
+    #[test]
+    fn membership_per_vertex() {
+        // K5 {0..5} and a triangle {5, 6, 7}; 8 is isolated
+        let mut edges = Vec::new();
+        clique(&mut edges, &[0, 1, 2, 3, 4]);
+        clique(&mut edges, &[5, 6, 7]);
+        let g = Graph::from_edge_list(9, &edges);
+        let p = Params { gamma_target: 1.0, max_iter: 2_000, ..Params::default() };
+
+        let scores = membership_scores(&g, 5, &mut ChaCha8Rng::seed_from_u64(0), &p);
+        assert!(scores[..5].iter().all(|m| *m == Some(Membership { set: 0, size: 5, density: 1.0 })));
+        assert!(scores[5..8].iter().all(|m| *m == Some(Membership { set: 1, size: 3, density: 1.0 })));
+        assert_eq!(scores[8], None);
+
+        let scores = membership_scores(&g, 1, &mut ChaCha8Rng::seed_from_u64(0), &p);
+        assert!(scores[5..].iter().all(Option::is_none));
+    }
+}
@@ -3,13 +3,15 @@
 //!
 //! ▲ heavy_perturbation: “large shake”
 //! ▲ mild_perturbation: “small shake”
+//! ▲ oscillate: strategic oscillation to k ± δ and back
+//! ▲ decay_frequencies: halve the long-term memory (periodic decay)
 //!
 //! After each perturbation we:
 //!  1. Increment long‐term frequency memory for swapped vertices.
 //!  2. Reset the tabu lists.
 //!  3. Recompute tabu tenures based on the new solution.
 
-use crate::{params::Params, solution::Solution, tabu::DualTabu, Graph};
+use crate::{construct::resize_to_k, params::Params, solution::Solution, tabu::DualTabu};
 use rand::seq::SliceRandom;
 use rand::Rng;
 use std::f64;
@@ -26,7 +28,22 @@ pub fn heavy_perturbation<'g, R>(
     tabu: &mut DualTabu,
     rng: &mut R,
     p: &Params,
-    freq: &mut Vec<usize>,
+    freq: &mut [usize],
+) where
+    R: Rng + ?Sized,
+{
+    heavy_perturbation_n(sol, tabu, rng, p, freq, 1);
+}
+
+/// [`heavy_perturbation`] replacing `swaps` vertices: steps 1 and 3–5 are
+/// repeated `swaps` times (at least once) before the tabu reset.
+pub fn heavy_perturbation_n<'g, R>(
+    sol: &mut Solution<'g>,
+    tabu: &mut DualTabu,
+    rng: &mut R,
+    p: &Params,
+    freq: &mut [usize],
+    swaps: usize,
 ) where
     R: Rng + ?Sized,
 {
@@ -35,15 +52,6 @@ pub fn heavy_perturbation<'g, R>(
         return;
     }
 
-    // 1) pick and remove random u ∈ S
-    let u = *sol
-        .bitset()
-        .iter_ones()
-        .collect::<Vec<_>>()
-        .choose(rng)
-        .expect("Solution must be non-empty");
-    sol.remove(u);
-
     // 2) threshold h, density‐based
     let n = sol.graph().n();
     let m = sol.graph().m();
@@ -55,59 +63,55 @@ pub fn heavy_perturbation<'g, R>(
     }
     .clamp(1, k.saturating_sub(1));
 
-    // 3) collect outsiders
-    let outsiders: Vec<usize> = (0..n).filter(|&v| !sol.bitset()[v]).collect();
-
-    let mut candidates: Vec<usize> = outsiders
-        .iter()
-        .copied()
-        .filter(|&v| {
-            sol.graph()
-                .neigh_row(v)
-                .iter_ones()
-                .filter(|&j| sol.bitset()[j])
-                .count()
-                < h
-        })
-        .collect();
+    for _ in 0..swaps.max(1) {
+        // 1) pick and remove random u ∈ S
+        let u = *sol
+            .bitset()
+            .iter_ones()
+            .collect::<Vec<_>>()
+            .choose(rng)
+            .expect("Solution must be non-empty");
+        sol.remove(u);
 
-    // fallback to minimal deg_in if none < h
-    if candidates.is_empty() {
-        let min_deg = outsiders
-            .iter()
-            .map(|&v| {
-                sol.graph()
-                    .neigh_row(v)
-                    .iter_ones()
-                    .filter(|&j| sol.bitset()[j])
-                    .count()
-            })
-            .min()
-            .unwrap_or(0);
-        candidates = outsiders
-            .into_iter()
-            .filter(|&v| {
-                sol.graph()
-                    .neigh_row(v)
-                    .iter_ones()
-                    .filter(|&j| sol.bitset()[j])
-                    .count()
-                    == min_deg
-            })
-            .collect();
-    }
-
-    // 4) add random v
-    let &v = candidates
-        .choose(rng)
-        .expect("At least one outsider must exist");
-    sol.add(v);
+        // 3) collect outsiders
+        let v = match sol.frontier() {
+            Some(front) => low_degree_outsider(sol, front, h, rng),
+            None => {
+                let outsiders: Vec<usize> = (0..n).filter(|&v| !sol.bitset()[v]).collect();
 
-    // 5) update frequency memory
-    freq[u] = freq[u].saturating_add(1);
-    freq[v] = freq[v].saturating_add(1);
-    if freq[u] > k || freq[v] > k {
-        freq.fill(0);
+                let mut candidates: Vec<usize> = outsiders
+                    .iter()
+                    .copied()
+                    .filter(|&v| sol.deg_in(v) < h)
+                    .collect();
+
+                // fallback to minimal deg_in if none < h
+                if candidates.is_empty() {
+                    let min_deg = outsiders
+                        .iter()
+                        .map(|&v| sol.deg_in(v))
+                        .min()
+                        .unwrap_or(0);
+                    candidates = outsiders
+                        .into_iter()
+                        .filter(|&v| sol.deg_in(v) == min_deg)
+                        .collect();
+                }
+                *candidates
+                    .choose(rng)
+                    .expect("At least one outsider must exist")
+            }
+        };
+
+        // 4) add random v
+        sol.add(v);
+
+        // 5) update frequency memory
+        freq[u] = freq[u].saturating_add(1);
+        freq[v] = freq[v].saturating_add(1);
+        if freq[u] > k || freq[v] > k {
+            freq.fill(0);
+        }
     }
 
     // 6) reset tabu and update tenures
@@ -115,6 +119,34 @@ pub fn heavy_perturbation<'g, R>(
     tabu.update_tenures(sol.size(), sol.edges(), p.gamma_target, rng);
 }
 
+/// Step 3 of [`heavy_perturbation`] with frontier tracking: a uniform
+/// pick among outsiders with `deg_in < h` without scanning all of V.
+/// Outsiders off the frontier (`deg_in = 0 < h`) are drawn by rejection.
+fn low_degree_outsider<R>(sol: &Solution<'_>, front: &[usize], h: usize, rng: &mut R) -> usize
+where
+    R: Rng + ?Sized,
+{
+    let n = sol.graph().n();
+    let low: Vec<usize> = front.iter().copied().filter(|&v| sol.deg_in(v) < h).collect();
+    let off_front = n - sol.size() - front.len();
+    if low.is_empty() && off_front == 0 {
+        // fallback to minimal deg_in (all outsiders are on the frontier)
+        let min_deg = front.iter().map(|&v| sol.deg_in(v)).min().expect("At least one outsider must exist");
+        let cand: Vec<usize> = front.iter().copied().filter(|&v| sol.deg_in(v) == min_deg).collect();
+        return *cand.choose(rng).unwrap();
+    }
+    let r = rng.gen_range(0..low.len() + off_front);
+    if r < low.len() {
+        return low[r];
+    }
+    loop {
+        let v = rng.gen_range(0..n);
+        if !sol.bitset()[v] && sol.deg_in(v) == 0 {
+            return v;
+        }
+    }
+}
+
 /// Mild perturbation (“small shake”):
 /// 1. Build critical sets A (u ∈ S with minimal deg_in) and B (v ∉ S with maximal deg_in).
 /// 2. Pick random `u ∈ A`, `v ∈ B` and swap them.
@@ -125,7 +157,7 @@ pub fn mild_perturbation<'g, R>(
     tabu: &mut DualTabu,
     rng: &mut R,
     p: &Params,
-    freq: &mut Vec<usize>,
+    freq: &mut [usize],
 ) where
     R: Rng + ?Sized,
 {
@@ -133,60 +165,34 @@ pub fn mild_perturbation<'g, R>(
     if k < 1 {
         return;
     }
-    let graph = sol.graph();
-    let n = graph.n();
 
     // 1) critical set A: u ∈ S of minimal internal degree
     let mut min_in = usize::MAX;
     for u in sol.bitset().iter_ones() {
-        let d = graph
-            .neigh_row(u)
-            .iter_ones()
-            .filter(|&j| sol.bitset()[j])
-            .count();
+        let d = sol.deg_in(u);
         min_in = min_in.min(d);
     }
-    let A: Vec<usize> = sol
+    let a_set: Vec<usize> = sol
         .bitset()
         .iter_ones()
         .filter(|&u| {
-            graph
-                .neigh_row(u)
-                .iter_ones()
-                .filter(|&j| sol.bitset()[j])
-                .count()
-                == min_in
+            sol.deg_in(u) == min_in
         })
         .collect();
 
     // 2) critical set B: v ∉ S of maximal internal degree into S
     let mut max_out = 0;
-    for v in 0..n {
-        if sol.bitset()[v] {
-            continue;
-        }
-        let d = graph
-            .neigh_row(v)
-            .iter_ones()
-            .filter(|&j| sol.bitset()[j])
-            .count();
-        max_out = max_out.max(d);
-    }
-    let B: Vec<usize> = (0..n)
-        .filter(|&v| {
-            !sol.bitset()[v]
-                && graph
-                    .neigh_row(v)
-                    .iter_ones()
-                    .filter(|&j| sol.bitset()[j])
-                    .count()
-                    == max_out
-        })
+    for v in sol.outsiders_to_scan() {
+        max_out = max_out.max(sol.deg_in(v));
+    }
+    let b_set: Vec<usize> = sol
+        .outsiders_to_scan()
+        .filter(|&v| sol.deg_in(v) == max_out)
         .collect();
 
     // swap random u∈A, v∈B
-    let &u = A.choose(rng).expect("A must be non-empty");
-    let &v = B.choose(rng).expect("B must be non-empty");
+    let &u = a_set.choose(rng).expect("A must be non-empty");
+    let &v = b_set.choose(rng).expect("B must be non-empty");
     sol.remove(u);
     sol.add(v);
 
@@ -201,3 +207,106 @@ pub fn mild_perturbation<'g, R>(
     tabu.reset();
     tabu.update_tenures(sol.size(), sol.edges(), p.gamma_target, rng);
 }
+
+/// Halve every long-term frequency count, so restart seeding reflects
+/// recent history; called every `p.freq_decay_interval` iterations.
+pub fn decay_frequencies(freq: &mut [usize]) {
+    for f in freq.iter_mut() {
+        *f /= 2;
+    }
+}
+
+/// Strategic oscillation (replaces the mild shake when `p.oscillation_delta`
+/// = δ > 0):
+/// 1. With probability ½ grow S to k+δ by adding best outsiders (max
+///    deg_in), otherwise shrink it to k−δ by dropping weakest members.
+/// 2. Return to size k the opposite way (prune weakest / add best).
+/// 3. Increment `freq` for every vertex whose membership changed; if any
+///    `> k`, reset all to 0.
+/// 4. Clear tabu lists and then update tenures.
+pub fn oscillate<'g, R>(
+    sol: &mut Solution<'g>,
+    tabu: &mut DualTabu,
+    rng: &mut R,
+    p: &Params,
+    freq: &mut [usize],
+) where
+    R: Rng + ?Sized,
+{
+    let k = sol.size();
+    let n = sol.graph().n();
+    if k < 1 || p.oscillation_delta == 0 {
+        return;
+    }
+    let before = sol.bitset().clone();
+
+    // 1–2) drift to k ± δ and back
+    let up = rng.gen_bool(0.5);
+    let target = if up {
+        (k + p.oscillation_delta).min(n)
+    } else {
+        k.saturating_sub(p.oscillation_delta).max(1)
+    };
+    resize_to_k(sol, target, rng);
+    resize_to_k(sol, k, rng);
+
+    // 3) update frequency memory
+    let mut reset = false;
+    for v in (before ^ sol.bitset()).iter_ones() {
+        freq[v] = freq[v].saturating_add(1);
+        reset |= freq[v] > k;
+    }
+    if reset {
+        freq.fill(0);
+    }
+
+    // 4) reset tabu and update tenures
+    tabu.reset();
+    tabu.update_tenures(sol.size(), sol.edges(), p.gamma_target, rng);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Graph;
+    use crate::testgraphs::clique_edges;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn oscillation_keeps_size() {
+        // K4 on 0..4 and a pendant path 3-4-5-6; S starts off the clique
+        let mut edges = clique_edges(0..4);
+        edges.extend([(3, 4), (4, 5), (5, 6)]);
+        let g = Graph::from_edge_list(7, &edges);
+        let p = Params { oscillation_delta: 2, ..Params::default() };
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        let mut tabu = DualTabu::new(7, 1, 1);
+        let mut freq = vec![0; 7];
+
+        let mut sol = Solution::new(&g);
+        for v in [2, 4, 5, 6] { sol.add(v); }
+        for _ in 0..4 {
+            oscillate(&mut sol, &mut tabu, &mut rng, &p, &mut freq);
+            assert_eq!(sol.size(), 4);
+        }
+        assert!(freq.iter().any(|&f| f > 0));
+    }
+
+    #[test]
+    fn heavy_swaps_several_vertices() {
+        let g = Graph::from_edge_list(8, &[(0, 1), (1, 2), (2, 3), (4, 5), (5, 6), (6, 7)]);
+        let p = Params::default();
+        let mut rng = ChaCha8Rng::seed_from_u64(2);
+        let mut tabu = DualTabu::new(8, 1, 1);
+        let mut freq = vec![0; 8];
+
+        let mut sol = Solution::new(&g);
+        for v in 0..4 { sol.add(v); }
+        let before = sol.bitset().clone();
+        heavy_perturbation_n(&mut sol, &mut tabu, &mut rng, &p, &mut freq, 3);
+        assert_eq!(sol.size(), 4);
+        let changed = (before ^ sol.bitset()).count_ones();
+        assert!((2..=6).contains(&changed));
+    }
+}
@@ -0,0 +1,71 @@
+// src/ego.rs
+//! Dense groups around given vertices ("the densest group around each of
+//! these accounts").
+//!
+//! [`solve_ego`] takes the ball of radius r around every center
+//! ([`Graph::ego_network`]), runs max-k inside it and maps the result back
+//! to the original ids.  Centers are solved in parallel on the pool for
+//! `p.threads` (see [`crate::threads`]); center i draws from stream i of
+//! the seed, so results do not depend on the thread count.
+
+use crate::{graph::Graph, maxk::solve_maxk, params::Params, rng::SolverRng, solution::Solution, threads};
+use rayon::prelude::*;
+
+/// Largest γ-quasi-clique (per `p`) found within distance `radius` of
+/// each center, in the order of `centers`.  The set need not contain its
+/// center; a center whose ball has no other vertex gets the set {center}.
+///
+/// Panics if a center is out of range.
+pub fn solve_ego<'g>(graph: &'g Graph, centers: &[usize], radius: usize, seed: u64, p: &Params) -> Vec<Solution<'g>> {
+    assert!(centers.iter().all(|&c| c < graph.n()), "center out of range");
+    let found: Vec<Vec<usize>> = threads::install(p.threads, || {
+        centers
+            .par_iter()
+            .enumerate()
+            .map(|(i, &c)| {
+                let (ego, map) = graph.ego_network(c, radius);
+                if ego.n() < 2 {
+                    return vec![c];
+                }
+                let mut rng = SolverRng::with_stream(p.rng, seed, i as u64);
+                let sol = solve_maxk(&ego, &mut rng, p);
+                sol.bitset().iter_ones().map(|v| map[v]).collect()
+            })
+            .collect()
+    });
+    found
+        .into_iter()
+        .map(|members| {
+            let mut sol = Solution::new(graph);
+            for v in members {
+                sol.add(v);
+            }
+            sol
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn one_group_per_center() {
+        // K4 {0..4} – 4 – 5 – K3 {5, 6, 7}; 8 isolated
+        let edges = [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3), (3, 4), (4, 5), (5, 6), (5, 7), (6, 7)];
+        let g = Graph::from_edge_list(9, &edges);
+        let p = Params { gamma_target: 1.0, max_iter: 2_000, ..Params::default() };
+
+        let sets = solve_ego(&g, &[0, 6, 8, 4], 1, 7, &p);
+        let members: Vec<Vec<usize>> = sets.iter().map(|s| s.bitset().iter_ones().collect()).collect();
+        assert_eq!(members[0], vec![0, 1, 2, 3]);
+        assert_eq!(members[1], vec![5, 6, 7]);
+        assert_eq!(members[2], vec![8]);
+        // the ball {3, 4, 5} holds edges only
+        assert_eq!(members[3].len(), 2);
+
+        // same streams, other pool size
+        let again = solve_ego(&g, &[0, 6, 8, 4], 1, 7, &Params { threads: 1, ..p });
+        assert!(again.iter().zip(&sets).all(|(a, b)| a.bitset() == b.bitset()));
+    }
+}
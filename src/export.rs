@@ -0,0 +1,120 @@
+// src/export.rs
+//! Visualisation export of a found quasi-clique.
+//!
+//! • `write_dot` – GraphViz DOT, members filled and internal edges bold
+//! • `write_graphml` – GraphML with boolean `in_set` (nodes) and
+//!   `internal` (edges) attributes, for Gephi / yEd / networkx
+//!
+//! Both write the subgraph induced by the set, optionally widened by its
+//! 1-hop neighbourhood (every vertex adjacent to a member).  Vertex ids are
+//! the 0-based graph indices.
+
+use crate::graph::Graph;
+use std::io::{self, Write};
+
+/// Vertices to draw (ascending) and a membership flag per graph vertex.
+fn view(graph: &Graph, members: &[usize], neighbourhood: bool) -> (Vec<usize>, Vec<bool>) {
+    let mut in_set = vec![false; graph.n()];
+    let mut shown = vec![false; graph.n()];
+    for &v in members {
+        in_set[v] = true;
+        shown[v] = true;
+        if neighbourhood {
+            for w in graph.neighbors(v) {
+                shown[w] = true;
+            }
+        }
+    }
+    let verts = (0..graph.n()).filter(|&v| shown[v]).collect();
+    (verts, in_set)
+}
+
+/// Edges `(u, v)`, `u < v`, among `verts` (ascending).
+fn edges_among(graph: &Graph, verts: &[usize]) -> Vec<(usize, usize)> {
+    let mut out = Vec::new();
+    for (i, &u) in verts.iter().enumerate() {
+        for &v in &verts[i + 1..] {
+            if graph.has_edge(u, v) {
+                out.push((u, v));
+            }
+        }
+    }
+    out
+}
+
+/// Write the set (and optionally its neighbourhood) as an undirected DOT graph.
+pub fn write_dot<W: Write>(
+    graph: &Graph,
+    members: &[usize],
+    neighbourhood: bool,
+    mut w: W,
+) -> io::Result<()> {
+    let (verts, in_set) = view(graph, members, neighbourhood);
+    writeln!(w, "graph tsqc {{")?;
+    writeln!(w, "  node [shape=circle, style=filled, fillcolor=white];")?;
+    for &v in &verts {
+        if in_set[v] {
+            writeln!(w, "  {v} [fillcolor=tomato];")?;
+        } else {
+            writeln!(w, "  {v};")?;
+        }
+    }
+    for (u, v) in edges_among(graph, &verts) {
+        if in_set[u] && in_set[v] {
+            writeln!(w, "  {u} -- {v} [color=tomato, penwidth=2];")?;
+        } else {
+            writeln!(w, "  {u} -- {v} [color=gray];")?;
+        }
+    }
+    writeln!(w, "}}")
+}
+
+/// Write the set (and optionally its neighbourhood) as GraphML.
+pub fn write_graphml<W: Write>(
+    graph: &Graph,
+    members: &[usize],
+    neighbourhood: bool,
+    mut w: W,
+) -> io::Result<()> {
+    let (verts, in_set) = view(graph, members, neighbourhood);
+    writeln!(w, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(w, r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">"#)?;
+    writeln!(w, r#"  <key id="in_set" for="node" attr.name="in_set" attr.type="boolean"/>"#)?;
+    writeln!(w, r#"  <key id="internal" for="edge" attr.name="internal" attr.type="boolean"/>"#)?;
+    writeln!(w, r#"  <graph id="tsqc" edgedefault="undirected">"#)?;
+    for &v in &verts {
+        writeln!(w, r#"    <node id="n{v}"><data key="in_set">{}</data></node>"#, in_set[v])?;
+    }
+    for (u, v) in edges_among(graph, &verts) {
+        writeln!(
+            w,
+            r#"    <edge source="n{u}" target="n{v}"><data key="internal">{}</data></edge>"#,
+            in_set[u] && in_set[v]
+        )?;
+    }
+    writeln!(w, "  </graph>")?;
+    writeln!(w, "</graphml>")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dot_and_graphml_views() {
+        // triangle 0-1-2, pendant 2-3, far vertex 4 on 3
+        let g = Graph::from_edge_list(5, &[(0, 1), (1, 2), (0, 2), (2, 3), (3, 4)]);
+
+        let mut dot = Vec::new();
+        write_dot(&g, &[0, 1, 2], false, &mut dot).unwrap();
+        let dot = String::from_utf8(dot).unwrap();
+        assert_eq!(dot.matches("fillcolor=tomato").count(), 3);
+        assert_eq!(dot.matches(" -- ").count(), 3);
+
+        let mut xml = Vec::new();
+        write_graphml(&g, &[0, 1, 2], true, &mut xml).unwrap();
+        let xml = String::from_utf8(xml).unwrap();
+        assert_eq!(xml.matches("<node ").count(), 4); // + neighbour 3, not 4
+        assert_eq!(xml.matches(r#"<data key="internal">false"#).count(), 1);
+    }
+}
@@ -0,0 +1,502 @@
+// src/facade.rs
+//! One-call entry point for Rust users.
+//!
+//! ```no_run
+//! use tsqc::prelude::*;
+//!
+//! let tsqc = Tsqc::load("g.clq")?.gamma(0.9).time_limit_secs(60.0).seed(1);
+//! let found = tsqc.solve_max()?;
+//! println!("{} vertices, {}", found.solution.size(), found.report.manifest.to_json());
+//! # Ok::<(), std::io::Error>(())
+//! ```
+//!
+//! [`Tsqc`] owns the graph and the configuration ([`Params`], seed, RNG
+//! kind, time limit) and wires them into the solvers; every solve starts
+//! a fresh RNG from the seed, so repeated calls give the same result.
+//! A [`Found`] pairs the set with a [`Report`] of the run; both print as
+//! a short human-readable summary.  With [`Tsqc::reduce`], max-k first
+//! shrinks the graph by the safe rules of [`crate::reduce`] and the report
+//! records what each rule removed.
+
+use crate::{
+    construct::greedy_clique,
+    graph::Graph,
+    manifest::Manifest,
+    maxk::solve_maxk_with,
+    neighbour::MoveKind,
+    observer::{IterRecord, Observer, RestartRecord, StopReason},
+    params::Params,
+    reduce::{reduce, ReductionReport},
+    restart::solve_fixed_k_with,
+    rng::{RngKind, SolverRng},
+    solution::Solution,
+};
+use std::fmt;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Accepted steps of a run by type, for comparing operators.  Allowed
+/// (non-tabu) swaps, single or double, are split by the change of m(S);
+/// they only lose edges under penalties or degree-floor repairs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MoveStats {
+    /// Allowed swaps that gained edges.
+    pub improving:  usize,
+    /// Allowed swaps that kept m(S).
+    pub sideways:   usize,
+    /// Allowed swaps that lost edges.
+    pub worsening:  usize,
+    /// Tabu swaps accepted through aspiration.
+    pub aspiration: usize,
+    /// Steps without an admissible swap.
+    pub idle:       usize,
+    pub mild:       usize,
+    pub heavy:      usize,
+    pub oscillate:  usize,
+}
+
+impl MoveStats {
+    /// Count one step.
+    pub fn record(&mut self, rec: &IterRecord) {
+        let slot = match rec.kind {
+            MoveKind::Swap | MoveKind::DoubleSwap => match rec.delta.signum() {
+                1 => &mut self.improving,
+                0 => &mut self.sideways,
+                _ => &mut self.worsening,
+            },
+            MoveKind::Aspiration => &mut self.aspiration,
+            MoveKind::Idle       => &mut self.idle,
+            MoveKind::Mild       => &mut self.mild,
+            MoveKind::Heavy      => &mut self.heavy,
+            MoveKind::Oscillate  => &mut self.oscillate,
+        };
+        *slot += 1;
+    }
+
+    /// `(name, count)` per type, in field order.
+    pub fn entries(&self) -> [(&'static str, usize); 8] {
+        [
+            ("improving", self.improving),
+            ("sideways", self.sideways),
+            ("worsening", self.worsening),
+            ("aspiration", self.aspiration),
+            ("idle", self.idle),
+            ("mild", self.mild),
+            ("heavy", self.heavy),
+            ("oscillate", self.oscillate),
+        ]
+    }
+}
+
+/// Counts moves and restarts of one solver call; records the stop reason
+/// and the elapsed time when it finishes, and stops it at the deadline.
+#[derive(Clone, Debug)]
+pub struct RunCounter {
+    pub(crate) started:       Instant,
+    pub(crate) deadline:      Option<Instant>,
+    pub(crate) timed_out:     bool,
+    pub(crate) seconds:       f64,
+    pub(crate) iterations:    usize,
+    pub(crate) restarts:      usize,
+    pub(crate) moves:         MoveStats,
+    pub(crate) reason:        Option<StopReason>,
+    /// Restart-best sets each vertex was in (empty until a restart ends).
+    pub(crate) participation: Vec<u32>,
+}
+
+impl RunCounter {
+    /// Fresh counter; the clock starts now, with an optional time limit
+    /// (one too far off to represent is no limit).
+    pub fn start(limit: Option<Duration>) -> Self {
+        let started = Instant::now();
+        Self {
+            started,
+            deadline:      limit.and_then(|l| started.checked_add(l)),
+            timed_out:     false,
+            seconds:       0.0,
+            iterations:    0,
+            restarts:      0,
+            moves:         MoveStats::default(),
+            reason:        None,
+            participation: Vec::new(),
+        }
+    }
+
+    /// Report of the finished run that returned `sol`.
+    pub fn report(&self, sol: &Solution<'_>, p: &Params, manifest: Manifest) -> Report {
+        Report {
+            iterations:    self.iterations,
+            restarts:      self.restarts,
+            moves:         self.moves,
+            seconds:       self.seconds,
+            stop_reason:   self.reason,
+            timed_out:     self.timed_out,
+            gamma:         p.gamma_target,
+            feasible:      sol.size() > 0 && p.accepts(sol),
+            participation: self.participation.clone(),
+            manifest,
+            reduction:     None,
+        }
+    }
+}
+
+impl Observer for RunCounter {
+    fn wants_iters(&self) -> bool {
+        true
+    }
+
+    fn on_iter(&mut self, rec: &IterRecord) {
+        if !rec.kind.is_perturbation() {
+            self.iterations += 1;
+        }
+        self.moves.record(rec);
+    }
+
+    fn on_restart(&mut self, _rec: &RestartRecord) {
+        self.restarts += 1;
+    }
+
+    fn on_run_best(&mut self, sol: &Solution<'_>) {
+        self.participation.resize(sol.graph().n(), 0);
+        for v in sol.bitset().iter_ones() {
+            self.participation[v] += 1;
+        }
+    }
+
+    fn should_stop(&mut self) -> bool {
+        if self.deadline.is_some_and(|d| Instant::now() >= d) {
+            self.timed_out = true;
+        }
+        self.timed_out
+    }
+
+    fn on_finish(&mut self, reason: StopReason) {
+        self.seconds = self.started.elapsed().as_secs_f64();
+        self.reason = Some(reason);
+    }
+}
+
+/// Statistics of one solve.
+#[derive(Clone, Debug)]
+pub struct Report {
+    /// Intensification moves over all fixed-k searches.
+    pub iterations:    usize,
+    /// Restarts over all fixed-k searches.
+    pub restarts:      usize,
+    /// Iterations and perturbations by type.
+    pub moves:         MoveStats,
+    pub seconds:       f64,
+    pub stop_reason:   Option<StopReason>,
+    /// The time limit expired; the set is the best found until then.
+    pub timed_out:     bool,
+    pub gamma:         f64,
+    /// The set meets γ and every side constraint.
+    pub feasible:      bool,
+    /// How many restart-best sets each vertex was in (empty if no restart
+    /// ended): vertices most runs agree on form the core.
+    pub participation: Vec<u32>,
+    /// Version, commit, params, seed and graph fingerprint.
+    pub manifest:      Manifest,
+    /// What the reductions removed and the map back to the original ids
+    /// (max-k with [`Tsqc::reduce`] only).  Iterations, restarts and
+    /// participation are for the whole run, in original ids.
+    pub reduction:     Option<ReductionReport>,
+}
+
+impl fmt::Display for Report {
+    /// `1234 moves, 3 restarts, 0.052 s, stop: feasible`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} moves, {} restarts, {:.3} s", self.iterations, self.restarts, self.seconds)?;
+        if let Some(r) = self.stop_reason {
+            write!(f, ", stop: {}", r.as_str())?;
+        }
+        if self.timed_out {
+            write!(f, " (time limit)")?;
+        }
+        if let Some(r) = &self.reduction {
+            write!(f, ", reduced to {} vertices", r.map.len())?;
+        }
+        Ok(())
+    }
+}
+
+/// A found set and how it was found.
+#[derive(Clone, Debug)]
+pub struct Found<'g> {
+    pub solution: Solution<'g>,
+    pub report:   Report,
+}
+
+/// Members listed as weakest in the summary.
+const WEAKEST_SHOWN: usize = 3;
+
+impl fmt::Display for Found<'_> {
+    /// Three lines: size / density / feasibility, the [`Report`], and the
+    /// members with the fewest internal neighbours (out of |S|−1).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sol = &self.solution;
+        writeln!(
+            f,
+            "size {}, density {:.4}, {} at gamma {}",
+            sol.size(),
+            sol.density(),
+            if self.report.feasible { "feasible" } else { "infeasible" },
+            self.report.gamma
+        )?;
+        write!(f, "{}", self.report)?;
+        if sol.size() > 1 {
+            let weakest: Vec<String> = sol
+                .weakest(WEAKEST_SHOWN)
+                .into_iter()
+                .map(|(v, d)| format!("{v} ({d}/{})", sol.size() - 1))
+                .collect();
+            write!(f, "\nweakest {}", weakest.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Graph plus configuration, solved in one call.
+#[derive(Clone, Debug)]
+pub struct Tsqc {
+    graph:      Graph,
+    params:     Params,
+    seed:       u64,
+    time_limit: Option<Duration>,
+    reduce:     bool,
+}
+
+impl Tsqc {
+    /// Default [`Params`] (γ = 0.9), seed 0, no time limit.
+    pub fn new(graph: Graph) -> Self {
+        Self { graph, params: Params::default(), seed: 0, time_limit: None, reduce: false }
+    }
+
+    /// Read a DIMACS file, or a NumPy `.npy` / `.npz` array by extension.
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let path = path.as_ref();
+        let numpy = path.extension().is_some_and(|e| e.eq_ignore_ascii_case("npy") || e.eq_ignore_ascii_case("npz"));
+        let graph = if numpy { Graph::load_numpy(path)? } else { Graph::read_dimacs_file(path)? };
+        Ok(Self::new(graph))
+    }
+
+    pub fn gamma(mut self, gamma: f64) -> Self {
+        self.params.gamma_target = gamma;
+        self
+    }
+
+    /// Replace the whole configuration (γ included).
+    pub fn params(mut self, params: Params) -> Self {
+        self.params = params;
+        self
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn rng(mut self, kind: RngKind) -> Self {
+        self.params.rng = kind;
+        self
+    }
+
+    /// Return the best set found so far after `limit`.
+    pub fn time_limit(mut self, limit: Duration) -> Self {
+        self.time_limit = Some(limit);
+        self
+    }
+
+    /// [`Tsqc::time_limit`] in seconds; negative or non-finite values are
+    /// ignored.
+    pub fn time_limit_secs(self, secs: f64) -> Self {
+        match Duration::try_from_secs_f64(secs) {
+            Ok(limit) => self.time_limit(limit),
+            Err(_) => self,
+        }
+    }
+
+    /// Reduce the graph before max-k (see [`crate::reduce`]) against the
+    /// greedy clique, if it meets every constraint.
+    pub fn reduce(mut self, on: bool) -> Self {
+        self.reduce = on;
+        self
+    }
+
+    pub fn graph(&self) -> &Graph {
+        &self.graph
+    }
+
+    pub fn config(&self) -> &Params {
+        &self.params
+    }
+
+    fn check_params(&self) -> io::Result<()> {
+        let g = self.params.gamma_target;
+        if g > 0.0 && g <= 1.0 {
+            self.params.check(self.graph.n())
+        } else {
+            Err(io::Error::new(io::ErrorKind::InvalidInput, format!("gamma must be in (0, 1], got {g}")))
+        }
+    }
+
+    fn found<'g>(&'g self, solution: Solution<'g>, counter: RunCounter) -> Found<'g> {
+        let manifest = Manifest::new(&self.graph, &self.params, self.seed, 0);
+        let report = counter.report(&solution, &self.params, manifest);
+        Found { solution, report }
+    }
+
+    /// Largest γ-quasi-clique found.
+    pub fn solve_max(&self) -> io::Result<Found<'_>> {
+        self.check_params()?;
+        let mut rng = SolverRng::seeded(self.params.rng, self.seed);
+        let mut counter = RunCounter::start(self.time_limit);
+        if !self.reduce {
+            let sol = solve_maxk_with(&self.graph, &mut rng, &self.params, &mut counter);
+            return Ok(self.found(sol, counter));
+        }
+
+        let clique = greedy_clique(&self.graph, self.params.clique_starts);
+        let mut sol = if self.params.accepts(&clique) { clique } else { Solution::new(&self.graph) };
+        let reduction = reduce(&self.graph, sol.size(), &self.params);
+        let map = &reduction.report.map;
+        if reduction.graph.n() >= 2 {
+            let p = self.params.restricted(map);
+            let inner = solve_maxk_with(&reduction.graph, &mut rng, &p, &mut counter);
+            if inner.size() > sol.size() && p.accepts(&inner) {
+                sol = Solution::new(&self.graph);
+                for v in inner.bitset().iter_ones() {
+                    sol.add(map[v]);
+                }
+            }
+        }
+        if !counter.participation.is_empty() {
+            let mut participation = vec![0; self.graph.n()];
+            for (i, &c) in counter.participation.iter().enumerate() {
+                participation[map[i]] = c;
+            }
+            counter.participation = participation;
+        }
+        let mut found = self.found(sol, counter);
+        found.report.reduction = Some(reduction.report);
+        Ok(found)
+    }
+
+    /// Best γ-quasi-clique of size `k` (empty if none was found).
+    pub fn solve_k(&self, k: usize) -> io::Result<Found<'_>> {
+        self.check_params()?;
+        if k < 2 || k > self.graph.n() {
+            let msg = format!("k must be in 2..={}, got {k}", self.graph.n());
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, msg));
+        }
+        let mut rng = SolverRng::seeded(self.params.rng, self.seed);
+        let mut counter = RunCounter::start(self.time_limit);
+        let sol = solve_fixed_k_with(&self.graph, k, &mut rng, &self.params, &mut counter);
+        Ok(self.found(sol, counter))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testgraphs::clique_edges;
+
+    #[test]
+    fn fluent_solves() {
+        // K5 plus a pendant vertex
+        let mut edges = clique_edges(0..5);
+        edges.push((4, 5));
+        let tsqc = Tsqc::new(Graph::from_edge_list(6, &edges))
+            .gamma(1.0)
+            .params(Params { gamma_target: 1.0, max_iter: 2_000, ..Params::default() })
+            .seed(3)
+            .time_limit_secs(30.0);
+
+        let found = tsqc.solve_max().unwrap();
+        assert_eq!(found.solution.size(), 5);
+        assert!(!found.report.timed_out);
+        assert_eq!(found.report.manifest.seed, 3);
+        assert_eq!(found.report.manifest.graph.m, 11);
+
+        let again = tsqc.solve_max().unwrap();
+        assert_eq!(again.solution.bitset(), found.solution.bitset());
+
+        let text = found.to_string();
+        assert!(text.starts_with("size 5, density 1.0000, feasible at gamma 1\n"));
+        assert!(text.ends_with("weakest 0 (4/4), 1 (4/4), 2 (4/4)"));
+
+        let k4 = tsqc.solve_k(4).unwrap();
+        assert_eq!(k4.report.stop_reason, Some(StopReason::Feasible));
+        assert!(k4.report.to_string().ends_with(", stop: feasible"));
+        assert!(tsqc.solve_k(7).is_err());
+        assert!(tsqc.clone().gamma(1.5).solve_max().is_err());
+    }
+
+    #[test]
+    fn reduced_max_k_reports_the_rules() {
+        // K6 {0..6} with a pendant 11 on 0, and a separate K5 {6..11}
+        let mut edges = vec![(0, 11)];
+        for (lo, hi) in [(0, 6), (6, 11)] {
+            edges.extend(clique_edges(lo..hi));
+        }
+        let tsqc = Tsqc::new(Graph::from_edge_list(12, &edges))
+            .params(Params { gamma_target: 0.9, max_iter: 2_000, clique_starts: 16, ..Params::default() })
+            .reduce(true);
+        let found = tsqc.solve_max().unwrap();
+        assert_eq!(found.solution.bitset().iter_ones().collect::<Vec<_>>(), (0..6).collect::<Vec<_>>());
+        let r = found.report.reduction.as_ref().unwrap();
+        assert_eq!((r.lower_bound, r.map.len()), (6, 0));
+        assert!(found.report.to_string().ends_with(", reduced to 0 vertices"));
+
+        // against a smaller bound the K6 survives and is searched
+        let tsqc = tsqc.params(Params { gamma_target: 0.9, max_iter: 2_000, clique_starts: 0, ..Params::default() });
+        let found = tsqc.solve_max().unwrap();
+        assert_eq!(found.solution.size(), 6);
+        assert_eq!(found.report.reduction.unwrap().rules.iter().map(|s| s.vertices).sum::<usize>(), 0);
+    }
+
+    #[test]
+    fn participation_counts_restart_bests() {
+        // two disjoint triangles and no K4: every restart ends on one of them
+        let edges = [(0, 1), (1, 2), (0, 2), (3, 4), (4, 5), (3, 5)];
+        let tsqc = Tsqc::new(Graph::from_edge_list(6, &edges))
+            .params(Params { gamma_target: 1.0, stagnation_iter: 20, max_iter: 400, ..Params::default() });
+        let report = tsqc.solve_k(4).unwrap().report;
+        assert!(report.restarts > 1);
+        assert_eq!(report.participation.len(), 6);
+        assert_eq!(report.participation.iter().sum::<u32>() as usize, 4 * report.restarts);
+    }
+
+    #[test]
+    fn moves_by_type() {
+        // two disjoint triangles and no K4: the search runs to max_iter,
+        // perturbing (no U1 cut) whenever 20 moves bring nothing new
+        let edges = [(0, 1), (1, 2), (0, 2), (3, 4), (4, 5), (3, 5)];
+        let p = Params { gamma_target: 1.0, stagnation_iter: 20, max_iter: 400, u1_interval: 0, ..Params::default() };
+        let tsqc = Tsqc::new(Graph::from_edge_list(6, &edges)).params(p);
+        let report = tsqc.solve_k(4).unwrap().report;
+        let m = report.moves;
+        assert_eq!(m.improving + m.sideways + m.worsening + m.aspiration + m.idle, report.iterations);
+        assert!(m.improving > 0 && m.mild + m.heavy + m.oscillate > 0, "{m:?}");
+        assert_eq!(m.worsening, 0);
+        assert_eq!(m.entries().iter().map(|e| e.1).sum::<usize>(), report.iterations + m.mild + m.heavy + m.oscillate);
+    }
+
+    #[test]
+    fn moves_by_type_in_clique_mode() {
+        // a 7-cycle: no triangle, but the colouring bound is 3, so the
+        // clique search moves between edges until max_iter
+        let edges: Vec<(usize, usize)> = (0..7).map(|v| (v, (v + 1) % 7)).collect();
+        let p = Params { gamma_target: 1.0, clique_mode: true, clique_starts: 0, max_iter: 200, ..Params::default() };
+        let tsqc = Tsqc::new(Graph::from_edge_list(7, &edges)).params(p);
+        let report = tsqc.solve_k(3).unwrap().report;
+        let m = report.moves;
+        assert_eq!(report.stop_reason, Some(StopReason::MaxIter));
+        assert_eq!(report.iterations, 200);
+        // ADD / SWAP / DROP are improving / sideways / worsening swaps
+        assert_eq!(m.improving + m.sideways + m.worsening + m.aspiration, report.iterations);
+        assert!(m.improving > 0 && m.sideways > 0 && m.worsening > 0, "{m:?}");
+        assert_eq!(m.idle + m.mild + m.heavy + m.oscillate, 0);
+    }
+}
@@ -1,13 +1,101 @@
-//! Simple undirected graph stored as an adjacency BitVec per row.
+//! Simple undirected graph stored as an adjacency bit matrix.
 //! Supports DIMACS *.clq parsing and edge iteration.
+//!
+//! Three layouts are available (see [`Layout`]):
+//! • `Rows` – one `BitVec` per vertex (n² bits); fastest, word-level
+//!   intersections with a solution bitset.
+//! • `Triangular` – the strict upper triangle packed into one `BitVec`
+//!   (n(n−1)/2 bits); about half the memory, slower neighbour scans.
+//! • `Csr` – sorted neighbour lists in compressed sparse rows
+//!   (n + 1 offsets, 2m `u32` targets); for large sparse graphs, where
+//!   n² bits would not fit.  Membership tests are binary searches.
+//!
+//! Every layout serves the same queries, so the solvers run unchanged on
+//! any of them; [`Layout::auto`] picks `Csr` for large sparse inputs.
 
 use bitvec::prelude::*;
+use crate::{builder::GraphBuilder, memory::{self, MemoryError}, solution::Solution};
+use std::collections::HashMap;
 use std::io::{BufRead, Read};
 
+/// Storage layout of the adjacency matrix.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Layout {
+    /// Full matrix, one row per vertex.
+    #[default]
+    Rows,
+    /// Packed strict upper triangle.
+    Triangular,
+    /// Compressed sparse rows: sorted neighbour lists.
+    Csr,
+}
+
+/// Fewest vertices for which [`Layout::auto`] considers [`Layout::Csr`]
+/// (below it the row matrix takes at most 32 MiB).
+pub const CSR_MIN_VERTICES: usize = 1 << 14;
+
+impl Layout {
+    /// Bytes the adjacency of an `n`-vertex graph takes in this layout,
+    /// known before anything is allocated.  For [`Layout::Csr`] this is
+    /// the offsets alone; the targets grow with m (see
+    /// [`Layout::bytes_with_edges`]).
+    pub fn bytes_for(self, n: usize) -> usize {
+        let word = size_of::<usize>();
+        let words = |bits: usize| bits.div_ceil(8 * word);
+        match self {
+            Layout::Rows => n * words(n) * word,
+            Layout::Triangular => words(n * n.saturating_sub(1) / 2) * word,
+            Layout::Csr => (n + 1) * word,
+        }
+    }
+
+    /// Bytes the adjacency of an `n`-vertex, `m`-edge graph takes: for
+    /// [`Layout::Csr`] the offsets plus the 2m `u32` targets, else
+    /// [`Layout::bytes_for`].
+    pub fn bytes_with_edges(self, n: usize, m: usize) -> usize {
+        let targets = if self == Layout::Csr { 2 * m * size_of::<u32>() } else { 0 };
+        self.bytes_for(n) + targets
+    }
+
+    /// Layout for an `n`-vertex, `m`-edge graph: [`Layout::Csr`] from
+    /// [`CSR_MIN_VERTICES`] on when the average degree is at most n/64
+    /// (the lists are then smaller than the rows), else [`Layout::Rows`].
+    pub fn auto(n: usize, m: usize) -> Layout {
+        if n >= CSR_MIN_VERTICES && m.saturating_mul(2 * 64) <= n.saturating_mul(n) {
+            Layout::Csr
+        } else {
+            Layout::Rows
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Adjacency {
+    /// Row‐major adjacency; `rows[i][j]` is 1 ⇔ edge (i,j) exists, j≠i.
+    Rows(Vec<BitVec>),
+    /// Bit `tri_index(n, i, j)` (i < j) is 1 ⇔ edge (i,j) exists.
+    Triangular { n: usize, bits: BitVec },
+    /// Neighbours of i are `targets[offsets[i]..offsets[i + 1]]`, ascending.
+    Csr { offsets: Vec<usize>, targets: Vec<u32> },
+}
+
 #[derive(Clone, Debug)]
 pub struct Graph {
-    /// Row‐major adjacency; `adj[i][j]` is 1 ⇔ edge (i,j) exists, j≠i.
-    adj: Vec<BitVec>,
+    adj: Adjacency,
+    /// Edge count, kept by `add_edge`.
+    m:   usize,
+}
+
+/// Offset of row `i` in the packed upper triangle.
+#[inline]
+fn tri_row(n: usize, i: usize) -> usize {
+    i * n - i * (i + 1) / 2
+}
+
+/// Position of pair (i, j), i < j, in the packed upper triangle.
+#[inline]
+fn tri_index(n: usize, i: usize, j: usize) -> usize {
+    tri_row(n, i) + (j - i - 1)
 }
 
 impl Graph {
@@ -15,16 +103,109 @@ impl Graph {
 
     /// Empty graph with `n` isolated vertices.
     pub fn with_vertices(n: usize) -> Self {
-        let mut rows = Vec::with_capacity(n);
-        for _ in 0..n {
-            rows.push(bitvec![0; n]);
+        Self::with_layout(n, Layout::Rows)
+    }
+
+    /// Empty graph with `n` isolated vertices in the given layout.
+    ///
+    /// Aborts if the adjacency cannot be allocated; see
+    /// [`Graph::try_with_layout`].
+    pub fn with_layout(n: usize, layout: Layout) -> Self {
+        let adj = match layout {
+            Layout::Rows => {
+                let mut rows = Vec::with_capacity(n);
+                for _ in 0..n {
+                    rows.push(bitvec![0; n]);
+                }
+                Adjacency::Rows(rows)
+            }
+            Layout::Triangular => Adjacency::Triangular {
+                n,
+                bits: bitvec![0; n * n.saturating_sub(1) / 2],
+            },
+            Layout::Csr => Adjacency::Csr { offsets: vec![0; n + 1], targets: Vec::new() },
+        };
+        Self { adj, m: 0 }
+    }
+
+    /// [`Layout::Csr`] graph on `n` vertices from undirected edges in any
+    /// order (duplicates are dropped), built in two passes without
+    /// per-edge insertion.
+    ///
+    /// Panics on a self-loop or a vertex out of range.
+    pub(crate) fn csr_from_edges<I>(n: usize, edges: I) -> Self
+    where
+        I: IntoIterator<Item = (usize, usize)>,
+        I::IntoIter: Clone,
+    {
+        assert!(n <= 1 << 32, "CSR vertex ids must fit in u32");
+        let edges = edges.into_iter();
+        let mut offsets = vec![0usize; n + 1];
+        for (u, v) in edges.clone() {
+            assert!(u < n && v < n && u != v);
+            offsets[u + 1] += 1;
+            offsets[v + 1] += 1;
         }
-        Self { adj: rows }
+        for i in 0..n {
+            offsets[i + 1] += offsets[i];
+        }
+        let mut next = offsets[..n].to_vec();
+        let mut targets = vec![0u32; offsets[n]];
+        for (u, v) in edges {
+            targets[next[u]] = v as u32;
+            next[u] += 1;
+            targets[next[v]] = u as u32;
+            next[v] += 1;
+        }
+        drop(next);
+
+        // sort each row and compact it over the duplicates
+        let mut len = 0;
+        for v in 0..n {
+            let (lo, hi) = (offsets[v], offsets[v + 1]);
+            targets[lo..hi].sort_unstable();
+            offsets[v] = len;
+            let mut i = lo;
+            while i < hi {
+                let t = targets[i];
+                if len == offsets[v] || targets[len - 1] != t {
+                    targets[len] = t;
+                    len += 1;
+                }
+                i += 1;
+            }
+        }
+        offsets[n] = len;
+        targets.truncate(len);
+        targets.shrink_to_fit();
+        Self { adj: Adjacency::Csr { offsets, targets }, m: len / 2 }
+    }
+
+    /// [`Graph::with_layout`], refused with a [`MemoryError`] when the
+    /// adjacency exceeds the memory limit or what is available (see
+    /// [`crate::memory`]).
+    pub fn try_with_layout(n: usize, layout: Layout) -> Result<Self, MemoryError> {
+        memory::check(n, layout)?;
+        Ok(Self::with_layout(n, layout))
+    }
+
+    /// Peak bytes to load an `n`-vertex, `m`-edge graph in `layout`: the
+    /// adjacency plus the [`GraphBuilder`] edge buffer.
+    pub fn estimated_bytes(n: usize, m: usize, layout: Layout) -> usize {
+        layout.bytes_with_edges(n, m) + m * size_of::<(u32, u32)>()
     }
 
     /// Build from explicit edge list (0-based indices, undirected).
     pub fn from_edge_list(n: usize, edges: &[(usize, usize)]) -> Self {
-        let mut g = Self::with_vertices(n);
+        Self::from_edge_list_with(n, edges, Layout::Rows)
+    }
+
+    /// Build from explicit edge list in the given layout.
+    pub fn from_edge_list_with(n: usize, edges: &[(usize, usize)], layout: Layout) -> Self {
+        if layout == Layout::Csr {
+            return Self::csr_from_edges(n, edges.iter().copied());
+        }
+        let mut g = Self::with_layout(n, layout);
         for &(u, v) in edges {
             g.add_edge(u, v);
         }
@@ -33,8 +214,7 @@ impl Graph {
 
     /// Parse DIMACS *.clq format from any buffered reader.
     pub fn parse_dimacs<R: Read>(reader: R) -> std::io::Result<Self> {
-        let mut n = 0usize;
-        let mut edges: Vec<(usize, usize)> = Vec::new();
+        let mut builder = GraphBuilder::new();
 
         for line in std::io::BufReader::new(reader).lines() {
             let line = line?;
@@ -44,7 +224,10 @@ impl Graph {
                 // p edge <n> <m>
                 let parts: Vec<_> = line.split_whitespace().collect();
                 if parts.len() >= 3 {
-                    n = parts[2].parse().unwrap_or(0);
+                    builder.vertices(parts[2].parse().unwrap_or(0));
+                }
+                if parts.len() >= 4 {
+                    builder.reserve(parts[3].parse().unwrap_or(0));
                 }
             } else if line.starts_with('e') {
                 // e u v   (1-based)
@@ -52,45 +235,260 @@ impl Graph {
                 if parts.len() >= 3 {
                     let u: usize = parts[1].parse().unwrap();
                     let v: usize = parts[2].parse().unwrap();
-                    edges.push((u - 1, v - 1));
+                    builder.add_edge(u - 1, v - 1);
+                }
+            }
+        }
+        Ok(builder.try_build()?)
+    }
+
+    /// Subgraph induced by `vertices` (distinct, in range); new vertex `i`
+    /// is `vertices[i]`.  Keeps the layout of `self`.
+    pub fn induced_subgraph(&self, vertices: &[usize]) -> Self {
+        if let Adjacency::Csr { .. } = self.adj {
+            // walk the members' lists instead of testing all pairs
+            let index: HashMap<usize, usize> = vertices.iter().enumerate().map(|(i, &v)| (v, i)).collect();
+            let edges = vertices.iter().enumerate().flat_map(|(i, &u)| {
+                let index = &index;
+                self.neighbors(u).filter_map(move |w| index.get(&w).filter(|&&j| j > i).map(|&j| (i, j)))
+            });
+            return Self::csr_from_edges(vertices.len(), edges);
+        }
+        let mut g = Self::with_layout(vertices.len(), self.layout());
+        for (i, &u) in vertices.iter().enumerate() {
+            for (j, &v) in vertices.iter().enumerate().skip(i + 1) {
+                if self.has_edge(u, v) {
+                    g.add_edge(i, j);
                 }
             }
         }
-        Ok(Self::from_edge_list(n, &edges))
+        g
     }
 
     /*────────── getters ──────────*/
 
-    #[inline] pub fn n(&self) -> usize { self.adj.len() }
+    #[inline]
+    pub fn n(&self) -> usize {
+        match &self.adj {
+            Adjacency::Rows(rows) => rows.len(),
+            Adjacency::Triangular { n, .. } => *n,
+            Adjacency::Csr { offsets, .. } => offsets.len() - 1,
+        }
+    }
+
+    /// Storage layout of this graph.
+    pub fn layout(&self) -> Layout {
+        match self.adj {
+            Adjacency::Rows(_) => Layout::Rows,
+            Adjacency::Triangular { .. } => Layout::Triangular,
+            Adjacency::Csr { .. } => Layout::Csr,
+        }
+    }
+
+    /// Bytes held by the adjacency bits, or the offsets and targets of
+    /// [`Layout::Csr`] (excluding small fixed overheads).
+    pub fn adjacency_bytes(&self) -> usize {
+        match &self.adj {
+            Adjacency::Rows(rows) => rows.iter().map(|r| size_of_val(r.as_raw_slice())).sum(),
+            Adjacency::Triangular { bits, .. } => size_of_val(bits.as_raw_slice()),
+            Adjacency::Csr { offsets, targets } => size_of_val(&offsets[..]) + size_of_val(&targets[..]),
+        }
+    }
 
     /// Number of edges (each counted once).
+    #[inline]
     pub fn m(&self) -> usize {
-        let mut m = 0usize;
-        for i in 0..self.n() {
-            for j in self.neigh_row(i).iter_ones().filter(|&j| j > i) {
-                if self.adj[i][j] { m += 1; }
-            }
-        }
-        m
+        self.m
+    }
+
+    /// Average degree at most n/64: a vertex's neighbours are fewer than
+    /// the words of its bitset row, so walking the rows of S's members
+    /// ([`Graph::degrees_into`]) beats one popcount pass per outsider.
+    #[inline]
+    pub fn is_sparse(&self) -> bool {
+        2 * self.m * 64 <= self.n() * self.n()
     }
 
     /// Degree of vertex v.
     #[inline]
     pub fn degree(&self, v: usize) -> usize {
-        self.adj[v].count_ones()
+        match &self.adj {
+            Adjacency::Rows(rows) => rows[v].count_ones(),
+            Adjacency::Triangular { n, bits } => {
+                let above = (0..v).filter(|&u| bits[tri_index(*n, u, v)]).count();
+                let row = tri_row(*n, v);
+                above + bits[row..row + (n - v - 1)].count_ones()
+            }
+            Adjacency::Csr { offsets, .. } => offsets[v + 1] - offsets[v],
+        }
     }
 
-    /// Immutable row slice for adjacency of v.
+    /// All vertex degrees in ascending order.
+    pub fn sorted_degrees(&self) -> Vec<usize> {
+        let mut degs: Vec<usize> = (0..self.n()).map(|v| self.degree(v)).collect();
+        degs.sort_unstable();
+        degs
+    }
+
+    /// Degree at percentile `pct` ∈ [0, 100] (nearest-rank); 0 for an empty graph.
+    pub fn degree_percentile(&self, pct: f64) -> usize {
+        percentile_of_sorted(&self.sorted_degrees(), pct)
+    }
+
+    /// Is (u,v) an edge?
     #[inline]
-    pub fn neigh_row(&self, v: usize) -> &BitSlice {
-        &self.adj[v]
+    pub fn has_edge(&self, u: usize, v: usize) -> bool {
+        match &self.adj {
+            Adjacency::Rows(rows) => rows[u][v],
+            Adjacency::Triangular { n, bits } => {
+                u != v && bits[tri_index(*n, u.min(v), u.max(v))]
+            }
+            Adjacency::Csr { .. } => self.row(u).binary_search(&(v as u32)).is_ok(),
+        }
+    }
+
+    /// [`Graph::has_edge`] for every pair, in input order.  Lookups are
+    /// made in storage order (by smaller, then larger endpoint) so large
+    /// batches walk the matrix once instead of jumping between rows.
+    ///
+    /// Panics if a vertex is out of range.
+    pub fn has_edges(&self, pairs: &[(usize, usize)]) -> BitVec {
+        let n = self.n();
+        let key = |i: usize| {
+            let (u, v) = pairs[i];
+            assert!(u < n && v < n, "vertex out of range");
+            (u.min(v), u.max(v))
+        };
+        let mut order: Vec<usize> = (0..pairs.len()).collect();
+        order.sort_unstable_by_key(|&i| key(i));
+        let mut out = bitvec![0; pairs.len()];
+        for i in order {
+            let (u, v) = key(i);
+            out.set(i, self.has_edge(u, v));
+        }
+        out
+    }
+
+    /// Neighbours of `v`, ascending.
+    #[inline]
+    pub fn neighbors(&self, v: usize) -> Neighbors<'_> {
+        match &self.adj {
+            Adjacency::Rows(rows) => Neighbors::Row(rows[v].iter_ones()),
+            Adjacency::Triangular { n, bits } => {
+                let row = tri_row(*n, v);
+                Neighbors::Tri {
+                    n: *n,
+                    bits,
+                    v,
+                    above: 0,
+                    below: bits[row..row + (n - v - 1)].iter_ones(),
+                }
+            }
+            Adjacency::Csr { .. } => Neighbors::List(self.row(v).iter()),
+        }
+    }
+
+    /// Sorted neighbour list of `v` in [`Layout::Csr`]; empty otherwise.
+    #[inline]
+    fn row(&self, v: usize) -> &[u32] {
+        match &self.adj {
+            Adjacency::Csr { offsets, targets } => &targets[offsets[v]..offsets[v + 1]],
+            _ => &[],
+        }
+    }
+
+    /// Common neighbours of `u` and `v` in [`Layout::Csr`]: the shorter
+    /// list, searched in the longer one.
+    fn common_in_lists(&self, u: usize, v: usize) -> impl Iterator<Item = usize> + '_ {
+        let (a, b) = (self.row(u), self.row(v));
+        let (short, long) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+        short.iter().filter(move |w| long.binary_search(w).is_ok()).map(|&w| w as usize)
+    }
+
+    /// |N(v) ∩ set| — the internal degree of `v` w.r.t. a vertex bitset
+    /// (`set.len()` ≥ n).  Word-level AND + popcount on the row layout.
+    #[inline]
+    pub fn deg_into(&self, v: usize, set: &BitSlice) -> usize {
+        match &self.adj {
+            Adjacency::Rows(rows) => {
+                const W: usize = usize::BITS as usize;
+                rows[v].chunks(W)
+                    .zip(set.chunks(W))
+                    .map(|(a, b)| (a.load_le::<usize>() & b.load_le::<usize>()).count_ones() as usize)
+                    .sum()
+            }
+            Adjacency::Triangular { .. } | Adjacency::Csr { .. } => {
+                self.neighbors(v).filter(|&j| set[j]).count()
+            }
+        }
+    }
+
+    /// |N(w) ∩ set| for every vertex w (members of `set` included), in one
+    /// pass over the members' rows: O(|set|·n/64 + Σ_{s∈set} deg(s))
+    /// instead of n word-level intersections.
+    pub fn degrees_into(&self, set: &BitSlice) -> Vec<u32> {
+        let mut deg = vec![0u32; self.n()];
+        for s in set.iter_ones() {
+            for w in self.neighbors(s) {
+                deg[w] += 1;
+            }
+        }
+        deg
+    }
+
+    /// Neighbours of `v` that are members of `sol`, ascending.
+    /// Use [`Graph::deg_into`] when only the count is needed.
+    #[inline]
+    pub fn neighbors_in<'a>(
+        &'a self,
+        v: usize,
+        sol: &'a Solution<'_>,
+    ) -> impl Iterator<Item = usize> + 'a {
+        let (fast, slow) = match &self.adj {
+            Adjacency::Rows(rows) => (
+                Some(AndOnes::new(rows[v].as_raw_slice(), sol.bitset().as_raw_slice())),
+                None,
+            ),
+            Adjacency::Triangular { .. } | Adjacency::Csr { .. } => {
+                (None, Some(self.neighbors(v).filter(|&j| sol.bitset()[j])))
+            }
+        };
+        fast.into_iter().flatten().chain(slow.into_iter().flatten())
+    }
+
+    /// Number of vertices adjacent to both `u` and `v` (row AND + popcount).
+    pub fn common_neighbor_count(&self, u: usize, v: usize) -> usize {
+        match &self.adj {
+            Adjacency::Rows(rows) => rows[u].as_raw_slice()
+                .iter()
+                .zip(rows[v].as_raw_slice())
+                .map(|(a, b)| (a & b).count_ones() as usize)
+                .sum(),
+            Adjacency::Triangular { .. } => {
+                self.neighbors(u).filter(|&w| self.has_edge(v, w)).count()
+            }
+            Adjacency::Csr { .. } => self.common_in_lists(u, v).count(),
+        }
+    }
+
+    /// Vertices adjacent to both `u` and `v`, ascending.
+    pub fn common_neighbors(&self, u: usize, v: usize) -> Vec<usize> {
+        match &self.adj {
+            Adjacency::Rows(rows) => {
+                AndOnes::new(rows[u].as_raw_slice(), rows[v].as_raw_slice()).collect()
+            }
+            Adjacency::Triangular { .. } => {
+                self.neighbors(u).filter(|&w| self.has_edge(v, w)).collect()
+            }
+            Adjacency::Csr { .. } => self.common_in_lists(u, v).collect(),
+        }
     }
 
     /// Return all edges as Vec<(u,v)> with u < v.
     pub fn edge_list(&self) -> Vec<(usize, usize)> {
         let mut edges = Vec::with_capacity(self.m());
         for i in 0..self.n() {
-            for j in self.neigh_row(i).iter_ones().filter(|&j| j > i) {
+            for j in self.neighbors(i).filter(|&j| j > i) {
                 edges.push((i, j));
             }
         }
@@ -99,11 +497,121 @@ impl Graph {
 
     /*────────── mutators ──────────*/
 
+    /// Add edge (u,v) if absent.  O(1) on the bit layouts; on
+    /// [`Layout::Csr`] both lists are shifted (O(n + m)), so build sparse
+    /// graphs in bulk ([`GraphBuilder`], [`Graph::from_edge_list_with`]).
     #[inline]
     pub fn add_edge(&mut self, u: usize, v: usize) {
         assert!(u < self.n() && v < self.n() && u != v);
-        self.adj[u].set(v, true);
-        self.adj[v].set(u, true);
+        if self.has_edge(u, v) {
+            return;
+        }
+        match &mut self.adj {
+            Adjacency::Rows(rows) => {
+                rows[u].set(v, true);
+                rows[v].set(u, true);
+            }
+            Adjacency::Triangular { n, bits } => {
+                bits.set(tri_index(*n, u.min(v), u.max(v)), true);
+            }
+            Adjacency::Csr { offsets, targets } => {
+                for (a, b) in [(u, v), (v, u)] {
+                    let row = &targets[offsets[a]..offsets[a + 1]];
+                    let at = offsets[a] + row.partition_point(|&t| (t as usize) < b);
+                    targets.insert(at, b as u32);
+                    for o in &mut offsets[a + 1..] {
+                        *o += 1;
+                    }
+                }
+            }
+        }
+        self.m += 1;
+    }
+}
+
+/// Iterator over the neighbours of one vertex (see [`Graph::neighbors`]).
+#[derive(Clone)]
+pub enum Neighbors<'a> {
+    Row(bitvec::slice::IterOnes<'a, usize, Lsb0>),
+    Tri {
+        n:     usize,
+        bits:  &'a BitVec,
+        v:     usize,
+        /// next candidate u < v (column walk)
+        above: usize,
+        /// ones in row v, offset by v + 1
+        below: bitvec::slice::IterOnes<'a, usize, Lsb0>,
+    },
+    List(std::slice::Iter<'a, u32>),
+}
+
+impl Iterator for Neighbors<'_> {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<usize> {
+        match self {
+            Neighbors::Row(it) => it.next(),
+            Neighbors::Tri { n, bits, v, above, below } => {
+                while *above < *v {
+                    let u = *above;
+                    *above += 1;
+                    if bits[tri_index(*n, u, *v)] {
+                        return Some(u);
+                    }
+                }
+                below.next().map(|j| j + *v + 1)
+            }
+            Neighbors::List(it) => it.next().map(|&u| u as usize),
+        }
+    }
+}
+
+/// Nearest-rank percentile of an ascending slice; `pct` is clamped to [0, 100].
+pub fn percentile_of_sorted(sorted: &[usize], pct: f64) -> usize {
+    if sorted.is_empty() {
+        return 0;
+    }
+    let pct = pct.clamp(0.0, 100.0);
+    let rank = ((pct / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.saturating_sub(1).min(sorted.len() - 1)]
+}
+
+/*────────── word-level intersection iterator ──────────*/
+
+/// Indices of bits set in both `a` and `b` (raw `Lsb0` words).
+struct AndOnes<'a> {
+    a:    &'a [usize],
+    b:    &'a [usize],
+    idx:  usize,
+    word: usize,
+}
+
+impl<'a> AndOnes<'a> {
+    fn new(a: &'a [usize], b: &'a [usize]) -> Self {
+        let word = match (a.first(), b.first()) {
+            (Some(x), Some(y)) => x & y,
+            _ => 0,
+        };
+        Self { a, b, idx: 0, word }
+    }
+}
+
+impl Iterator for AndOnes<'_> {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<usize> {
+        while self.word == 0 {
+            self.idx += 1;
+            if self.idx >= self.a.len().min(self.b.len()) {
+                return None;
+            }
+            self.word = self.a[self.idx] & self.b[self.idx];
+        }
+        let bit = self.word.trailing_zeros() as usize;
+        self.word &= self.word - 1;
+        Some(self.idx * usize::BITS as usize + bit)
     }
 }
 
@@ -119,4 +627,131 @@ mod tests {
         assert_eq!(g.m(), 3);
         assert_eq!(g.edge_list().len(), 3);
     }
+
+    #[test]
+    fn batched_edge_queries() {
+        let edges = [(0, 1), (2, 3), (1, 3)];
+        let pairs = [(3, 2), (0, 3), (1, 0), (2, 2), (3, 1), (0, 2)];
+        for layout in [Layout::Rows, Layout::Triangular, Layout::Csr] {
+            let g = Graph::from_edge_list_with(4, &edges, layout);
+            let hits = g.has_edges(&pairs);
+            assert_eq!(hits, bits![1, 0, 1, 0, 1, 0], "{layout:?}");
+            assert!(hits.iter().by_vals().zip(pairs).all(|(h, (u, v))| h == g.has_edge(u, v)));
+        }
+        assert!(Graph::with_vertices(2).has_edges(&[]).is_empty());
+    }
+
+    #[test]
+    fn batched_degrees_into() {
+        // path 0-1-…-199 plus a repeated edge: sparse
+        let mut edges: Vec<(usize, usize)> = (1..200).map(|v| (v - 1, v)).collect();
+        edges.push((1, 0));
+        for layout in [Layout::Rows, Layout::Triangular, Layout::Csr] {
+            let g = Graph::from_edge_list_with(200, &edges, layout);
+            assert_eq!(g.m(), 199);
+            assert!(g.is_sparse());
+            let mut set = bitvec![0; 200];
+            for v in [0, 1, 70, 150] {
+                set.set(v, true);
+            }
+            let deg = g.degrees_into(&set);
+            for (v, &d) in deg.iter().enumerate() {
+                assert_eq!(d as usize, g.deg_into(v, &set), "{layout:?} vertex {v}");
+            }
+        }
+        assert!(!Graph::from_edge_list(3, &[(0, 1)]).is_sparse());
+    }
+
+    #[test]
+    fn common_neighbours_across_words() {
+        // 0 and 1 share neighbours 2, 65 and 130 (spanning three words)
+        let mut g = Graph::with_vertices(131);
+        for w in [2, 65, 130] {
+            g.add_edge(0, w);
+            g.add_edge(1, w);
+        }
+        g.add_edge(0, 3);
+        assert_eq!(g.common_neighbor_count(0, 1), 3);
+        assert_eq!(g.common_neighbors(0, 1), vec![2, 65, 130]);
+        assert_eq!(g.common_neighbor_count(0, 2), 0);
+
+        let mut set = bitvec![0; 131];
+        for w in [3, 65, 130, 7] {
+            set.set(w, true);
+        }
+        assert_eq!(g.deg_into(0, &set), 3);
+        assert_eq!(g.deg_into(1, &set), 2);
+        let t = Graph::from_edge_list_with(131, &g.edge_list(), Layout::Triangular);
+        assert_eq!(t.deg_into(0, &set), 3);
+    }
+
+    #[test]
+    fn degree_percentiles() {
+        // star with centre 0 and four leaves: degrees 1,1,1,1,4
+        let g = Graph::from_edge_list(5, &[(0, 1), (0, 2), (0, 3), (0, 4)]);
+        assert_eq!(g.sorted_degrees(), vec![1, 1, 1, 1, 4]);
+        assert_eq!(g.degree_percentile(0.0), 1);
+        assert_eq!(g.degree_percentile(80.0), 1);
+        assert_eq!(g.degree_percentile(81.0), 4);
+        assert_eq!(g.degree_percentile(100.0), 4);
+    }
+
+    #[test]
+    fn triangular_matches_rows() {
+        let edges = [(0, 1), (0, 4), (1, 2), (2, 4), (3, 4), (1, 4)];
+        let r = Graph::from_edge_list(5, &edges);
+        let t = Graph::from_edge_list_with(5, &edges, Layout::Triangular);
+        assert_eq!(t.m(), r.m());
+        assert_eq!(t.edge_list(), r.edge_list());
+        for v in 0..5 {
+            assert_eq!(t.degree(v), r.degree(v));
+            assert_eq!(t.neighbors(v).collect::<Vec<_>>(), r.neighbors(v).collect::<Vec<_>>());
+            for u in 0..5 {
+                assert_eq!(t.has_edge(u, v), r.has_edge(u, v));
+                assert_eq!(t.common_neighbor_count(u, v), r.common_neighbor_count(u, v));
+            }
+        }
+        assert!(t.adjacency_bytes() <= r.adjacency_bytes());
+        assert_eq!(r.adjacency_bytes(), Layout::Rows.bytes_for(5));
+        assert_eq!(t.adjacency_bytes(), Layout::Triangular.bytes_for(5));
+        assert_eq!(Graph::estimated_bytes(5, 3, Layout::Rows), Layout::Rows.bytes_for(5) + 3 * 8);
+        assert!(Graph::try_with_layout(5, Layout::Triangular).is_ok());
+    }
+
+    #[test]
+    fn csr_matches_rows() {
+        let edges = [(0, 1), (4, 0), (1, 2), (2, 4), (3, 4), (1, 4), (0, 1)];
+        let r = Graph::from_edge_list(6, &edges);
+        let c = Graph::from_edge_list_with(6, &edges, Layout::Csr);
+        assert_eq!(c.layout(), Layout::Csr);
+        assert_eq!((c.n(), c.m()), (6, r.m()));
+        assert_eq!(c.edge_list(), r.edge_list());
+        for v in 0..6 {
+            assert_eq!(c.degree(v), r.degree(v));
+            assert_eq!(c.neighbors(v).collect::<Vec<_>>(), r.neighbors(v).collect::<Vec<_>>());
+            for u in 0..6 {
+                assert_eq!(c.has_edge(u, v), r.has_edge(u, v));
+                assert_eq!(c.common_neighbors(u, v), r.common_neighbors(u, v));
+            }
+        }
+        let sub = [4, 1, 2, 5];
+        assert_eq!(c.induced_subgraph(&sub).edge_list(), r.induced_subgraph(&sub).edge_list());
+        assert_eq!(c.induced_subgraph(&sub).layout(), Layout::Csr);
+
+        // insertion keeps the lists sorted
+        let mut c = c;
+        c.add_edge(5, 0);
+        c.add_edge(3, 2);
+        c.add_edge(0, 5);
+        assert_eq!(c.m(), r.m() + 2);
+        assert_eq!(c.neighbors(0).collect::<Vec<_>>(), vec![1, 4, 5]);
+        assert_eq!(c.neighbors(2).collect::<Vec<_>>(), vec![1, 3, 4]);
+        assert_eq!(c.adjacency_bytes(), 7 * 8 + 2 * c.m() * 4);
+        assert_eq!(Graph::estimated_bytes(6, 8, Layout::Csr), 7 * 8 + 8 * 8 + 8 * 8);
+
+        // large and sparse goes to lists, small or dense stays in rows
+        assert_eq!(Layout::auto(CSR_MIN_VERTICES, 3 * CSR_MIN_VERTICES), Layout::Csr);
+        assert_eq!(Layout::auto(1000, 2000), Layout::Rows);
+        assert_eq!(Layout::auto(CSR_MIN_VERTICES, CSR_MIN_VERTICES * CSR_MIN_VERTICES / 4), Layout::Rows);
+    }
 }
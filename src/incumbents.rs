@@ -0,0 +1,168 @@
+// src/incumbents.rs
+//! Incumbent improvements as an iterator (anytime use without callbacks).
+//!
+//! [`solve_fixed_k_iter`] runs the fixed-k search on a worker thread and
+//! sends every new best set through a channel; the returned
+//! [`Incumbents`] yields them as they arrive and ends when the solver
+//! returns.  Dropping it early cancels the search (at the next move) and
+//! joins the worker, so no thread outlives the iterator.
+
+use crate::{
+    graph::Graph,
+    observer::{Observer, StopReason},
+    params::Params,
+    restart::solve_fixed_k_with,
+    solution::Solution,
+};
+use rand::Rng;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+/// One improvement of the search's best set.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Incumbent {
+    /// Members, ascending.
+    pub vertices: Vec<usize>,
+    pub edges:    usize,
+    pub density:  f64,
+    /// [`Solution::zobrist`] of the set, e.g. to spot repeats.
+    pub zobrist:  u64,
+    /// Time since the search started.
+    pub elapsed:  Duration,
+}
+
+/// Forwards incumbents to the channel, stops once cancelled and keeps
+/// the stop reason.
+struct Sink {
+    tx:        Sender<Incumbent>,
+    cancelled: Arc<AtomicBool>,
+    started:   Instant,
+    reason:    Option<StopReason>,
+    /// Zobrist hash of the last set sent.
+    last:      Option<u64>,
+}
+
+impl Observer for Sink {
+    fn on_incumbent(&mut self, sol: &Solution<'_>) {
+        self.last = Some(sol.zobrist());
+        let inc = Incumbent {
+            vertices: sol.bitset().iter_ones().collect(),
+            edges:    sol.edges(),
+            density:  sol.density(),
+            zobrist:  sol.zobrist(),
+            elapsed:  self.started.elapsed(),
+        };
+        // a closed receiver means the iterator was dropped
+        if self.tx.send(inc).is_err() {
+            self.cancelled.store(true, Ordering::Relaxed);
+        }
+    }
+
+    fn should_stop(&mut self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    fn on_finish(&mut self, reason: StopReason) {
+        self.reason = Some(reason);
+    }
+}
+
+/// Iterator over the improvements of a running search (see the module
+/// docs).
+#[derive(Debug)]
+pub struct Incumbents {
+    rx:        Receiver<Incumbent>,
+    cancelled: Arc<AtomicBool>,
+    worker:    Option<JoinHandle<StopReason>>,
+    reason:    Option<StopReason>,
+}
+
+impl Incumbents {
+    /// Ask the search to return at its next move; the iterator still
+    /// yields what was sent before.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Why the search returned (`None` while it is running).
+    pub fn stop_reason(&self) -> Option<StopReason> {
+        self.reason
+    }
+
+    fn join(&mut self) {
+        if let Some(worker) = self.worker.take() {
+            self.reason = worker.join().ok();
+        }
+    }
+}
+
+impl Iterator for Incumbents {
+    type Item = Incumbent;
+
+    fn next(&mut self) -> Option<Incumbent> {
+        match self.rx.recv() {
+            Ok(inc) => Some(inc),
+            Err(_) => {
+                self.join();
+                None
+            }
+        }
+    }
+}
+
+impl Drop for Incumbents {
+    fn drop(&mut self) {
+        self.cancel();
+        self.join();
+    }
+}
+
+/// Start [`solve_fixed_k`](crate::solve_fixed_k) on a worker thread and
+/// iterate over its incumbents, the last one being the returned set.
+pub fn solve_fixed_k_iter<R>(graph: Arc<Graph>, k: usize, mut rng: R, p: Params) -> Incumbents
+where
+    R: Rng + Send + 'static,
+{
+    let (tx, rx) = mpsc::channel();
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let mut sink = Sink { tx, cancelled: Arc::clone(&cancelled), started: Instant::now(), reason: None, last: None };
+    let worker = std::thread::spawn(move || {
+        let sol = solve_fixed_k_with(&graph, k, &mut rng, &p, &mut sink);
+        // a start that is already best is returned without an improvement
+        if sol.size() > 0 && sink.last != Some(sol.zobrist()) {
+            sink.on_incumbent(&sol);
+        }
+        sink.reason.unwrap_or(StopReason::Interrupted)
+    });
+    Incumbents { rx, cancelled, worker: Some(worker), reason: None }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SolverRng;
+    use crate::testgraphs::clique_edges;
+
+    #[test]
+    fn incumbents_improve_and_end() {
+        // K5 plus a path hanging off it
+        let mut edges = clique_edges(0..5);
+        edges.extend([(4, 5), (5, 6), (6, 7)]);
+        let g = Arc::new(Graph::from_edge_list(8, &edges));
+        let p = Params { gamma_target: 1.0, max_iter: 5_000, ..Params::default() };
+
+        let mut it = solve_fixed_k_iter(Arc::clone(&g), 5, SolverRng::seeded(p.rng, 1), p.clone());
+        let all: Vec<Incumbent> = it.by_ref().collect();
+        assert!(all.windows(2).all(|w| w[1].density > w[0].density));
+        assert_eq!(all.last().unwrap().vertices, vec![0, 1, 2, 3, 4]);
+        assert_eq!(it.stop_reason(), Some(StopReason::Feasible));
+
+        // dropping a running search stops it
+        let p = Params { gamma_target: 1.0, max_iter: usize::MAX, ..p };
+        let it = solve_fixed_k_iter(g, 6, SolverRng::seeded(p.rng, 1), p);
+        drop(it);
+    }
+}
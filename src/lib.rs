@@ -1,92 +1,77 @@
-//! TSQC – Rust-kernel + PyO3 bindings.
-
-use pyo3::prelude::*;
-use pyo3::wrap_pyfunction;
-use pyo3::types::PyModule;
-use pyo3::prelude::Bound;       // Bound<'py, T> API in PyO3 v0.25
+//! TSQC – Rust-kernel + PyO3 bindings (feature `python`, on by default).
 
 /*───────── interne modules ─────────*/
 pub mod graph;
+pub mod builder;
 pub mod solution;
+pub mod pool;
 pub mod tabu;
 pub mod construct;
+pub mod community;
 pub mod neighbour;
 pub mod diversify;
 pub mod params;
 pub mod restart;
 pub mod maxk;
+pub mod observer;
+pub mod monitor;
+pub mod sample;
+pub mod traverse;
+pub mod npy;
+pub mod parse;
+pub mod export;
+pub mod signed;
+pub mod directed;
+pub mod weighted;
+pub mod disjoint;
+pub mod ego;
+pub mod percolation;
+pub mod rng;
+pub mod threads;
+pub mod memory;
+pub mod manifest;
+pub mod facade;
+pub mod prelude;
+pub mod incumbents;
+pub mod cache;
+pub mod realtime;
+pub mod snapshot;
+pub mod stream;
+pub mod reduce;
+pub mod clique;
+#[cfg(feature = "cross-check")]
+pub mod crosscheck;
+mod setsearch;
+#[cfg(feature = "python")]
+mod python;
+#[cfg(test)]
+mod testgraphs;
 
 /*───────── re-exports voor Rust-gebruikers ─────────*/
-pub use graph::Graph;
-pub use solution::Solution;
-pub use params::Params;
-pub use restart::solve_fixed_k;
-pub use maxk::solve_maxk;
-
-/*───────── extern util ─────────*/
-use rand_chacha::ChaCha8Rng;
-use rand::SeedableRng;
-use std::fs::File;
-use std::io::BufReader;
-
-/*======================================================================
-│  Python-functies
-└=====================================================================*/
-
-/// Fixed-k solver – returns density of best k-subset.
-#[pyfunction]
-#[pyo3(text_signature = "(graph_path, k, gamma, seed)")]
-fn solve_k_py(graph_path: String, k: usize, gamma: f64, seed: u64) -> PyResult<f64> {
-    let file = File::open(&graph_path)
-        .map_err(|e| pyo3::exceptions::PyIOError::new_err(e.to_string()))?;
-    let graph = Graph::parse_dimacs(BufReader::new(file))
-        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
-
-    let mut p = Params::default();
-    p.gamma_target = gamma;
-
-    let mut rng = ChaCha8Rng::seed_from_u64(seed);
-    let sol = solve_fixed_k(&graph, k, &mut rng, &p);
-    Ok(sol.density())
-}
-
-/// Max-k solver – returns (size, density) of best quasi-clique.
-#[pyfunction]
-#[pyo3(text_signature = "(graph_path, gamma, seed)")]
-fn solve_max_py(graph_path: String, gamma: f64, seed: u64) -> PyResult<(usize, f64)> {
-    let file = File::open(&graph_path)
-        .map_err(|e| pyo3::exceptions::PyIOError::new_err(e.to_string()))?;
-    let graph = Graph::parse_dimacs(BufReader::new(file))
-        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
-
-    let mut p = Params::default();
-    p.gamma_target = gamma;
-
-    let mut rng = ChaCha8Rng::seed_from_u64(seed);
-    let sol = maxk::solve_maxk(&graph, &mut rng, &p);
-    Ok((sol.size(), sol.density()))
-}
-
-/// Helper: parse DIMACS, return (n, m).
-#[pyfunction]
-#[pyo3(text_signature = "(graph_path)")]
-fn parse_dimacs_py(graph_path: String) -> PyResult<(usize, usize)> {
-    let file = File::open(&graph_path)
-        .map_err(|e| pyo3::exceptions::PyIOError::new_err(e.to_string()))?;
-    let graph = Graph::parse_dimacs(BufReader::new(file))
-        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
-    Ok((graph.n(), graph.m()))
-}
-
-/*======================================================================
-│  PyO3 module-init
-└=====================================================================*/
-
-/// ***Important***: name `_native` must match `pyproject.toml -> module-name`.
-#[pymodule]
-fn _native(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
-    m.add_function(wrap_pyfunction!(solve_k_py, m)?)?;
-    m.add_function(wrap_pyfunction!(solve_max_py, m)?)?;
-    m.add_function(wrap_pyfunction!(parse_dimacs_py, m)?)?;
-    Ok(())
-}
+pub use graph::{Graph, Layout};
+pub use builder::GraphBuilder;
+pub use solution::{density_of, edges_of, feasibility_spectrum, missing_links, Solution, Spectrum};
+pub use pool::SolutionPool;
+pub use params::{Budget, Params, Scan};
+pub use restart::{solve_fixed_k, solve_fixed_k_all, solve_fixed_k_from, solve_fixed_k_parallel, solve_fixed_k_with};
+pub use maxk::{densest_for_sizes, gamma_profile, grow_profile, solve_maxk, solve_maxk_with, GrowStep};
+pub use observer::{Observer, TraceWriter};
+pub use monitor::Monitor;
+pub use signed::{solve_signed_k, solve_signed_maxk, SignedGraph};
+pub use directed::{solve_directed_k, solve_directed_maxk, DiGraph};
+pub use weighted::{solve_weighted_k, solve_weighted_maxk, WeightedGraph};
+pub use disjoint::{membership_scores, solve_disjoint, Membership};
+pub use ego::solve_ego;
+pub use percolation::{percolate, Communities};
+pub use rng::{RngKind, SolverRng};
+pub use threads::set_threads;
+pub use memory::{set_memory_limit, MemoryError};
+pub use manifest::{Fingerprint, Manifest};
+pub use facade::{Found, MoveStats, Report, RunCounter, Tsqc};
+pub use incumbents::{solve_fixed_k_iter, Incumbent, Incumbents};
+pub use cache::{instance_key, ResultCache};
+pub use realtime::Stepper;
+pub use snapshot::{Snapshot, SnapshotRecorder};
+pub use stream::{solve_streaming, stream_core, StreamCore};
+pub use reduce::{reduce, Reduction, ReductionReport};
+pub use clique::{clique_bound, solve_clique, solve_clique_with};
@@ -0,0 +1,132 @@
+// src/manifest.rs
+//! Reproducibility manifest attached to results.
+//!
+//! A [`Manifest`] records what produced a result: crate version, git
+//! commit (embedded by `build.rs`), the full [`Params`] (its `Debug` form,
+//! so every field is kept as options are added), seed and RNG stream, and
+//! a [`Fingerprint`] of the graph.  [`Manifest::to_json`] renders it as
+//! one JSON object.
+
+use crate::{graph::Graph, params::Params};
+
+/// Crate version of this build.
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+/// Git commit of this build ("-dirty" with local changes, "unknown"
+/// outside a checkout).
+pub const GIT_HASH: &str = env!("TSQC_GIT_HASH");
+
+/// FNV-1a offset basis; start of every [`fnv1a`] chain.
+pub(crate) const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// Fold `bytes` into the FNV-1a hash `h`.
+pub(crate) fn fnv1a(mut h: u64, bytes: &[u8]) -> u64 {
+    for &b in bytes {
+        h = (h ^ b as u64).wrapping_mul(0x100_0000_01b3);
+    }
+    h
+}
+
+/// n, m and a hash of the edge list: equal fingerprints mean the same
+/// graph with the same vertex numbering (up to hash collisions).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Fingerprint {
+    pub n:         usize,
+    pub m:         usize,
+    /// FNV-1a over the edges (u < v) in ascending order, stable across
+    /// platforms and layouts.
+    pub edge_hash: u64,
+}
+
+impl Fingerprint {
+    pub fn of(graph: &Graph) -> Self {
+        let mut h = FNV_OFFSET;
+        for u in 0..graph.n() {
+            for v in graph.neighbors(u).filter(|&v| v > u) {
+                h = fnv1a(fnv1a(h, &(u as u64).to_le_bytes()), &(v as u64).to_le_bytes());
+            }
+        }
+        Self { n: graph.n(), m: graph.m(), edge_hash: h }
+    }
+}
+
+/// Everything needed to reproduce one solver run.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Manifest {
+    pub version:  &'static str,
+    pub git_hash: &'static str,
+    /// `{:?}` of the [`Params`] used.
+    pub params:   String,
+    pub seed:     u64,
+    /// RNG stream (0 unless the run used a separate stream).
+    pub stream:   u64,
+    pub graph:    Fingerprint,
+}
+
+/// `s` as a JSON string literal.
+fn json_str(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+impl Manifest {
+    pub fn new(graph: &Graph, p: &Params, seed: u64, stream: u64) -> Self {
+        Self {
+            version: VERSION,
+            git_hash: GIT_HASH,
+            params: format!("{p:?}"),
+            seed,
+            stream,
+            graph: Fingerprint::of(graph),
+        }
+    }
+
+    /// One JSON object (the edge hash as a hex string, since JSON numbers
+    /// lose precision above 2⁵³).
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\"version\":{},\"git_hash\":{},\"seed\":{},\"stream\":{},\
+\"graph\":{{\"n\":{},\"m\":{},\"edge_hash\":\"{:016x}\"}},\"params\":{}}}",
+            json_str(self.version),
+            json_str(self.git_hash),
+            self.seed,
+            self.stream,
+            self.graph.n,
+            self.graph.m,
+            self.graph.edge_hash,
+            json_str(&self.params)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::Layout;
+
+    #[test]
+    fn fingerprint_and_json() {
+        let edges = [(0, 1), (1, 2), (2, 0)];
+        let a = Fingerprint::of(&Graph::from_edge_list(4, &edges));
+        let b = Fingerprint::of(&Graph::from_edge_list_with(4, &[(2, 1), (0, 2), (1, 0)], Layout::Triangular));
+        assert_eq!(a, b);
+        assert_ne!(a, Fingerprint::of(&Graph::from_edge_list(4, &[(0, 1), (1, 2), (2, 3)])));
+
+        let g = Graph::from_edge_list(4, &edges);
+        let m = Manifest::new(&g, &Params::default(), 7, 0);
+        let json = m.to_json();
+        assert!(json.starts_with(&format!("{{\"version\":\"{VERSION}\"")));
+        assert!(json.contains("\"seed\":7") && json.contains("\"n\":4,\"m\":3"));
+        assert!(json.contains("gamma_target: 0.9"));
+        assert_eq!(json_str("a\"b\\\n"), "\"a\\\"b\\\\\\u000a\"");
+    }
+}
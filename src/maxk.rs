@@ -1,33 +1,79 @@
 // src/maxk.rs
 //! Outer **max-k** search for TSQC (Alg. 1, § 3.1).
 //!
-//! 1. Compute an initial γ-feasible subset S₀ via `greedy_until_gamma` → k_lb = |S₀|
+//! 1. Compute an initial γ-feasible subset S₀ via `greedy_until_gamma` and a
+//!    greedy clique C (feasible for every γ; its costliest members are
+//!    dropped while over budget) → k_lb = max(|S₀|, |C|)
 //! 2. Build a degree‐prefix table for quick upper bounds.
 //! 3. For k = k_lb..n:
-//!     a) If k == best_sol.size(), skip (already feasible).
-//!     b) Compute `required = ceil(γ * C(k,2))`.
-//!     c) If `ub_edges(prefix, k) < required`:
-//!           • If k > best_sol.size(), **break** (first impossibility above best).  
-//!           • Otherwise `continue`.
-//!     d) Run `solve_fixed_k(graph, k, ...)`.
-//!     e) If solution is feasible (density ≥ γ): update best_sol.
-//!        Else if k > best_sol.size(): **break** (first failure above best).
+//!    - If k == best_sol.size(), skip (already feasible).
+//!    - Compute `required = ceil(γ * C(k,2))`.
+//!    - If `ub_edges(prefix, k) < required`, fewer than k vertices have
+//!      degree ⌈θ(k−1)⌉ (`p.min_degree_ratio`), or the k cheapest vertices
+//!      already cost more than the budget (`p.budget`): **break** if
+//!      k > best_sol.size() (first impossibility above best), otherwise
+//!      `continue`.
+//!    - Run `solve_fixed_k(graph, k, ...)`.
+//!    - If solution is feasible (density ≥ γ, degree floor and budget met):
+//!      update best_sol.
+//!      Else if k > best_sol.size(): **break** once more than
+//!      `p.maxk_lookahead` consecutive sizes above best have failed.
 //! 4. Return `best_sol`.
+//!
+//! With `p.warm_start` each fixed-k search starts from the previous
+//! solution grown by its best outsider(s); with `p.carry_memory` the
+//! frequency memory and tabu lists survive from one k to the next.  With
+//! `p.per_k_time_limit` each fixed-k search stops at the end of its time
+//! slice (the unused rest of earlier slices rolls forward).  `p.time_limit`
+//! bounds the whole max-k search: the fixed-k search running when it
+//! expires is cut short and the largest feasible set so far is returned.
+//!
+//! With `p.clique_mode` at γ = 1 the loop is replaced by the clique
+//! search of [`crate::clique`] (seeded with `prev` when given).
+//!
+//! [`gamma_profile`] runs max-k over a descending list of γ values, each
+//! search seeded with the previous result (the feasibility profile).
+//! [`grow_profile`] grows a given seed set one vertex at a time and
+//! records the density after every step (the growth curve).
+//! [`densest_for_sizes`] finds the densest set of each of several sizes
+//! in one shared search (elite pool and frequency memory carried from
+//! size to size).
 
 use crate::{
-    construct::greedy_until_gamma,
+    clique::clique_search,
+    construct::{greedy_clique, greedy_until_gamma, resize_to_k},
+    observer::{IterRecord, NoObserver, Observer, RestartRecord, StopReason},
     params::Params,
-    restart::solve_fixed_k,
-    solution::Solution,
+    pool::SolutionPool,
+    restart::{search, solve_fixed_k_from, Carry},
+    snapshot::Snapshot,
+    solution::{required_degree, required_edges, Solution},
     graph::Graph,
 };
 use rand::Rng;
+use std::time::{Duration, Instant};
+
+/// Forwards inner fixed-k events but drops their incumbents and stop
+/// reasons: within max-k the incumbent is the best *feasible* solution and
+/// the run ends when the outer loop does, both reported by the outer loop.
+/// Also stops the inner search at its deadline (the per-k time slice).
+struct InnerObserver<'a, O: ?Sized>(&'a mut O, Option<Instant>);
+
+impl<O: Observer + ?Sized> Observer for InnerObserver<'_, O> {
+    fn wants_iters(&self) -> bool { self.0.wants_iters() }
+    fn on_iter(&mut self, rec: &IterRecord) { self.0.on_iter(rec); }
+    fn on_restart(&mut self, rec: &RestartRecord) { self.0.on_restart(rec); }
+    fn on_run_best(&mut self, sol: &Solution<'_>) { self.0.on_run_best(sol); }
+    fn should_stop(&mut self) -> bool { self.0.should_stop() || self.1.is_some_and(|d| Instant::now() >= d) }
+    fn wants_snapshot(&mut self, iter: usize) -> bool { self.0.wants_snapshot(iter) }
+    fn on_snapshot(&mut self, snap: &Snapshot) { self.0.on_snapshot(snap); }
+}
 
 /// Build prefix sums of degrees in descending order:
 /// `pref[i] = sum_{j< i} deg_j`, where `deg_0 ≥ deg_1 ≥ …`.
 fn degree_prefix(graph: &Graph) -> Vec<usize> {
-    let mut degs: Vec<usize> = (0..graph.n()).map(|v| graph.degree(v)).collect();
-    degs.sort_unstable_by(|a, b| b.cmp(a)); // descending
+    let mut degs = graph.sorted_degrees();
+    degs.reverse(); // descending
     let mut pref = Vec::with_capacity(degs.len() + 1);
     pref.push(0);
     let mut sum = 0;
@@ -62,13 +108,226 @@ pub fn solve_maxk<'g, R>(
 where
     R: Rng + ?Sized,
 {
+    solve_maxk_with(graph, rng, p, &mut NoObserver)
+}
+
+/// [`solve_maxk`] forwarding every inner fixed-k step to `obs`.
+pub fn solve_maxk_with<'g, R, O>(
+    graph: &'g Graph,
+    rng: &mut R,
+    p: &Params,
+    obs: &mut O,
+) -> Solution<'g>
+where
+    R: Rng + ?Sized,
+    O: Observer + ?Sized,
+{
+    maxk_from(graph, None, rng, p, obs)
+}
+
+/// Max-k for each γ of `gammas`, largest γ first: every search starts
+/// from the set found for the previous γ (feasible for all smaller γ), so
+/// sizes up to it are not searched again and the fixed-k searches above
+/// it are warm-started.  Returns (γ, best set) in descending γ.
+///
+/// Panics unless every γ is in (0, 1].
+pub fn gamma_profile<'g, R>(graph: &'g Graph, gammas: &[f64], rng: &mut R, p: &Params) -> Vec<(f64, Solution<'g>)>
+where
+    R: Rng + ?Sized,
+{
+    assert!(gammas.iter().all(|g| *g > 0.0 && *g <= 1.0), "gamma must be in (0, 1]");
+    let mut gammas = gammas.to_vec();
+    gammas.sort_by(|a, b| b.total_cmp(a));
+    let mut profile: Vec<(f64, Solution<'g>)> = Vec::with_capacity(gammas.len());
+    for gamma in gammas {
+        let p = Params { gamma_target: gamma, ..p.clone() };
+        let prev = profile.last().map(|(_, s)| s.clone());
+        let sol = maxk_from(graph, prev, rng, &p, &mut NoObserver);
+        profile.push((gamma, sol));
+    }
+    profile
+}
+
+/// Elite sets [`densest_for_sizes`] shares between sizes.
+const DENSEST_ELITE: usize = 8;
+
+/// Densest set found for each size of `sizes`, in that order (sizes
+/// outside 2..=n get an empty set).  The distinct sizes are solved in
+/// ascending order by fixed-k searches at γ = 1, which only end early on
+/// a k-clique; each starts from the elite set (run bests of the smaller
+/// sizes) that keeps the most edges once resized to k, and inherits the
+/// frequency memory of the previous size.  `budget` moves are split
+/// evenly over the sizes.  Finally every result is peeled down to the
+/// next smaller size and replaces the set found there if it has more
+/// edges.  γ, the constraints and the U1 cut of `p` are not used.
+pub fn densest_for_sizes<'g, R>(graph: &'g Graph, sizes: &[usize], budget: usize, rng: &mut R, p: &Params) -> Vec<Solution<'g>>
+where
+    R: Rng + ?Sized,
+{
+    let mut ks: Vec<usize> = sizes.iter().copied().filter(|k| (2..=graph.n()).contains(k)).collect();
+    ks.sort_unstable();
+    ks.dedup();
+    let q = Params {
+        gamma_target: 1.0,
+        min_degree_ratio: 0.0,
+        budget: None,
+        clique_mode: false,
+        u1_interval: 0,
+        restart_bests: DENSEST_ELITE,
+        max_iter: (budget / ks.len().max(1)).max(1),
+        ..p.clone()
+    };
+
+    let mut elite = SolutionPool::new(DENSEST_ELITE, 0);
+    let mut carry = Carry::default();
+    let mut found: Vec<Solution<'g>> = Vec::with_capacity(ks.len());
+    for &k in &ks {
+        carry.start = elite
+            .iter()
+            .map(|s| {
+                let mut start = s.clone();
+                resize_to_k(&mut start, k, rng);
+                start
+            })
+            .max_by_key(Solution::edges);
+        carry.tabu = None;
+        let mut bests = Vec::new();
+        let sol = search(graph, k, rng, &q, &mut NoObserver, &mut bests, Some(&mut carry));
+        for s in bests.iter().chain([&sol]) {
+            elite.insert(s);
+        }
+        found.push(sol);
+    }
+
+    // a larger dense set peeled down may beat the smaller search
+    for i in (1..found.len()).rev() {
+        let mut peeled = found[i].clone();
+        resize_to_k(&mut peeled, ks[i - 1], rng);
+        if peeled.edges() > found[i - 1].edges() {
+            found[i - 1] = peeled;
+        }
+    }
+    sizes
+        .iter()
+        .map(|k| ks.binary_search(k).map_or_else(|_| Solution::new(graph), |i| found[i].clone()))
+        .collect()
+}
+
+/// One step of [`grow_profile`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct GrowStep {
+    pub size:     usize,
+    pub edges:    usize,
+    pub density:  f64,
+    /// Members after the step, ascending.
+    pub members:  Vec<usize>,
+    /// `true` if the greedy step fell below γ and a warm-started fixed-k
+    /// search replaced the set (which may then drop earlier members).
+    pub repaired: bool,
+}
+
+impl GrowStep {
+    fn of(sol: &Solution<'_>, repaired: bool) -> Self {
+        let members = sol.bitset().iter_ones().collect();
+        Self { size: sol.size(), edges: sol.edges(), density: sol.density(), members, repaired }
+    }
+}
+
+/// Growth curve of `seeds`: the seed set first, then one step per added
+/// vertex.  A step adds the outsider with the most neighbours in the set
+/// (ties: higher degree, then lower id); if that leaves the set short of
+/// the constraints of `p`, a fixed-k search started from it (`p.max_iter`
+/// moves) may replace it.  Growth stops after the first step that stays
+/// infeasible (it is recorded, to show where density gives way) or at n.
+///
+/// Panics if `seeds` is empty or holds an out-of-range vertex.
+pub fn grow_profile<R>(graph: &Graph, seeds: &[usize], rng: &mut R, p: &Params) -> Vec<GrowStep>
+where
+    R: Rng + ?Sized,
+{
+    assert!(!seeds.is_empty(), "seed set must not be empty");
+    assert!(seeds.iter().all(|&v| v < graph.n()), "seed vertex out of range");
+    let mut sol = Solution::new(graph);
+    for &v in seeds {
+        if !sol.bitset()[v] {
+            sol.add(v);
+        }
+    }
+    sol.track_degrees();
+    let mut profile = vec![GrowStep::of(&sol, false)];
+    while sol.size() < graph.n() {
+        let v = sol
+            .outsiders_to_scan()
+            .max_by_key(|&v| (sol.deg_in(v), graph.degree(v), std::cmp::Reverse(v)))
+            .unwrap();
+        sol.add(v);
+        let mut repaired = false;
+        if !p.accepts(&sol) {
+            let found = solve_fixed_k_from(sol.clone(), rng, p, &mut NoObserver);
+            if p.accepts(&found) {
+                sol = found;
+                sol.track_degrees();
+                repaired = true;
+            }
+        }
+        profile.push(GrowStep::of(&sol, repaired));
+        if !p.accepts(&sol) {
+            break;
+        }
+    }
+    profile
+}
+
+/// The max-k search, optionally seeded with a set `prev` that becomes the
+/// incumbent if it is feasible and at least as large as the greedy bounds
+/// (and then warm-starts every fixed-k search).
+pub(crate) fn maxk_from<'g, R, O>(graph: &'g Graph, prev: Option<Solution<'g>>, rng: &mut R, p: &Params, obs: &mut O) -> Solution<'g>
+where
+    R: Rng + ?Sized,
+    O: Observer + ?Sized,
+{
+    if p.uses_clique_mode() {
+        return clique_search(graph, prev, usize::MAX, rng, p, obs);
+    }
+    let deadline = p.time_limit.and_then(|t| Instant::now().checked_add(t));
     // 1) initial greedy γ-feasible solution
     let mut best_sol = greedy_until_gamma(graph, p.gamma_target, rng);
+    let mut clique = greedy_clique(graph, p.clique_starts);
+    if let Some(b) = &p.budget {
+        while !p.within_budget(&clique) {
+            let u = clique.bitset().iter_ones().max_by(|&a, &c| b.costs[a].total_cmp(&b.costs[c])).unwrap();
+            clique.remove(u);
+        }
+    }
+    if clique.size() > best_sol.size()
+        || !best_sol.meets_min_degree(p.min_degree_ratio)
+        || !p.within_budget(&best_sol)
+    {
+        best_sol = clique;
+    }
+    let prev = prev.filter(|s| p.accepts(s) && s.size() >= best_sol.size());
+    let warm_start = p.warm_start || prev.is_some();
+    if let Some(s) = prev {
+        best_sol = s;
+    }
+    obs.on_incumbent(&best_sol);
     let k_lb = best_sol.size();
 
     // 2) degree-prefix for quick UB checks
     let pref = degree_prefix(graph);
+    // cost of the i cheapest vertices, for the budget bound
+    let cheapest: Vec<f64> = p.budget.as_ref().map_or_else(Vec::new, |b| {
+        let mut costs = b.costs.clone();
+        costs.sort_by(f64::total_cmp);
+        std::iter::once(0.0).chain(costs.iter().scan(0.0, |s, &c| { *s += c; Some(*s) })).collect()
+    });
 
+    let mut carry = Carry::default();
+    // per-k time left over from earlier slices
+    let mut banked = Duration::ZERO;
+    // consecutive fixed-k failures above the current best
+    let mut failures = 0usize;
+    let mut reason = StopReason::Exhausted;
     let n = graph.n();
     for k in k_lb..=n {
         // already feasible at this size?
@@ -77,35 +336,72 @@ where
         }
 
         // compute how many edges we need to satisfy γ at size k:
-        let clique_edges = k.saturating_mul(k.saturating_sub(1)) / 2;
-        let required = (p.gamma_target * (clique_edges as f64)).ceil() as usize;
+        let required = required_edges(k, p.gamma_target);
 
-        // quick impossibility test
-        if ub_edges(&pref, k) < required {
+        // quick impossibility test (k-th largest degree vs. the degree floor)
+        if ub_edges(&pref, k) < required
+            || pref[k] - pref[k - 1] < required_degree(k, p.min_degree_ratio)
+            || p.budget.as_ref().is_some_and(|b| !b.allows(cheapest[k]))
+        {
             // first impossible above current best → stop
             if k > best_sol.size() {
+                reason = StopReason::Bound;
                 break;
             }
             continue;
         }
 
         // 3) expensive tabu search for fixed k
-        let sol_k = solve_fixed_k(graph, k, rng, p);
+        carry.start = (warm_start && best_sol.size() > 0).then(|| {
+            let mut start = best_sol.clone();
+            resize_to_k(&mut start, k, rng);
+            start
+        });
+        if !p.carry_memory {
+            carry.freq.clear();
+            carry.tabu = None;
+        }
+        let started = Instant::now();
+        let slice = p.per_k_time_limit.map(|t| t + banked);
+        let end = match (slice.and_then(|s| started.checked_add(s)), deadline) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        let inner = &mut InnerObserver(&mut *obs, end);
+        let sol_k = search(graph, k, rng, p, inner, &mut Vec::new(), Some(&mut carry));
+        if let Some(s) = slice {
+            banked = s.saturating_sub(started.elapsed());
+        }
 
         // if feasible, update best; otherwise, first failure above best → stop
-        if sol_k.density() + f64::EPSILON >= p.gamma_target {
+        let feasible = p.accepts(&sol_k);
+        if feasible {
             best_sol = sol_k;
-        } else if k > best_sol.size() {
+            obs.on_incumbent(&best_sol);
+            failures = 0;
+        }
+        if obs.should_stop() || deadline.is_some_and(|d| Instant::now() >= d) {
+            reason = StopReason::Interrupted;
             break;
         }
+        if !feasible && k > best_sol.size() {
+            failures += 1;
+            if failures > p.maxk_lookahead {
+                reason = StopReason::Failure;
+                break;
+            }
+        }
     }
 
+    obs.on_finish(reason);
     best_sol
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::params::Budget;
+    use crate::testgraphs::{clique_edges, cycle_edges, k5_with_tail, mod3_graph};
     use rand_chacha::ChaCha8Rng;
     use rand::SeedableRng;
 
@@ -115,10 +411,141 @@ mod tests {
         let edges = &[(0,1),(1,2),(0,2),(2,3)];
         let g = Graph::from_edge_list(4, edges);
         let mut rng = ChaCha8Rng::seed_from_u64(0);
-        let mut p = Params::default();
-        p.gamma_target = 1.0;
+        let p = Params { gamma_target: 1.0, ..Params::default() };
         let sol = solve_maxk(&g, &mut rng, &p);
         assert_eq!(sol.size(), 3);
         assert!((sol.density() - 1.0).abs() < 1e-12);
     }
+
+    #[test]
+    fn warm_start_keeps_optimum() {
+        let g = k5_with_tail();
+        let p = Params {
+            gamma_target: 1.0,
+            warm_start: true,
+            carry_memory: true,
+            ..Params::default()
+        };
+        let mut rng = ChaCha8Rng::seed_from_u64(4);
+        let sol = solve_maxk(&g, &mut rng, &p);
+        assert_eq!(sol.size(), 5);
+        assert!((sol.density() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn gamma_profile_grows_as_gamma_drops() {
+        let g = k5_with_tail();
+        let p = Params { max_iter: 2_000, ..Params::default() };
+        let profile = gamma_profile(&g, &[0.5, 1.0, 0.8], &mut ChaCha8Rng::seed_from_u64(1), &p);
+        let gammas: Vec<f64> = profile.iter().map(|(g, _)| *g).collect();
+        assert_eq!(gammas, vec![1.0, 0.8, 0.5]);
+        assert_eq!(profile[0].1.size(), 5);
+        // K5 + 5 has 11 of 15 edges
+        assert_eq!(profile[1].1.size(), 5);
+        assert!(profile[2].1.size() >= 6);
+        assert!(profile.iter().all(|(g, s)| s.density() + 1e-12 >= *g));
+    }
+
+    #[test]
+    fn growth_curve_from_seeds() {
+        let g = k5_with_tail();
+        let p = Params { gamma_target: 0.7, max_iter: 1_000, ..Params::default() };
+        let curve = grow_profile(&g, &[0, 1], &mut ChaCha8Rng::seed_from_u64(0), &p);
+        let sizes: Vec<usize> = curve.iter().map(|s| s.size).collect();
+        assert_eq!(sizes, vec![2, 3, 4, 5, 6, 7]);
+        // the K5 is filled first (4 before 2, 3 by degree), then 5 and 6
+        assert_eq!(curve[1].members, vec![0, 1, 4]);
+        assert_eq!(curve[4].edges, 11);
+        assert!(curve[..5].iter().all(|s| s.density >= 0.7 && !s.repaired));
+        // 12 of 21 pairs: below γ, and no 7-set does better
+        assert_eq!(curve[5].edges, 12);
+        assert!(curve[5].density < 0.7);
+    }
+
+    #[test]
+    fn densest_sets_per_size() {
+        let g = k5_with_tail();
+        let p = Params { stagnation_iter: 50, ..Params::default() };
+        let sets = densest_for_sizes(&g, &[6, 3, 1, 5, 3, 9], 3_000, &mut ChaCha8Rng::seed_from_u64(0), &p);
+        let edges: Vec<usize> = sets.iter().map(Solution::edges).collect();
+        assert_eq!(edges, vec![11, 3, 0, 10, 3, 0]);
+        assert_eq!(sets[3].bitset().iter_ones().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4]);
+        assert_eq!((sets[2].size(), sets[5].size()), (0, 0));
+    }
+
+    #[test]
+    fn lookahead_probes_past_failure() {
+        #[derive(Default)]
+        struct MaxK(usize);
+        impl Observer for MaxK {
+            fn on_restart(&mut self, rec: &RestartRecord) { self.0 = self.0.max(rec.k); }
+        }
+
+        let g = mod3_graph();
+        let probe = |lookahead| {
+            let p = Params { max_iter: 500, maxk_lookahead: lookahead, ..Params::default() };
+            let mut obs = MaxK::default();
+            solve_maxk_with(&g, &mut ChaCha8Rng::seed_from_u64(2), &p, &mut obs);
+            obs.0
+        };
+        assert!(probe(2) > probe(0));
+    }
+
+    #[test]
+    fn per_k_slices_end_hopeless_searches() {
+        // no k above the greedy bound is feasible at γ = 1 here, so without
+        // a slice every probe would run to max_iter
+        let g = mod3_graph();
+        let p = Params {
+            gamma_target: 1.0,
+            maxk_lookahead: 3,
+            per_k_time_limit: Some(Duration::from_millis(20)),
+            ..Params::default()
+        };
+        let started = Instant::now();
+        let sol = solve_maxk(&g, &mut ChaCha8Rng::seed_from_u64(0), &p);
+        assert!(sol.is_gamma_feasible(1.0));
+        assert!(started.elapsed() < Duration::from_secs(30));
+    }
+
+    #[test]
+    fn time_limit_bounds_the_whole_search() {
+        // a 30-cycle has no triangle: the k = 3 search would run to max_iter
+        let g = Graph::from_edge_list(30, &cycle_edges(30));
+        let p = Params { gamma_target: 1.0, time_limit: Some(Duration::from_millis(50)), ..Params::default() };
+        let started = Instant::now();
+        let sol = solve_maxk(&g, &mut ChaCha8Rng::seed_from_u64(0), &p);
+        assert_eq!(sol.size(), 2);
+        assert!(started.elapsed() < Duration::from_secs(10));
+    }
+
+    #[test]
+    fn degree_floor_drops_spoke() {
+        // K5 on 0..5 plus 5 hanging off 0: {0..6} has density 11/15 ≥ 0.7
+        let mut edges = clique_edges(0..5);
+        edges.push((0, 5));
+        let g = Graph::from_edge_list(6, &edges);
+        let p = Params { gamma_target: 0.7, max_iter: 10_000, ..Params::default() };
+        assert_eq!(solve_maxk(&g, &mut ChaCha8Rng::seed_from_u64(0), &p).size(), 6);
+
+        let p = Params { min_degree_ratio: 0.5, ..p };
+        let sol = solve_maxk(&g, &mut ChaCha8Rng::seed_from_u64(0), &p);
+        assert_eq!(sol.size(), 5);
+        assert!(sol.is_feasible(0.7, 0.5));
+    }
+
+    #[test]
+    fn budget_prefers_cheap_clique() {
+        // K5 on 0..5 (cost 2 each) and K4 on 5..9 (cost 1 each)
+        let edges = [clique_edges(0..5), clique_edges(5..9)].concat();
+        let g = Graph::from_edge_list(9, &edges);
+        let p = Params { gamma_target: 1.0, max_iter: 10_000, stagnation_iter: 100, ..Params::default() };
+        assert_eq!(solve_maxk(&g, &mut ChaCha8Rng::seed_from_u64(0), &p).size(), 5);
+
+        let costs = [vec![2.0; 5], vec![1.0; 4]].concat();
+        let p = Params { budget: Some(Budget { costs, limit: 6.0 }), ..p };
+        let sol = solve_maxk(&g, &mut ChaCha8Rng::seed_from_u64(0), &p);
+        assert_eq!(sol.bitset().iter_ones().collect::<Vec<_>>(), vec![5, 6, 7, 8]);
+        assert!(p.accepts(&sol));
+    }
 }
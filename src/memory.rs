@@ -0,0 +1,203 @@
+// src/memory.rs
+//! Guard against adjacency matrices that do not fit in memory.
+//!
+//! A dense adjacency takes n² bits (half that in [`Layout::Triangular`]),
+//! so a large `n` aborts the process on allocation failure.  Before
+//! allocating, the checked constructors ([`Graph::try_with_layout`](crate::Graph::try_with_layout),
+//! [`GraphBuilder::try_build`](crate::GraphBuilder::try_build), the file
+//! parsers) compare [`Layout::bytes_with_edges`] (the CSR targets
+//! included when m is known) with
+//!
+//! • the cap set by [`set_memory_limit`], if any, and
+//! • the memory the OS reports available (`MemAvailable`, Linux only),
+//!   for adjacencies of at least `MIN_CHECKED_BYTES`,
+//!
+//! and return a [`MemoryError`] naming the smallest layout that would fit:
+//! [`Layout::Csr`] for sparse graphs, else [`Layout::Triangular`].
+//! Per-vertex arrays sized from untrusted input (the degree counts of
+//! [`crate::stream`]) go through the same limits.
+
+use crate::graph::Layout;
+use std::fmt;
+use std::io;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Adjacencies below this size skip the OS query.
+const MIN_CHECKED_BYTES: usize = 64 << 20;
+
+/// User cap in bytes; 0 = none.
+static LIMIT: AtomicUsize = AtomicUsize::new(0);
+
+/// Cap the adjacency the checked constructors allocate (`None` = no cap;
+/// the OS check still applies).
+pub fn set_memory_limit(bytes: Option<usize>) {
+    LIMIT.store(bytes.unwrap_or(0), Ordering::Relaxed);
+}
+
+/// Cap set by [`set_memory_limit`].
+pub fn memory_limit() -> Option<usize> {
+    Some(LIMIT.load(Ordering::Relaxed)).filter(|&b| b > 0)
+}
+
+/// Bytes the OS reports available, where known.
+pub fn available_bytes() -> Option<usize> {
+    let info = std::fs::read_to_string("/proc/meminfo").ok()?;
+    let line = info.lines().find(|l| l.starts_with("MemAvailable:"))?;
+    let kib: usize = line.split_whitespace().nth(1)?.parse().ok()?;
+    Some(kib * 1024)
+}
+
+/// An adjacency that would not fit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MemoryError {
+    pub n:         usize,
+    /// Edges, when known (the CSR size depends on them).
+    pub m:         Option<usize>,
+    pub layout:    Layout,
+    /// Bytes the adjacency needs.
+    pub needed:    usize,
+    /// Bytes allowed (the cap, or what the OS reports available).
+    pub available: usize,
+}
+
+impl MemoryError {
+    /// The smallest layout that would fit instead: [`Layout::Csr`] (when m
+    /// is known) or, from [`Layout::Rows`], [`Layout::Triangular`].
+    pub fn suggestion(&self) -> Option<Layout> {
+        let csr = self.m.filter(|_| self.layout != Layout::Csr).map(|_| Layout::Csr);
+        let tri = (self.layout == Layout::Rows).then_some(Layout::Triangular);
+        [csr, tri]
+            .into_iter()
+            .flatten()
+            .filter(|&l| self.bytes_in(l) <= self.available)
+            .min_by_key(|&l| self.bytes_in(l))
+    }
+
+    /// Bytes the adjacency would take in `layout`.
+    fn bytes_in(&self, layout: Layout) -> usize {
+        layout.bytes_with_edges(self.n, self.m.unwrap_or(0))
+    }
+}
+
+impl fmt::Display for MemoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mib = |b: usize| b as f64 / (1u64 << 20) as f64;
+        write!(
+            f,
+            "n = {} needs {:.1} MiB for the {:?} adjacency, only {:.1} MiB available",
+            self.n,
+            mib(self.needed),
+            self.layout,
+            mib(self.available)
+        )?;
+        if let Some(layout) = self.suggestion() {
+            write!(f, "; the {layout:?} layout needs {:.1} MiB", mib(self.bytes_in(layout)))?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for MemoryError {}
+
+impl From<MemoryError> for io::Error {
+    fn from(e: MemoryError) -> Self {
+        io::Error::new(io::ErrorKind::OutOfMemory, e)
+    }
+}
+
+/// Check an `n`-vertex adjacency in `layout` against `limit` (bytes).
+pub fn check_within(n: usize, layout: Layout, limit: usize) -> Result<(), MemoryError> {
+    check_graph_within(n, None, layout, limit)
+}
+
+/// Check an `n`-vertex adjacency in `layout` against the cap and the
+/// memory available.
+pub fn check(n: usize, layout: Layout) -> Result<(), MemoryError> {
+    check_graph(n, None, layout)
+}
+
+/// [`check`] for an `n`-vertex, `m`-edge graph: CSR targets included, and
+/// a CSR suggestion when it would fit.
+pub fn check_edges(n: usize, m: usize, layout: Layout) -> Result<(), MemoryError> {
+    check_graph(n, Some(m), layout)
+}
+
+fn check_graph(n: usize, m: Option<usize>, layout: Layout) -> Result<(), MemoryError> {
+    match allowance(layout.bytes_with_edges(n, m.unwrap_or(0))) {
+        Some(limit) => check_graph_within(n, m, layout, limit),
+        None => Ok(()),
+    }
+}
+
+fn check_graph_within(n: usize, m: Option<usize>, layout: Layout, limit: usize) -> Result<(), MemoryError> {
+    let needed = layout.bytes_with_edges(n, m.unwrap_or(0));
+    if needed <= limit {
+        Ok(())
+    } else {
+        Err(MemoryError { n, m, layout, needed, available: limit })
+    }
+}
+
+/// Check a `needed`-byte array (`what`, for the message) against the cap
+/// and the memory available; `Err(OutOfMemory)` if it would not fit.
+pub(crate) fn check_bytes(needed: usize, what: &str) -> io::Result<()> {
+    match allowance(needed) {
+        Some(limit) if needed > limit => {
+            let mib = |b: usize| b as f64 / (1u64 << 20) as f64;
+            let msg = format!("the {what} needs {:.1} MiB, only {:.1} MiB available", mib(needed), mib(limit));
+            Err(io::Error::new(io::ErrorKind::OutOfMemory, msg))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Bytes an allocation of `needed` bytes may take: the cap and, from
+/// `MIN_CHECKED_BYTES` on, the memory available; `None` if neither is known.
+pub(crate) fn allowance(needed: usize) -> Option<usize> {
+    let os = if needed >= MIN_CHECKED_BYTES { available_bytes() } else { None };
+    match (memory_limit(), os) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (a, b) => a.or(b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rows_over_limit_suggests_triangular() {
+        let n = 1000;
+        let limit = Layout::Rows.bytes_for(n) - 1;
+        let e = check_within(n, Layout::Rows, limit).unwrap_err();
+        assert_eq!(e.needed, Layout::Rows.bytes_for(n));
+        assert_eq!(e.suggestion(), Some(Layout::Triangular));
+        assert!(e.to_string().contains("Triangular"));
+        assert!(check_within(n, Layout::Triangular, limit).is_ok());
+
+        let e = check_within(n, Layout::Triangular, 1024).unwrap_err();
+        assert_eq!(e.suggestion(), None);
+        assert_eq!(io::Error::from(e).kind(), io::ErrorKind::OutOfMemory);
+    }
+
+    #[test]
+    fn sparse_graph_over_limit_suggests_csr() {
+        let (n, m) = (100_000, 200_000);
+        let csr = Layout::Csr.bytes_with_edges(n, m);
+        assert_eq!(csr, Layout::Csr.bytes_for(n) + 4 * 2 * m);
+
+        let e = check_graph_within(n, Some(m), Layout::Rows, csr).unwrap_err();
+        assert_eq!(e.suggestion(), Some(Layout::Csr));
+        assert!(e.to_string().contains("Csr"));
+
+        // the targets count: offsets alone would fit, the full CSR does not
+        let e = check_graph_within(n, Some(m), Layout::Csr, csr - 1).unwrap_err();
+        assert_eq!(e.needed, csr);
+        assert_eq!(e.suggestion(), None);
+
+        // dense: the triangle beats the lists
+        let m = n * (n - 1) / 4;
+        let e = check_graph_within(n, Some(m), Layout::Rows, Layout::Triangular.bytes_for(n)).unwrap_err();
+        assert_eq!(e.suggestion(), Some(Layout::Triangular));
+    }
+}
@@ -5,22 +5,361 @@
 //! scans all (u∈A, v∈B) for the best non-deteriorating or aspirational
 //! swap, executes it, updates frequency memory, steps the tabu clocks,
 //! and adapts tabu tenures.
+//!
+//! With a minimum-degree ratio θ (`Params::min_degree_ratio`), swaps whose
+//! newcomer would end up with fewer than ⌈θ(k−1)⌉ internal neighbours rank
+//! below those whose newcomer meets the floor, and a swap replacing a
+//! member below the floor by one meeting it is admissible even if it loses
+//! edges, as long as the density stays ≥ γ.
+//!
+//! Under a vertex-cost budget (`Params::budget`) a swap is admissible only
+//! if S stays within B afterwards or gets cheaper; the outsider set B only
+//! holds vertices affordable in exchange for the costliest member of A.
+//!
+//! Soft penalties (`Params::penalties`) shift the evaluation instead of
+//! restricting it: A and B take the extreme deg_in − pen, and a swap u→v
+//! scores its edge delta minus pen(v) − pen(u), both for admissibility
+//! (score ≥ 0) and for the ranking.  Aspiration still looks at density.
+//!
+//! `Params::scan` picks the outsiders B is built from: all of them, the
+//! frontier N(S) \ S, or a random sample per step.  [`ScanSelector`]
+//! resolves [`Scan::Auto`] for one solve: full scans up to
+//! `AUTO_FULL_MAX_N` vertices, otherwise the frontier, switching to
+//! samples while the scanned set or the measured step latency is too large.
+//!
+//! Optionally (`Params::double_swap`), when no swap is admissible a
+//! depth-2 lookahead over small candidate lists looks for a pair of
+//! consecutive swaps that is jointly non-deteriorating, escaping a local
+//! optimum without a perturbation.
 
-use crate::{params::Params, solution::Solution, tabu::DualTabu, Graph};
+use crate::{
+    params::{Budget, Params, Scan},
+    solution::{required_degree, Solution},
+    tabu::{scale_tenure, DualTabu},
+};
 use rand::Rng;
+use std::cmp::Reverse;
+use std::time::{Duration, Instant};
+
+/// Outcome of one search step, as reported to observers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MoveKind {
+    /// Non-tabu, non-deteriorating swap.
+    Swap,
+    /// Tabu swap accepted through aspiration.
+    Aspiration,
+    /// Two consecutive swaps chosen jointly (depth-2 lookahead).
+    DoubleSwap,
+    /// No admissible swap; only the tabu clock advanced.
+    Idle,
+    /// Mild perturbation (§ 3.4.2).
+    Mild,
+    /// Heavy perturbation (§ 3.4.2).
+    Heavy,
+    /// Strategic oscillation to k ± δ and back.
+    Oscillate,
+}
+
+impl MoveKind {
+    /// `true` if the step changed the solution.
+    #[inline]
+    pub fn is_move(self) -> bool {
+        self != MoveKind::Idle
+    }
+
+    /// `true` for mild/heavy perturbations (not counted as iterations).
+    #[inline]
+    pub fn is_perturbation(self) -> bool {
+        matches!(self, MoveKind::Mild | MoveKind::Heavy | MoveKind::Oscillate)
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            MoveKind::Swap       => "swap",
+            MoveKind::Aspiration => "aspiration",
+            MoveKind::DoubleSwap => "double_swap",
+            MoveKind::Idle       => "idle",
+            MoveKind::Mild       => "mild",
+            MoveKind::Heavy      => "heavy",
+            MoveKind::Oscillate  => "oscillate",
+        }
+    }
+}
+
+/// Exact change of m(S) when swapping member `u` out and outsider `v` in:
+/// gain − loss, minus the (u,v) edge that `gain` counts but the swap drops.
+#[inline]
+pub fn swap_delta(sol: &Solution<'_>, u: usize, v: usize) -> isize {
+    let graph = sol.graph();
+    let loss = sol.deg_in(u) as isize;
+    let gain = sol.deg_in(v) as isize;
+    gain - loss - graph.has_edge(u, v) as isize
+}
+
+/// A swap taking S from cost `cur` to `new` is allowed by the budget.
+#[inline]
+fn affordable(budget: &Budget, cur: f64, new: f64) -> bool {
+    budget.allows(new) || new < cur
+}
+
+/// Graphs with at most this many vertices are always scanned in full by
+/// [`Scan::Auto`].
+pub const AUTO_FULL_MAX_N: usize = 4_096;
+/// Outsiders per step once [`Scan::Auto`] samples.
+const AUTO_SAMPLE: usize = 256;
+/// Scanned outsiders above which [`Scan::Auto`] samples.
+const AUTO_SCAN_MAX: usize = 16 * AUTO_SAMPLE;
+/// Steps per [`Scan::Auto`] decision.
+const AUTO_WINDOW: u32 = 64;
+/// Mean step latency above which [`Scan::Auto`] samples.
+const AUTO_MAX_LATENCY: Duration = Duration::from_micros(250);
+
+/// Scan mode over one solve.  Fixed modes pass through; [`Scan::Auto`]
+/// re-decides between frontier and sampled scans every `AUTO_WINDOW`
+/// steps from the number of outsiders a scan would touch and the mean
+/// step latency of the last window.
+#[derive(Clone, Debug)]
+pub struct ScanSelector {
+    mode:    Scan,
+    auto:    bool,
+    steps:   u32,
+    window:  Instant,
+    /// Latency per scanned outsider in the last frontier window.
+    per_out: Duration,
+}
+
+impl ScanSelector {
+    pub fn new(n: usize, p: &Params) -> Self {
+        let (mode, auto) = match p.scan {
+            Scan::Auto if n <= AUTO_FULL_MAX_N => (Scan::Full, false),
+            Scan::Auto => (Scan::Frontier, true),
+            fixed => (fixed, false),
+        };
+        Self { mode, auto, steps: 0, window: Instant::now(), per_out: Duration::ZERO }
+    }
+
+    /// Mode for the next step (never [`Scan::Auto`]).
+    #[inline]
+    pub fn mode(&self) -> Scan {
+        self.mode
+    }
+
+    /// `true` if solutions should track their frontier.
+    pub fn tracks_frontier(&self) -> bool {
+        self.auto || self.mode == Scan::Frontier
+    }
+
+    /// Count one step taken on `sol`.
+    pub fn observe(&mut self, sol: &Solution<'_>) {
+        if !self.auto {
+            return;
+        }
+        self.steps += 1;
+        if self.steps < AUTO_WINDOW {
+            return;
+        }
+        let latency = self.window.elapsed() / self.steps;
+        // an empty or untracked frontier means every outsider is scanned
+        let scanned = match sol.frontier() {
+            Some(f) if !f.is_empty() => f.len(),
+            _ => sol.graph().n() - sol.size(),
+        };
+        self.mode = match self.mode {
+            Scan::Frontier => {
+                self.per_out = latency / scanned.max(1) as u32;
+                if scanned > AUTO_SCAN_MAX || latency > AUTO_MAX_LATENCY {
+                    Scan::Sampled(AUTO_SAMPLE)
+                } else {
+                    Scan::Frontier
+                }
+            }
+            // back once a frontier scan is expected to be cheap again
+            Scan::Sampled(_)
+                if scanned <= AUTO_SCAN_MAX / 2 && self.per_out * scanned as u32 <= AUTO_MAX_LATENCY / 2 =>
+            {
+                Scan::Frontier
+            }
+            mode => mode,
+        };
+        self.steps = 0;
+        self.window = Instant::now();
+    }
+}
+
+/// Up to `size` random outsiders (ascending, no repeats): from the
+/// frontier when tracked and non-empty, otherwise from all of V.
+fn sample_outsiders<R>(sol: &Solution<'_>, size: usize, rng: &mut R) -> Vec<usize>
+where
+    R: Rng + ?Sized,
+{
+    let mut picks: Vec<usize> = match sol.frontier() {
+        Some(f) if f.len() <= size => f.to_vec(),
+        Some(f) if !f.is_empty() => (0..size).map(|_| f[rng.gen_range(0..f.len())]).collect(),
+        _ => {
+            let n = sol.graph().n();
+            (0..size).map(|_| rng.gen_range(0..n)).filter(|&v| !sol.bitset()[v]).collect()
+        }
+    };
+    picks.sort_unstable();
+    picks.dedup();
+    picks
+}
+
+/// Non-tabu critical sets of `sol`: members of minimum internal degree
+/// (A) and outsiders of maximum internal degree (B), ascending.  B is
+/// drawn from `sample` if given, otherwise from every outsider worth
+/// scanning.  With a budget (and the current cost of S), B only considers
+/// outsiders affordable in exchange for the costliest member of A.  With
+/// penalties both sides rank by internal degree minus penalty.
+fn critical_sets(
+    sol: &Solution<'_>,
+    tabu: &DualTabu,
+    sample: Option<&[usize]>,
+    budget: Option<(&Budget, f64)>,
+    penalties: Option<&[f64]>,
+) -> (Vec<usize>, Vec<usize>) {
+
+    // one batched pass on sparse graphs, per-vertex counts otherwise
+    let batch = sol.batch_degrees();
+    let deg = |v: usize| {
+        let d = batch.as_ref().map_or_else(|| sol.deg_in(v), |d| d[v] as usize) as f64;
+        penalties.map_or(d, |pen| d - pen[v])
+    };
+
+    // compute MinInS and MaxOutS
+    let mut min_in = f64::INFINITY;
+    for u in sol.bitset().iter_ones() {
+        let deg_in = deg(u);
+        min_in = min_in.min(deg_in);
+    }
+
+    // build critical set A
+    let mut a_set = Vec::new();
+    for u in sol.bitset().iter_ones() {
+        let deg_in = deg(u);
+        if deg_in == min_in && !tabu.is_tabu_u(u) {
+            a_set.push(u);
+        }
+    }
+
+    // outsiders considered for B (all of them without a budget)
+    let release = budget.map(|(b, cur)| {
+        let top = a_set.iter().map(|&u| b.costs[u]).fold(f64::NEG_INFINITY, f64::max);
+        (b, cur, top)
+    });
+    let eligible = |v: usize| release.is_none_or(|(b, cur, top)| affordable(b, cur, cur - top + b.costs[v]));
+    let outsiders = || {
+        let (listed, scan) = match sample {
+            Some(s) => (Some(s.iter().copied()), None),
+            None => (None, Some(sol.outsiders_to_scan())),
+        };
+        listed.into_iter().flatten().chain(scan.into_iter().flatten()).filter(|&v| eligible(v))
+    };
+
+    let mut max_out = f64::NEG_INFINITY;
+    for v in outsiders() {
+        let deg_out = deg(v);
+        max_out = max_out.max(deg_out);
+    }
+    let mut b_set = Vec::new();
+    for v in outsiders() {
+        let deg_out = deg(v);
+        if deg_out == max_out && !tabu.is_tabu_v(v) {
+            b_set.push(v);
+        }
+    }
+    (a_set, b_set)
+}
+
+/// Candidates per side considered at each lookahead level.
+const DOUBLE_SWAP_CAP: usize = 8;
+
+/// The `DOUBLE_SWAP_CAP` non-tabu members of lowest and outsiders of
+/// highest internal degree, skipping `skip_in` / `skip_out`.
+fn ranked_candidates(
+    sol: &Solution<'_>,
+    tabu: &DualTabu,
+    skip_in: usize,
+    skip_out: usize,
+) -> (Vec<usize>, Vec<usize>) {
+    let mut ins: Vec<(usize, usize)> = sol.bitset()
+        .iter_ones()
+        .filter(|&u| u != skip_in && !tabu.is_tabu_u(u))
+        .map(|u| (sol.deg_in(u), u))
+        .collect();
+    let mut outs: Vec<(Reverse<usize>, usize)> = sol.outsiders_to_scan()
+        .filter(|&v| v != skip_out && !tabu.is_tabu_v(v))
+        .map(|v| (Reverse(sol.deg_in(v)), v))
+        .collect();
+    ins.sort_unstable();
+    outs.sort_unstable();
+    (
+        ins.into_iter().take(DOUBLE_SWAP_CAP).map(|(_, u)| u).collect(),
+        outs.into_iter().take(DOUBLE_SWAP_CAP).map(|(_, v)| v).collect(),
+    )
+}
+
+/// Best pair of consecutive non-tabu swaps u1→v1, u2→v2 (u2 ≠ v1,
+/// v2 ≠ u1) over small ranked candidate lists, if it is jointly
+/// non-deteriorating, as `(delta, [u1, v1, u2, v2])`.  `sol` is restored
+/// on return.
+fn best_double_swap(sol: &mut Solution<'_>, tabu: &DualTabu) -> Option<(isize, [usize; 4])> {
+    let (a_set, b_set) = ranked_candidates(sol, tabu, usize::MAX, usize::MAX);
+    let mut best: Option<(isize, [usize; 4])> = None;
+    for &u1 in &a_set {
+        for &v1 in &b_set {
+            let d1 = swap_delta(sol, u1, v1);
+            sol.checkpoint();
+            sol.remove(u1);
+            sol.add(v1);
+            let (a2, b2) = ranked_candidates(sol, tabu, v1, u1);
+            for &u2 in &a2 {
+                for &v2 in &b2 {
+                    let d = d1 + swap_delta(sol, u2, v2);
+                    if d >= 0 && best.is_none_or(|(bd, _)| d > bd) {
+                        best = Some((d, [u1, v1, u2, v2]));
+                    }
+                }
+            }
+            sol.rollback();
+        }
+    }
+    best
+}
 
 /// Attempt a single intensification move.  
 /// - `best_global_rho`: best density seen so far (for aspiration).  
-/// - `freq`: long‐term frequency memory (increment for any swapped u/v).  
-/// Returns `true` if a swap was performed.
+/// - `freq`: long‐term frequency memory (increment for any swapped u/v).
+///
+/// Returns which kind of swap was performed ([`MoveKind::Idle`] if none).
+/// Scans per `p.scan`, with [`Scan::Auto`] taken as [`Scan::Full`]; see
+/// [`improve_once_with`].
 pub fn improve_once<'g, R>(
     sol: &mut Solution<'g>,
     tabu: &mut DualTabu,
     best_global_rho: f64,
-    freq: &mut Vec<usize>,
+    freq: &mut [usize],
+    p: &Params,
+    rng: &mut R,
+) -> MoveKind
+where
+    R: Rng + ?Sized,
+{
+    let scan = if p.scan == Scan::Auto { Scan::Full } else { p.scan };
+    improve_once_with(sol, tabu, best_global_rho, freq, p, scan, rng)
+}
+
+/// [`improve_once`] with an explicit scan mode (e.g. from a
+/// [`ScanSelector`]).
+pub fn improve_once_with<'g, R>(
+    sol: &mut Solution<'g>,
+    tabu: &mut DualTabu,
+    best_global_rho: f64,
+    freq: &mut [usize],
     p: &Params,
+    scan: Scan,
     rng: &mut R,
-) -> bool
+) -> MoveKind
 where
     R: Rng + ?Sized,
 {
@@ -28,98 +367,62 @@ where
     let k = sol.size();
     // trivial if nothing to swap
     if k < 1 || k > graph.n() {
-        return false;
+        return MoveKind::Idle;
     }
 
     let m_cur = sol.edges();
     let max_edges = k.saturating_mul(k.saturating_sub(1)) / 2;
+    let floor = required_degree(k, p.min_degree_ratio);
+    let budget = p.budget.as_ref().map(|b| (b, b.cost_of(sol.bitset())));
 
-    // 1) compute MinInS and MaxOutS
-    let mut min_in = usize::MAX;
-    for u in sol.bitset().iter_ones() {
-        let deg_in = graph
-            .neigh_row(u)
-            .iter_ones()
-            .filter(|&j| sol.bitset()[j])
-            .count();
-        min_in = min_in.min(deg_in);
-    }
-    let mut max_out = 0;
-    for v in 0..graph.n() {
-        if sol.bitset()[v] { continue; }
-        let deg_out = graph
-            .neigh_row(v)
-            .iter_ones()
-            .filter(|&j| sol.bitset()[j])
-            .count();
-        max_out = max_out.max(deg_out);
-    }
-
-    // 2) build critical sets A and B
-    let mut A = Vec::new();
-    for u in sol.bitset().iter_ones() {
-        let deg_in = graph
-            .neigh_row(u)
-            .iter_ones()
-            .filter(|&j| sol.bitset()[j])
-            .count();
-        if deg_in == min_in && !tabu.is_tabu_u(u) {
-            A.push(u);
-        }
-    }
-    let mut B = Vec::new();
-    for v in 0..graph.n() {
-        if sol.bitset()[v] { continue; }
-        let deg_out = graph
-            .neigh_row(v)
-            .iter_ones()
-            .filter(|&j| sol.bitset()[j])
-            .count();
-        if deg_out == max_out && !tabu.is_tabu_v(v) {
-            B.push(v);
-        }
-    }
+    // 1–2) critical sets A (min internal deg) and B (max external deg)
+    let sample = match scan {
+        Scan::Sampled(size) => Some(sample_outsiders(sol, size, rng)),
+        _ => None,
+    };
+    let penalties = p.penalties.as_deref();
+    let pen = |v: usize| penalties.map_or(0.0, |pen| pen[v]);
+    let (a_set, b_set) = critical_sets(sol, tabu, sample.as_deref(), budget, penalties);
 
     // 3) scan A×B for best allowed (non-deteriorating) or aspirational swap
-    let mut best_allowed: Option<(f64, usize, usize)> = None;
+    let mut best_allowed: Option<(bool, f64, usize, usize)> = None;
     let mut best_aspire:  Option<(f64, usize, usize)> = None;
 
-    for &u in &A {
+    for &u in &a_set {
         // loss = how many edges we lose by removing u
-        let loss = graph
-            .neigh_row(u)
-            .iter_ones()
-            .filter(|&j| sol.bitset()[j])
-            .count();
+        let loss = sol.deg_in(u);
 
-        for &v in &B {
+        for &v in &b_set {
             // gain = how many edges we gain by adding v
-            let gain = graph
-                .neigh_row(v)
-                .iter_ones()
-                .filter(|&j| sol.bitset()[j])
-                .count();
-
-            // new total edges and density
-            let m_new = m_cur + gain.saturating_sub(loss);
+            let gain = sol.deg_in(v);
+
+            // exact new total edges and density
+            let delta = swap_delta(sol, u, v);
+            let m_new = (m_cur as isize + delta) as usize;
             let rho_new = (m_new as f64) / (max_edges as f64);
+            // edge delta net of the penalties, and its density equivalent
+            let shift = pen(u) - pen(v);
+            let score = delta as f64 + shift;
+            let rank = rho_new + shift / max_edges.max(1) as f64;
 
+            if budget.is_some_and(|(b, cur)| !affordable(b, cur, cur - b.costs[u] + b.costs[v])) {
+                continue;
+            }
             let forbidden = tabu.is_tabu_u(u) || tabu.is_tabu_v(v);
+            // newcomer keeps the minimum-degree floor (always true for θ = 0)
+            let fits = gain - graph.has_edge(u, v) as usize >= floor;
+
+            // trades density surplus for a member below the floor
+            let repair = fits && loss < floor && rho_new + f64::EPSILON >= p.gamma_target;
 
-            if !forbidden && gain >= loss {
+            if !forbidden && (score >= 0.0 || repair) {
                 // non-deteriorating allowed swap
-                if best_allowed
-                    .as_ref()
-                    .map_or(true, |&(r, _, _)| rho_new >= r)
-                {
-                    best_allowed = Some((rho_new, u, v));
+                if best_allowed.is_none_or(|(f, r, _, _)| (fits, rank) >= (f, r)) {
+                    best_allowed = Some((fits, rank, u, v));
                 }
             } else if forbidden && rho_new > best_global_rho {
                 // aspiration over global best density
-                if best_aspire
-                    .as_ref()
-                    .map_or(true, |&(r, _, _)| rho_new > r)
-                {
+                if best_aspire.is_none_or(|(r, _, _)| rho_new > r) {
                     best_aspire = Some((rho_new, u, v));
                 }
             }
@@ -127,35 +430,66 @@ where
     }
 
     // choose aspirational if no allowed
-    let chosen = best_allowed.or(best_aspire);
+    let chosen = match (best_allowed, best_aspire) {
+        (Some((_, _, u, v)), _) => Some((MoveKind::Swap, u, v)),
+        (None, Some((_, u, v))) => Some((MoveKind::Aspiration, u, v)),
+        (None, None) => None,
+    };
 
     // 4) execute swap if found
-    let did_swap = if let Some((_, u, v)) = chosen {
+    let kind = if let Some((kind, u, v)) = chosen {
         sol.remove(u);
         sol.add(v);
+        let delta = sol.edges() as isize - m_cur as isize;
 
         // update long-term frequency memory
         freq[u] = freq[u].saturating_add(1);
         freq[v] = freq[v].saturating_add(1);
 
-        // mark tabu for u,v
-        tabu.forbid_u(u);
-        tabu.forbid_v(v);
-        true
+        // mark tabu for u,v (tenures optionally scaled by move quality)
+        let (tu, tv) = tabu.tenures();
+        tabu.forbid_u_for(u, scale_tenure(tu, delta, p.tenure_delta_scale));
+        tabu.forbid_v_for(v, scale_tenure(tv, delta, p.tenure_delta_scale));
+        kind
+    } else if let Some((delta, [u1, v1, u2, v2])) =
+        p.double_swap.then(|| best_double_swap(sol, tabu)).flatten().filter(|(_, [u1, v1, u2, v2])| {
+            budget.is_none_or(|(b, cur)| {
+                affordable(b, cur, cur - b.costs[*u1] - b.costs[*u2] + b.costs[*v1] + b.costs[*v2])
+            })
+        })
+        .filter(|(delta, [u1, v1, u2, v2])| *delta as f64 + pen(*u1) + pen(*u2) - pen(*v1) - pen(*v2) >= 0.0)
+    {
+        // depth-2 escape: both swaps, with all four vertices made tabu
+        sol.remove(u1);
+        sol.add(v1);
+        sol.remove(u2);
+        sol.add(v2);
+
+        let (tu, tv) = tabu.tenures();
+        for u in [u1, u2] {
+            freq[u] = freq[u].saturating_add(1);
+            tabu.forbid_u_for(u, scale_tenure(tu, delta, p.tenure_delta_scale));
+        }
+        for v in [v1, v2] {
+            freq[v] = freq[v].saturating_add(1);
+            tabu.forbid_v_for(v, scale_tenure(tv, delta, p.tenure_delta_scale));
+        }
+        MoveKind::DoubleSwap
     } else {
-        false
+        MoveKind::Idle
     };
 
     // 5) advance tabu clock and adapt tenures
     tabu.step();
     tabu.update_tenures(sol.size(), sol.edges(), p.gamma_target, rng);
 
-    did_swap
+    kind
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Graph;
     use rand_chacha::ChaCha8Rng;
     use rand::SeedableRng;
 
@@ -169,12 +503,130 @@ mod tests {
         let mut rng = ChaCha8Rng::seed_from_u64(0);
         let mut tabu = DualTabu::new(4, 1, 1);
         let mut freq = vec![0; 4];
-        let mut p = Params::default();
-        p.gamma_target = 0.5;
+        let p = Params { gamma_target: 0.5, ..Params::default() };
 
         let before = sol.density();
         let did = improve_once(&mut sol, &mut tabu, before, &mut freq, &p, &mut rng);
-        assert!(did, "Should perform at least one swap");
+        assert!(did.is_move(), "Should perform at least one swap");
         assert!(sol.density() >= before);
     }
+
+    #[test]
+    fn penalties_steer_without_forbidding() {
+        // S = {0,1,2} with the edge 0-1; outsiders 3 and 4 both close a
+        // triangle with it, 2 is the member to go
+        let graph = Graph::from_edge_list(5, &[(0, 1), (0, 3), (1, 3), (0, 4), (1, 4)]);
+        let swap = |penalties: Vec<f64>| {
+            let mut sol = Solution::new(&graph);
+            for v in [0, 1, 2] { sol.add(v); }
+            let mut tabu = DualTabu::new(5, 1, 1);
+            let p = Params { gamma_target: 1.0, penalties: Some(penalties), ..Params::default() };
+            let kind = improve_once(&mut sol, &mut tabu, 0.0, &mut [0; 5], &p, &mut ChaCha8Rng::seed_from_u64(0));
+            (kind, sol.bitset().iter_ones().collect::<Vec<_>>())
+        };
+        assert_eq!(swap(vec![0.0, 0.0, 0.0, 0.0, 0.5]).1, vec![0, 1, 3]);
+        assert_eq!(swap(vec![0.0, 0.0, 0.0, 1.5, 0.5]).1, vec![0, 1, 4]);
+        // a gain of two edges does not pay for a penalty of three
+        assert_eq!(swap(vec![0.0, 0.0, 0.0, 3.0, 3.0]).0, MoveKind::Idle);
+        // unless the member leaving is penalised as well
+        assert_eq!(swap(vec![0.0, 0.0, 1.0, 3.0, 3.0]).1, vec![0, 1, 4]);
+    }
+
+    #[test]
+    fn swap_delta_handles_adjacent_pairs() {
+        // S = {0,1,2} with path 0-1-2; outsider 3 ~ 0,2 and 4 ~ 1
+        let graph = Graph::from_edge_list(5, &[(0, 1), (1, 2), (0, 3), (2, 3), (1, 4)]);
+        let mut sol = Solution::new(&graph);
+        for v in [0, 1, 2] { sol.add(v); }
+
+        for u in [0, 1, 2] {
+            for v in [3, 4] {
+                let mut t = sol.clone();
+                t.remove(u);
+                t.add(v);
+                let actual = t.edges() as isize - sol.edges() as isize;
+                assert_eq!(swap_delta(&sol, u, v), actual, "swap {u}->{v}");
+            }
+        }
+        // adjacent pair: gain 2, loss 1, but the 0–3 edge leaves with 0
+        assert_eq!(swap_delta(&sol, 0, 3), 0);
+
+        // the chosen swap must not be reported better than it is
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        let mut tabu = DualTabu::new(5, 1, 1);
+        let mut freq = vec![0; 5];
+        let p = Params { gamma_target: 1.0, ..Params::default() };
+        improve_once(&mut sol, &mut tabu, 1.0, &mut freq, &p, &mut rng);
+        assert_eq!(sol.edges(), 2);
+    }
+
+    #[test]
+    fn double_swap_escapes() {
+        // S = {0,1,2,3} holds the disjoint edges 0-2, 1-3; outsiders 4-5
+        // form an edge.  Every single swap loses an edge, but swapping
+        // 0→4 then 2→5 keeps m(S) = 2.
+        let graph = Graph::from_edge_list(6, &[(0, 2), (1, 3), (4, 5)]);
+        let mut sol = Solution::new(&graph);
+        for v in 0..4 { sol.add(v); }
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        let mut freq = vec![0; 6];
+
+        let p = Params { gamma_target: 1.0, ..Params::default() };
+        let mut plain = sol.clone();
+        let mut tabu = DualTabu::new(6, 1, 1);
+        assert_eq!(improve_once(&mut plain, &mut tabu, 1.0, &mut freq, &p, &mut rng), MoveKind::Idle);
+
+        let p = Params { double_swap: true, ..p };
+        let mut tabu = DualTabu::new(6, 1, 1);
+        let (delta, _) = best_double_swap(&mut sol, &tabu).expect("non-deteriorating pair");
+        assert_eq!((delta, sol.edges()), (0, 2)); // sol restored
+        let kind = improve_once(&mut sol, &mut tabu, 1.0, &mut freq, &p, &mut rng);
+        assert_eq!(kind, MoveKind::DoubleSwap);
+        assert_eq!(sol.edges(), 2);
+        assert!(sol.bitset()[4] && sol.bitset()[5]);
+    }
+
+    #[test]
+    fn scan_modes() {
+        let auto = Params { scan: Scan::Auto, ..Params::default() };
+        let small = ScanSelector::new(AUTO_FULL_MAX_N, &auto);
+        assert_eq!((small.mode(), small.tracks_frontier()), (Scan::Full, false));
+        let mut large = ScanSelector::new(AUTO_FULL_MAX_N + 1, &auto);
+        assert_eq!((large.mode(), large.tracks_frontier()), (Scan::Frontier, true));
+
+        // a hub's frontier is too large to scan; a leaf's is not
+        let n = AUTO_SCAN_MAX + 2;
+        let star: Vec<(usize, usize)> = (1..n).map(|v| (0, v)).collect();
+        let star = Graph::from_edge_list(n, &star);
+        let mut sol = Solution::new(&star);
+        sol.add(0);
+        sol.track_frontier();
+        for _ in 0..AUTO_WINDOW {
+            large.observe(&sol);
+        }
+        assert_eq!(large.mode(), Scan::Sampled(AUTO_SAMPLE));
+        sol.remove(0);
+        sol.add(1);
+        for _ in 0..AUTO_WINDOW {
+            large.observe(&sol);
+        }
+        assert_eq!(large.mode(), Scan::Frontier);
+
+        // star around 0 with S = {1, 2}: a sample covering the frontier
+        // {0} finds the same swap as a full scan
+        let graph = Graph::from_edge_list(6, &[(0, 1), (0, 2), (0, 3), (0, 4), (0, 5)]);
+        let mut sol = Solution::new(&graph);
+        sol.add(1);
+        sol.add(2);
+        sol.track_frontier();
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        assert_eq!(sample_outsiders(&sol, 4, &mut rng), vec![0]);
+
+        let p = Params { gamma_target: 1.0, ..Params::default() };
+        let mut tabu = DualTabu::new(6, 1, 1);
+        let mut freq = vec![0; 6];
+        let kind = improve_once_with(&mut sol, &mut tabu, 0.0, &mut freq, &p, Scan::Sampled(4), &mut rng);
+        assert_eq!((kind, sol.edges()), (MoveKind::Swap, 1));
+        assert!(sol.bitset()[0]);
+    }
 }
@@ -0,0 +1,399 @@
+// src/npy.rs
+//! NumPy `.npy` / `.npz` import and export.
+//!
+//! Two layouts are understood:
+//! • dense adjacency – an n×n array (bool, int or float); any non-zero
+//!   off-diagonal entry is an edge (the matrix is symmetrised);
+//! • edge array – an m×2 integer array of 0-based `(u, v)` pairs.
+//!
+//! `.npz` archives hold either `adjacency.npy` or `edges.npy` (plus an
+//! optional scalar `n.npy`, needed for trailing isolated vertices), as
+//! written by `np.savez(path, edges=E, n=n)`.
+
+use crate::graph::{Graph, Layout};
+use std::fs::File;
+use std::io::{self, Read, Seek, Write};
+use std::path::Path;
+
+const MAGIC: &[u8; 6] = b"\x93NUMPY";
+
+fn invalid(msg: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg.into())
+}
+
+/*───────────────────────── reading ─────────────────────────*/
+
+/// A decoded array: C-order values (as f64), shape and dtype (kind `b`,
+/// `u`, `i` or `f`, and width in bytes).
+struct NpyArray {
+    shape: Vec<usize>,
+    data:  Vec<f64>,
+    dtype: (u8, usize),
+}
+
+/// Exactly `len` bytes from `r`, without trusting `len` for the
+/// allocation up front.
+fn read_len<R: Read>(r: R, len: usize) -> io::Result<Vec<u8>> {
+    let mut buf = Vec::new();
+    r.take(len as u64).read_to_end(&mut buf)?;
+    if buf.len() < len {
+        return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "npy data ends early"));
+    }
+    Ok(buf)
+}
+
+/// Value of `'key': <value>` in the header dict (up to the next `,` or `}`
+/// outside parentheses).
+fn header_field<'h>(header: &'h str, key: &str) -> io::Result<&'h str> {
+    let pat = format!("'{key}':");
+    let start = header.find(&pat).ok_or_else(|| invalid(format!("npy header lacks '{key}'")))?
+        + pat.len();
+    let rest = &header[start..];
+    let mut depth = 0i32;
+    for (i, c) in rest.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' | '}' if depth == 0 => return Ok(rest[..i].trim()),
+            _ => {}
+        }
+    }
+    Ok(rest.trim())
+}
+
+fn read_array<R: Read>(mut r: R) -> io::Result<NpyArray> {
+    let mut magic = [0u8; 8];
+    r.read_exact(&mut magic)?;
+    if &magic[..6] != MAGIC {
+        return Err(invalid("not a .npy file"));
+    }
+    let header_len = if magic[6] == 1 {
+        let mut b = [0u8; 2];
+        r.read_exact(&mut b)?;
+        u16::from_le_bytes(b) as usize
+    } else {
+        let mut b = [0u8; 4];
+        r.read_exact(&mut b)?;
+        u32::from_le_bytes(b) as usize
+    };
+    let header = read_len(&mut r, header_len)?;
+    let header = String::from_utf8_lossy(&header);
+
+    let descr = header_field(&header, "descr")?.trim_matches(|c| c == '\'' || c == '"');
+    let fortran = header_field(&header, "fortran_order")? == "True";
+    let shape: Vec<usize> = header_field(&header, "shape")?
+        .trim_matches(|c| c == '(' || c == ')')
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| s.parse().map_err(|_| invalid(format!("bad npy shape entry '{s}'"))))
+        .collect::<io::Result<_>>()?;
+
+    let unsupported = || invalid(format!("unsupported dtype '{descr}'"));
+    let (big, kind, width) = match descr.as_bytes() {
+        [endian @ (b'<' | b'>' | b'|' | b'='), kind, width @ ..] => {
+            let width: usize = std::str::from_utf8(width).ok().and_then(|w| w.parse().ok()).ok_or_else(unsupported)?;
+            (*endian == b'>', *kind, width)
+        }
+        _ => return Err(unsupported()),
+    };
+    match (kind, width) {
+        (b'b', 1) | (b'u' | b'i', 1..=8) | (b'f', 4 | 8) => {}
+        _ => return Err(unsupported()),
+    }
+    let count = shape.iter().try_fold(1usize, |acc, &d| acc.checked_mul(d));
+    let len = count.and_then(|c| c.checked_mul(width)).ok_or_else(|| invalid("npy shape too large"))?;
+    let count = len / width;
+
+    let raw = read_len(&mut r, len)?;
+
+    let mut data = Vec::with_capacity(count);
+    for chunk in raw.chunks_exact(width) {
+        let mut b = [0u8; 8];
+        if big {
+            for (i, &x) in chunk.iter().rev().enumerate() { b[i] = x; }
+        } else {
+            b[..width].copy_from_slice(chunk);
+        }
+        let v = match kind {
+            b'b' | b'u' => u64::from_le_bytes(b) as f64,
+            b'i' => {
+                // sign-extend from `width` bytes
+                let shift = 64 - 8 * width as u32;
+                ((i64::from_le_bytes(b) << shift) >> shift) as f64
+            }
+            _ if width == 4 => f32::from_le_bytes([b[0], b[1], b[2], b[3]]) as f64,
+            _ => f64::from_le_bytes(b),
+        };
+        data.push(v);
+    }
+
+    if fortran && shape.len() == 2 {
+        // transpose to C order
+        let (rows, cols) = (shape[0], shape[1]);
+        let mut c = vec![0.0; count];
+        for i in 0..rows {
+            for j in 0..cols {
+                c[i * cols + j] = data[j * rows + i];
+            }
+        }
+        data = c;
+    }
+    Ok(NpyArray { shape, data, dtype: (kind, width) })
+}
+
+fn adjacency_from(arr: &NpyArray) -> io::Result<Graph> {
+    if arr.shape.len() != 2 || arr.shape[0] != arr.shape[1] {
+        return Err(invalid(format!("adjacency must be square, got shape {:?}", arr.shape)));
+    }
+    let n = arr.shape[0];
+    let mut g = Graph::try_with_layout(n, Layout::Rows)?;
+    for i in 0..n {
+        for j in 0..n {
+            if i != j && arr.data[i * n + j] != 0.0 {
+                g.add_edge(i, j);
+            }
+        }
+    }
+    Ok(g)
+}
+
+fn edges_from(arr: &NpyArray, n: Option<usize>) -> io::Result<Graph> {
+    if arr.shape.len() != 2 || arr.shape[1] != 2 {
+        return Err(invalid(format!("edge array must be m×2, got shape {:?}", arr.shape)));
+    }
+    let mut edges = Vec::with_capacity(arr.shape[0]);
+    for pair in arr.data.chunks_exact(2) {
+        let (u, v) = (pair[0], pair[1]);
+        if u < 0.0 || v < 0.0 || u.fract() != 0.0 || v.fract() != 0.0 {
+            return Err(invalid(format!("invalid vertex pair ({u}, {v})")));
+        }
+        let (u, v) = (u as usize, v as usize);
+        if u != v {
+            edges.push((u, v));
+        }
+    }
+    let needed = edges
+        .iter()
+        .map(|&(u, v)| u.max(v).checked_add(1).ok_or_else(|| invalid(format!("vertex {u} or {v} too large"))))
+        .try_fold(0, |acc, x| x.map(|x| acc.max(x)))?;
+    let n = match n {
+        Some(n) if n < needed => {
+            return Err(invalid(format!("vertex {} out of range for n = {n}", needed - 1)));
+        }
+        Some(n) => n,
+        None => needed,
+    };
+    let mut g = Graph::try_with_layout(n, Layout::Rows)?;
+    for (u, v) in edges {
+        g.add_edge(u, v);
+    }
+    Ok(g)
+}
+
+/// Graph from a C-order n×n 0/1 matrix, read row by row (any non-zero
+/// off-diagonal byte is an edge; the matrix is symmetrised).
+pub fn graph_from_dense(n: usize, data: &[u8]) -> io::Result<Graph> {
+    if data.len() != n * n {
+        return Err(invalid(format!("dense adjacency needs {} bytes, got {}", n * n, data.len())));
+    }
+    let mut g = Graph::try_with_layout(n, Layout::Rows)?;
+    for (i, row) in data.chunks_exact(n.max(1)).enumerate() {
+        for (j, &x) in row.iter().enumerate() {
+            if i != j && x != 0 {
+                g.add_edge(i, j);
+            }
+        }
+    }
+    Ok(g)
+}
+
+/// Read a dense n×n adjacency matrix.
+pub fn read_adjacency_npy<R: Read>(r: R) -> io::Result<Graph> {
+    adjacency_from(&read_array(r)?)
+}
+
+/// Read an m×2 edge array; `n` defaults to the largest index + 1.
+pub fn read_edges_npy<R: Read>(r: R, n: Option<usize>) -> io::Result<Graph> {
+    edges_from(&read_array(r)?, n)
+}
+
+/// Read an `.npz` archive holding `adjacency` or `edges` (+ optional `n`).
+pub fn read_npz<R: Read + Seek>(r: R) -> io::Result<Graph> {
+    let mut zip = zip::ZipArchive::new(r).map_err(|e| invalid(e.to_string()))?;
+    if let Ok(f) = zip.by_name("adjacency.npy") {
+        return adjacency_from(&read_array(f)?);
+    }
+    let n = match zip.by_name("n.npy") {
+        Ok(f) => {
+            let arr = read_array(f)?;
+            let n = *arr.data.first().ok_or_else(|| invalid("empty 'n' array"))?;
+            if n < 0.0 || n.fract() != 0.0 {
+                return Err(invalid(format!("invalid vertex count {n}")));
+            }
+            Some(n as usize)
+        }
+        Err(_) => None,
+    };
+    let edges = zip.by_name("edges.npy")
+        .map_err(|_| invalid("npz holds neither 'adjacency' nor 'edges'"))?;
+    edges_from(&read_array(edges)?, n)
+}
+
+/*───────────────────────── writing ─────────────────────────*/
+
+fn write_header<W: Write>(w: &mut W, descr: &str, shape: &[usize]) -> io::Result<()> {
+    let shape = match shape {
+        [] => "()".to_string(),
+        [a] => format!("({a},)"),
+        _ => format!("({})", shape.iter().map(|s| s.to_string()).collect::<Vec<_>>().join(", ")),
+    };
+    let mut header = format!("{{'descr': '{descr}', 'fortran_order': False, 'shape': {shape}, }}");
+    // magic(6) + version(2) + len(2) + header + '\n' must be a multiple of 64
+    let total = 10 + header.len() + 1;
+    header.push_str(&" ".repeat((64 - total % 64) % 64));
+    header.push('\n');
+    w.write_all(MAGIC)?;
+    w.write_all(&[1, 0])?;
+    w.write_all(&(header.len() as u16).to_le_bytes())?;
+    w.write_all(header.as_bytes())
+}
+
+/// Write the dense n×n adjacency as a boolean array.
+pub fn write_adjacency_npy<W: Write>(graph: &Graph, mut w: W) -> io::Result<()> {
+    let n = graph.n();
+    write_header(&mut w, "|b1", &[n, n])?;
+    let mut row = vec![0u8; n];
+    for v in 0..n {
+        row.fill(0);
+        for j in graph.neighbors(v) {
+            row[j] = 1;
+        }
+        w.write_all(&row)?;
+    }
+    Ok(())
+}
+
+/// Write the edge list (u < v, 0-based) as an m×2 int64 array.
+pub fn write_edges_npy<W: Write>(graph: &Graph, mut w: W) -> io::Result<()> {
+    let edges = graph.edge_list();
+    write_header(&mut w, "<i8", &[edges.len(), 2])?;
+    for (u, v) in edges {
+        w.write_all(&(u as i64).to_le_bytes())?;
+        w.write_all(&(v as i64).to_le_bytes())?;
+    }
+    Ok(())
+}
+
+/// Write an `.npz` archive with `edges` and `n`, loadable by `np.load`.
+pub fn write_npz<W: Write + Seek>(graph: &Graph, w: W) -> io::Result<()> {
+    let mut zip = zip::ZipWriter::new(w);
+    let opts = zip::write::SimpleFileOptions::default()
+        .compression_method(zip::CompressionMethod::Deflated);
+    zip.start_file("edges.npy", opts).map_err(|e| invalid(e.to_string()))?;
+    write_edges_npy(graph, &mut zip)?;
+    zip.start_file("n.npy", opts).map_err(|e| invalid(e.to_string()))?;
+    write_header(&mut zip, "<i8", &[])?;
+    zip.write_all(&(graph.n() as i64).to_le_bytes())?;
+    zip.finish().map_err(|e| invalid(e.to_string()))?;
+    Ok(())
+}
+
+/*───────────────────────── path helpers ─────────────────────────*/
+
+impl Graph {
+    /// Load `.npz` (by name) or `.npy` (square → adjacency, m×2 → edges;
+    /// a 2×2 array is an adjacency if bool, uint8 or float, else edges).
+    pub fn load_numpy<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let path = path.as_ref();
+        let file = File::open(path)?;
+        if path.extension().is_some_and(|e| e.eq_ignore_ascii_case("npz")) {
+            return read_npz(file);
+        }
+        let arr = read_array(io::BufReader::new(file))?;
+        match arr.shape.as_slice() {
+            [2, 2] if matches!(arr.dtype, (b'b' | b'f', _) | (b'u', 1)) => adjacency_from(&arr),
+            [a, b] if a == b && *b != 2 => adjacency_from(&arr),
+            [_, 2] => edges_from(&arr, None),
+            _ => adjacency_from(&arr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn roundtrip_npy_and_npz() {
+        let g = Graph::from_edge_list(5, &[(0, 1), (1, 2), (0, 2), (2, 3)]);
+
+        let mut buf = Vec::new();
+        write_adjacency_npy(&g, &mut buf).unwrap();
+        assert_eq!(buf[8] as usize + 10 + 25, buf.len()); // header + 5×5 bytes
+        let a = read_adjacency_npy(Cursor::new(&buf)).unwrap();
+        assert_eq!(a.edge_list(), g.edge_list());
+
+        let mut buf = Vec::new();
+        write_edges_npy(&g, &mut buf).unwrap();
+        let e = read_edges_npy(Cursor::new(&buf), None).unwrap();
+        assert_eq!(e.n(), 4); // isolated vertex 4 lost without `n`
+        assert_eq!(e.edge_list(), g.edge_list());
+
+        let mut zip = Cursor::new(Vec::new());
+        write_npz(&g, &mut zip).unwrap();
+        zip.set_position(0);
+        let z = read_npz(zip).unwrap();
+        assert_eq!(z.n(), 5);
+        assert_eq!(z.edge_list(), g.edge_list());
+    }
+
+    #[test]
+    fn dense_bytes() {
+        // upper triangle only: still symmetrised
+        let d = graph_from_dense(3, &[0, 1, 1, 0, 0, 0, 0, 5, 0]).unwrap();
+        assert_eq!(d.edge_list(), vec![(0, 1), (0, 2), (1, 2)]);
+        assert_eq!(graph_from_dense(0, &[]).unwrap().n(), 0);
+        assert!(graph_from_dense(2, &[0, 1, 1]).is_err());
+    }
+
+    /// A .npy file with the given header dict and body.
+    fn npy(dict: &str, body: &[u8]) -> Vec<u8> {
+        let mut buf = MAGIC.to_vec();
+        buf.extend([1, 0]);
+        buf.extend((dict.len() as u16).to_le_bytes());
+        buf.extend(dict.as_bytes());
+        buf.extend(body);
+        buf
+    }
+
+    #[test]
+    fn rejects_bad_headers_and_values() {
+        for descr in ["<c16", "", "<", "<i0", "<i9", "<f2", "|b2"] {
+            let dict = format!("{{'descr': '{descr}', 'fortran_order': False, 'shape': (1, 2), }}");
+            let err = read_edges_npy(Cursor::new(npy(&dict, &[0; 32])), None).unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidData, "{descr}");
+        }
+        let huge = "{'descr': '<i8', 'fortran_order': False, 'shape': (4611686018427387904, 4), }";
+        assert!(read_edges_npy(Cursor::new(npy(huge, &[])), None).is_err());
+        let short = "{'descr': '<i8', 'fortran_order': False, 'shape': (1000000000, 2), }";
+        assert!(read_edges_npy(Cursor::new(npy(short, &[0; 16])), None).is_err());
+
+        let float = "{'descr': '<f8', 'fortran_order': False, 'shape': (1, 2), }";
+        let body: Vec<u8> = [0.0f64, 1e300].iter().flat_map(|x| x.to_le_bytes()).collect();
+        assert!(read_edges_npy(Cursor::new(npy(float, &body)), None).is_err());
+
+        // a 2×2 boolean matrix is an adjacency, even without edges
+        let path = std::env::temp_dir().join(format!("tsqc-npy-{}.npy", std::process::id()));
+        let bool2 = "{'descr': '|b1', 'fortran_order': False, 'shape': (2, 2), }";
+        std::fs::write(&path, npy(bool2, &[0; 4])).unwrap();
+        let g = Graph::load_numpy(&path).unwrap();
+        assert_eq!((g.n(), g.m()), (2, 0));
+        let int2 = "{'descr': '<i8', 'fortran_order': False, 'shape': (2, 2), }";
+        let body: Vec<u8> = [0i64, 3, 1, 2].iter().flat_map(|x| x.to_le_bytes()).collect();
+        std::fs::write(&path, npy(int2, &body)).unwrap();
+        assert_eq!(Graph::load_numpy(&path).unwrap().edge_list(), vec![(0, 3), (1, 2)]);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,464 @@
+// src/observer.rs
+//! Opt-in observation of the search (per-iteration and per-restart records).
+//!
+//! The solvers accept any [`Observer`]; the default [`NoObserver`] reports
+//! `wants_iters() == false`, so no record is ever built and the hot loop
+//! pays a single predictable branch per move.  Restart records are rare
+//! and always delivered.
+//!
+//! [`TraceWriter`] is the stock sink: one line per record, written as CSV
+//! or JSONL, intended for convergence plots.  A writer carries one kind of
+//! record; combine two writers with a tuple `(a, b)`, and make either
+//! optional with `Option<_>`.
+//!
+//! Full state snapshots ([`Snapshot`]) are likewise opt-in, per move,
+//! through [`Observer::wants_snapshot`].
+//!
+//! [`LogObserver`] forwards incumbents, restarts and the stop reason as
+//! structured `tracing` events (target `tsqc`) to whatever subscriber the
+//! application installed.
+
+use crate::neighbour::MoveKind;
+use crate::snapshot::Snapshot;
+use crate::solution::Solution;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+/// Snapshot of the search after one move (or perturbation).
+#[derive(Clone, Debug)]
+pub struct IterRecord {
+    /// Target size of the fixed-k search emitting the record.
+    pub k:       usize,
+    /// Moves performed so far in this fixed-k search (all restarts).
+    pub iter:    usize,
+    /// |S| after the move.
+    pub size:    usize,
+    /// m(S) after the move.
+    pub edges:   usize,
+    /// m(S) after the move minus m(S) before it.
+    pub delta:   isize,
+    /// ρ(S) after the move.
+    pub density: f64,
+    /// What happened in this step.
+    pub kind:    MoveKind,
+    /// Current tabu tenures (Tu, Tv).
+    pub tu:      usize,
+    pub tv:      usize,
+    /// Number of vertices currently tabu for re-addition / removal.
+    pub tabu_u:  usize,
+    pub tabu_v:  usize,
+}
+
+/// Summary of one restart of the fixed-k search, emitted when it ends.
+#[derive(Clone, Debug)]
+pub struct RestartRecord {
+    /// Target size of the fixed-k search.
+    pub k:             usize,
+    /// 0-based restart index within this fixed-k search.
+    pub restart:       usize,
+    /// Seed vertex the initial solution was grown from.
+    pub seed_vertex:   usize,
+    /// ρ of the initial solution.
+    pub start_density: f64,
+    /// Best ρ reached during this restart.
+    pub best_density:  f64,
+    /// Moves performed during this restart.
+    pub iterations:    usize,
+    /// Perturbations triggered during this restart.
+    pub perturbations: usize,
+}
+
+/// Why a solver returned, delivered once through [`Observer::on_finish`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StopReason {
+    /// Fixed-k: a γ-feasible k-set was found.
+    Feasible,
+    /// Fixed-k (or the clique search): the move cap `p.max_iter` was
+    /// reached.
+    MaxIter,
+    /// Fixed-k: ⌈γ·C(k,2)⌉ exceeds C(k,2), no k-set can be feasible.
+    Infeasible,
+    /// Max-k: the degree bound ruled out the next size (the clique
+    /// search: the colouring or core bound proved the best maximum).
+    Bound,
+    /// Max-k: fixed-k searches failed past `p.maxk_lookahead`.
+    Failure,
+    /// Max-k: every size up to n was tried.
+    Exhausted,
+    /// [`Observer::should_stop`] asked the solver to return early.
+    Interrupted,
+}
+
+impl StopReason {
+    /// Lower-case name, as used in logs and result tables.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            StopReason::Feasible   => "feasible",
+            StopReason::MaxIter    => "max_iter",
+            StopReason::Infeasible => "infeasible",
+            StopReason::Bound      => "bound",
+            StopReason::Failure    => "failure",
+            StopReason::Exhausted  => "exhausted",
+            StopReason::Interrupted => "interrupted",
+        }
+    }
+}
+
+/// Receives search events. All methods default to no-ops.
+pub trait Observer {
+    /// Return `true` to receive [`Observer::on_iter`] calls.
+    /// Checked before a record is assembled, so disabled tracing is free.
+    fn wants_iters(&self) -> bool {
+        false
+    }
+
+    /// Called after every intensification move and every perturbation.
+    fn on_iter(&mut self, _rec: &IterRecord) {}
+
+    /// Called once at the end of every restart.
+    fn on_restart(&mut self, _rec: &RestartRecord) {}
+
+    /// Called after [`Observer::on_restart`] with the best set of that
+    /// restart.
+    fn on_run_best(&mut self, _sol: &Solution<'_>) {}
+
+    /// Called whenever the solver's incumbent (the solution it would
+    /// return if stopped now) improves.
+    fn on_incumbent(&mut self, _sol: &Solution<'_>) {}
+
+    /// Polled once per move; returning `true` makes the solver return its
+    /// best-so-far solution (anytime behaviour, e.g. a deadline).
+    fn should_stop(&mut self) -> bool {
+        false
+    }
+
+    /// Called once when the solver returns.
+    fn on_finish(&mut self, _reason: StopReason) {}
+
+    /// Return `true` to receive a [`Snapshot`] of the search state after
+    /// move `iter` (fixed-k searches only).  Polled once per move.
+    fn wants_snapshot(&mut self, _iter: usize) -> bool {
+        false
+    }
+
+    /// Called with the state requested by [`Observer::wants_snapshot`].
+    fn on_snapshot(&mut self, _snap: &Snapshot) {}
+}
+
+impl<A: Observer, B: Observer> Observer for (A, B) {
+    fn wants_iters(&self) -> bool {
+        self.0.wants_iters() || self.1.wants_iters()
+    }
+
+    fn on_iter(&mut self, rec: &IterRecord) {
+        if self.0.wants_iters() { self.0.on_iter(rec); }
+        if self.1.wants_iters() { self.1.on_iter(rec); }
+    }
+
+    fn on_restart(&mut self, rec: &RestartRecord) {
+        self.0.on_restart(rec);
+        self.1.on_restart(rec);
+    }
+
+    fn on_run_best(&mut self, sol: &Solution<'_>) {
+        self.0.on_run_best(sol);
+        self.1.on_run_best(sol);
+    }
+
+    fn on_incumbent(&mut self, sol: &Solution<'_>) {
+        self.0.on_incumbent(sol);
+        self.1.on_incumbent(sol);
+    }
+
+    fn should_stop(&mut self) -> bool {
+        self.0.should_stop() || self.1.should_stop()
+    }
+
+    fn on_finish(&mut self, reason: StopReason) {
+        self.0.on_finish(reason);
+        self.1.on_finish(reason);
+    }
+
+    fn wants_snapshot(&mut self, iter: usize) -> bool {
+        // both are polled: either may count moves
+        let (a, b) = (self.0.wants_snapshot(iter), self.1.wants_snapshot(iter));
+        a || b
+    }
+
+    fn on_snapshot(&mut self, snap: &Snapshot) {
+        self.0.on_snapshot(snap);
+        self.1.on_snapshot(snap);
+    }
+}
+
+impl<O: Observer> Observer for Option<O> {
+    fn wants_iters(&self) -> bool {
+        self.as_ref().is_some_and(|o| o.wants_iters())
+    }
+
+    fn on_iter(&mut self, rec: &IterRecord) {
+        if let Some(o) = self { o.on_iter(rec); }
+    }
+
+    fn on_restart(&mut self, rec: &RestartRecord) {
+        if let Some(o) = self { o.on_restart(rec); }
+    }
+
+    fn on_run_best(&mut self, sol: &Solution<'_>) {
+        if let Some(o) = self { o.on_run_best(sol); }
+    }
+
+    fn on_incumbent(&mut self, sol: &Solution<'_>) {
+        if let Some(o) = self { o.on_incumbent(sol); }
+    }
+
+    fn should_stop(&mut self) -> bool {
+        self.as_mut().is_some_and(|o| o.should_stop())
+    }
+
+    fn on_finish(&mut self, reason: StopReason) {
+        if let Some(o) = self { o.on_finish(reason); }
+    }
+
+    fn wants_snapshot(&mut self, iter: usize) -> bool {
+        self.as_mut().is_some_and(|o| o.wants_snapshot(iter))
+    }
+
+    fn on_snapshot(&mut self, snap: &Snapshot) {
+        if let Some(o) = self { o.on_snapshot(snap); }
+    }
+}
+
+/// Observer that ignores everything.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoObserver;
+
+impl Observer for NoObserver {}
+
+/// Emits search events as `tracing` records: incumbents and the stop
+/// reason at INFO, restarts at DEBUG.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LogObserver;
+
+impl Observer for LogObserver {
+    fn on_restart(&mut self, rec: &RestartRecord) {
+        tracing::debug!(
+            target: "tsqc",
+            k = rec.k,
+            restart = rec.restart,
+            seed_vertex = rec.seed_vertex,
+            start_density = rec.start_density,
+            best_density = rec.best_density,
+            iterations = rec.iterations,
+            perturbations = rec.perturbations,
+            "restart"
+        );
+    }
+
+    fn on_incumbent(&mut self, sol: &Solution<'_>) {
+        tracing::info!(
+            target: "tsqc",
+            size = sol.size(),
+            edges = sol.edges(),
+            density = sol.density(),
+            "improvement"
+        );
+    }
+
+    fn on_finish(&mut self, reason: StopReason) {
+        tracing::info!(target: "tsqc", reason = reason.as_str(), "finished");
+    }
+}
+
+/// Output format of a [`TraceWriter`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TraceFormat {
+    Csv,
+    Jsonl,
+}
+
+impl TraceFormat {
+    /// `.csv` → CSV, anything else → JSONL.
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("csv") => TraceFormat::Csv,
+            _ => TraceFormat::Jsonl,
+        }
+    }
+}
+
+/// Which records a [`TraceWriter`] writes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TraceEvents {
+    Iters,
+    Restarts,
+}
+
+/// Writes every [`IterRecord`] (or every [`RestartRecord`]) as one CSV row
+/// or JSON line.
+///
+/// I/O errors do not abort the search: the first error is kept and
+/// returned by [`TraceWriter::finish`].
+pub struct TraceWriter<W: Write> {
+    out:            W,
+    format:         TraceFormat,
+    events:         TraceEvents,
+    header_written: bool,
+    error:          Option<io::Error>,
+}
+
+impl TraceWriter<BufWriter<File>> {
+    /// Create (truncate) `path` for iteration records; the format follows
+    /// the file extension.
+    pub fn create<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::create_for(path, TraceEvents::Iters)
+    }
+
+    /// Create (truncate) `path` for restart records.
+    pub fn create_restarts<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::create_for(path, TraceEvents::Restarts)
+    }
+
+    fn create_for<P: AsRef<Path>>(path: P, events: TraceEvents) -> io::Result<Self> {
+        let path = path.as_ref();
+        let file = File::create(path)?;
+        Ok(Self::new(BufWriter::new(file), TraceFormat::from_path(path)).events(events))
+    }
+}
+
+impl<W: Write> TraceWriter<W> {
+    /// Iteration-record writer over `out`.
+    pub fn new(out: W, format: TraceFormat) -> Self {
+        Self { out, format, events: TraceEvents::Iters, header_written: false, error: None }
+    }
+
+    /// Select which records are written.
+    pub fn events(mut self, events: TraceEvents) -> Self {
+        self.events = events;
+        self
+    }
+
+    /// Flush and hand back the writer, or the first I/O error seen.
+    pub fn finish(mut self) -> io::Result<W> {
+        if let Some(e) = self.error.take() {
+            return Err(e);
+        }
+        self.out.flush()?;
+        Ok(self.out)
+    }
+
+    fn write_iter(&mut self, r: &IterRecord) -> io::Result<()> {
+        match self.format {
+            TraceFormat::Csv => {
+                if !self.header_written {
+                    writeln!(self.out, "k,iter,size,edges,density,move,tu,tv,tabu_u,tabu_v")?;
+                    self.header_written = true;
+                }
+                writeln!(
+                    self.out,
+                    "{},{},{},{},{:.6},{},{},{},{},{}",
+                    r.k, r.iter, r.size, r.edges, r.density, r.kind.as_str(),
+                    r.tu, r.tv, r.tabu_u, r.tabu_v
+                )
+            }
+            TraceFormat::Jsonl => writeln!(
+                self.out,
+                "{{\"k\":{},\"iter\":{},\"size\":{},\"edges\":{},\"density\":{:.6},\
+                 \"move\":\"{}\",\"tu\":{},\"tv\":{},\"tabu_u\":{},\"tabu_v\":{}}}",
+                r.k, r.iter, r.size, r.edges, r.density, r.kind.as_str(),
+                r.tu, r.tv, r.tabu_u, r.tabu_v
+            ),
+        }
+    }
+
+    fn write_restart(&mut self, r: &RestartRecord) -> io::Result<()> {
+        match self.format {
+            TraceFormat::Csv => {
+                if !self.header_written {
+                    writeln!(
+                        self.out,
+                        "k,restart,seed_vertex,start_density,best_density,iterations,perturbations"
+                    )?;
+                    self.header_written = true;
+                }
+                writeln!(
+                    self.out,
+                    "{},{},{},{:.6},{:.6},{},{}",
+                    r.k, r.restart, r.seed_vertex, r.start_density, r.best_density,
+                    r.iterations, r.perturbations
+                )
+            }
+            TraceFormat::Jsonl => writeln!(
+                self.out,
+                "{{\"k\":{},\"restart\":{},\"seed_vertex\":{},\"start_density\":{:.6},\
+                 \"best_density\":{:.6},\"iterations\":{},\"perturbations\":{}}}",
+                r.k, r.restart, r.seed_vertex, r.start_density, r.best_density,
+                r.iterations, r.perturbations
+            ),
+        }
+    }
+}
+
+impl<W: Write> Observer for TraceWriter<W> {
+    fn wants_iters(&self) -> bool {
+        self.events == TraceEvents::Iters && self.error.is_none()
+    }
+
+    fn on_iter(&mut self, rec: &IterRecord) {
+        if let Err(e) = self.write_iter(rec) {
+            self.error = Some(e);
+        }
+    }
+
+    fn on_restart(&mut self, rec: &RestartRecord) {
+        if self.events != TraceEvents::Restarts || self.error.is_some() {
+            return;
+        }
+        if let Err(e) = self.write_restart(rec) {
+            self.error = Some(e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record() -> IterRecord {
+        IterRecord {
+            k: 4, iter: 7, size: 4, edges: 5, delta: 1, density: 5.0 / 6.0,
+            kind: MoveKind::Swap, tu: 3, tv: 2, tabu_u: 1, tabu_v: 1,
+        }
+    }
+
+    #[test]
+    fn csv_and_jsonl_lines() {
+        let mut csv = TraceWriter::new(Vec::new(), TraceFormat::Csv);
+        csv.on_iter(&record());
+        csv.on_iter(&record());
+        let text = String::from_utf8(csv.finish().unwrap()).unwrap();
+        let lines: Vec<_> = text.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("k,iter,size"));
+        assert_eq!(lines[1], "4,7,4,5,0.833333,swap,3,2,1,1");
+
+        let mut js = TraceWriter::new(Vec::new(), TraceFormat::Jsonl);
+        js.on_iter(&record());
+        let text = String::from_utf8(js.finish().unwrap()).unwrap();
+        assert!(text.starts_with("{\"k\":4,\"iter\":7"));
+        assert!(text.contains("\"move\":\"swap\""));
+    }
+
+    #[test]
+    fn restart_writer_ignores_iters() {
+        let mut w = TraceWriter::new(Vec::new(), TraceFormat::Csv).events(TraceEvents::Restarts);
+        assert!(!w.wants_iters());
+        w.on_restart(&RestartRecord {
+            k: 4, restart: 0, seed_vertex: 2, start_density: 0.5,
+            best_density: 1.0, iterations: 10, perturbations: 1,
+        });
+        let text = String::from_utf8(w.finish().unwrap()).unwrap();
+        assert_eq!(text.lines().nth(1), Some("4,0,2,0.500000,1.000000,10,1"));
+    }
+}
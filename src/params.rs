@@ -11,6 +11,92 @@
 //!
 //! `gamma_target` must be set by the caller to the desired density threshold.
 
+use crate::{construct::Constructor, rng::RngKind, solution::Solution, tabu::TenureSchedule};
+use bitvec::slice::BitSlice;
+use std::{io, time::Duration};
+
+/// How the stagnation threshold L is chosen.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum StagnationRule {
+    /// L = `stagnation_iter` (§ 3.1).
+    #[default]
+    Fixed,
+    /// L = ⌈factor·√(n·k)⌉, so L grows with the instance.
+    InstanceSize(f64),
+    /// L = ⌈factor · mean moves between run-best improvements⌉ observed so
+    /// far in this solve (`stagnation_iter` until the first improvement).
+    ImprovementRate(f64),
+}
+
+/// Which outsiders the one-swap step scans when building B.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Scan {
+    /// Every outsider: full A×B best improvement (§ 3.4.1).
+    #[default]
+    Full,
+    /// Only the frontier N(S) \ S, tracked incrementally (as with
+    /// `track_frontier`).
+    Frontier,
+    /// A fresh random sample of this many outsiders per step, drawn from
+    /// the frontier when it is tracked.
+    Sampled(usize),
+    /// Full on small graphs; on large ones the frontier, falling back to
+    /// samples while |N(S)| or the measured step latency is too high (see
+    /// `neighbour::ScanSelector`).  Timing makes large runs
+    /// non-reproducible.
+    Auto,
+}
+
+/// Vertex costs and the cap on their sum over S (knapsack constraint).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Budget {
+    /// Cost of every vertex (length n, non-negative).
+    pub costs: Vec<f64>,
+    /// B: largest admissible Σ_{v∈S} cost(v).
+    pub limit: f64,
+}
+
+impl Budget {
+    /// Σ cost(v) over the set bits of `set`.
+    pub fn cost_of(&self, set: &BitSlice) -> f64 {
+        set.iter_ones().map(|v| self.costs[v]).sum()
+    }
+
+    /// The same budget on the subgraph induced by `vertices` (new vertex
+    /// `i` is `vertices[i]`).
+    pub fn restricted(&self, vertices: &[usize]) -> Budget {
+        Budget { costs: vertices.iter().map(|&v| self.costs[v]).collect(), limit: self.limit }
+    }
+
+    /// `Err(InvalidInput)` unless there is one finite, non-negative cost
+    /// per vertex of an `n`-vertex graph.
+    pub fn check(&self, n: usize) -> io::Result<()> {
+        check_per_vertex("costs", &self.costs, n)
+    }
+
+    /// `true` if a set of total cost `cost` is within the limit.
+    #[inline]
+    pub fn allows(&self, cost: f64) -> bool {
+        cost <= self.limit + 1e-9
+    }
+}
+
+/// `Err(InvalidInput)` unless `values` holds one finite, non-negative
+/// entry per vertex.
+fn check_per_vertex(what: &str, values: &[f64], n: usize) -> io::Result<()> {
+    let invalid = |msg: String| Err(io::Error::new(io::ErrorKind::InvalidInput, msg));
+    if values.len() != n {
+        return invalid(format!("{} {what} for {n} vertices", values.len()));
+    }
+    match values.iter().position(|&c| !(c.is_finite() && c >= 0.0)) {
+        Some(v) => invalid(format!("{what}[{v}] = {} is not a finite non-negative number", values[v])),
+        None => Ok(()),
+    }
+}
+
+/// Lower bound on any adaptive stagnation threshold.
+pub const MIN_STAGNATION: usize = 10;
+
 /// All tunable controls for TSQC.
 #[derive(Clone, Debug)]
 pub struct Params {
@@ -33,6 +119,153 @@ pub struct Params {
     /// Itₘₐₓ: hard cap on total TSQ iterations across all restarts
     /// (Section 3.1). Default = 10⁸.
     pub max_iter:         usize,
+
+    /// Wall-clock cap on a whole solve, polled once per move: the solver
+    /// returns its best-so-far set (stop reason `Interrupted`) when it
+    /// expires.  Default = None (only `max_iter` bounds the run).
+    pub time_limit:       Option<Duration>,
+
+    /// Degree percentile (0–100) the first-run seed vertex must reach.
+    /// Default = 0 (any vertex, as in the thesis).
+    pub seed_degree_percentile: f64,
+
+    /// Deterministic part of the adaptive tenures.
+    /// Default = deficit-driven (§3.4.3).
+    pub tenure_schedule: TenureSchedule,
+
+    /// Move-quality tenure scaling s ∈ [0, 1): the swapped u/v get tenures
+    /// ×(1+s) after a deteriorating move and ×(1−s) after an improving one.
+    /// Default = 0 (uniform tenures).
+    pub tenure_delta_scale: f64,
+
+    /// Constructor for the first run of each fixed-k search; restarts
+    /// always use the frequency-guided greedy fill (§ 3.5).
+    /// Default = greedy-random.
+    pub constructor: Constructor,
+
+    /// P: starts built in parallel for the first run (cycling through the
+    /// constructors, beginning with `constructor`).  The densest is used;
+    /// the others seed the next restarts via the elite pool.
+    /// Default = 1 (single start).
+    pub construct_pool: usize,
+
+    /// Sideways (equal-density) swaps per improvement that do not count
+    /// towards L; a set revisited on the plateau always counts.
+    /// Default = 0 (every non-improving swap counts, as in the thesis).
+    pub plateau_budget: usize,
+
+    /// Try a depth-2 double swap when no single swap is admissible,
+    /// before the step is counted as idle.
+    /// Default = false.
+    pub double_swap: bool,
+
+    /// Maintain the frontier N(S) \ S incrementally so outsider scans
+    /// touch only N(S); pays off on sparse graphs with small k.
+    /// Default = false (scans over all of V; internal degrees are tracked
+    /// either way).
+    pub track_frontier: bool,
+
+    /// Outsiders scanned per swap step: all, the frontier, a sample, or
+    /// chosen automatically from the graph size, |N(S)| and step latency.
+    /// Default = [`Scan::Full`].
+    pub scan: Scan,
+
+    /// δ for strategic oscillation: when > 0, the mild shake is replaced by
+    /// a drift to k ± δ (best additions / weakest removals) and back to k.
+    /// Default = 0 (mild perturbation, § 3.4.2).
+    pub oscillation_delta: usize,
+
+    /// Cap on the vertices a heavy perturbation replaces: it swaps
+    /// 1 + (consecutive diversifications without a run-best improvement),
+    /// up to this cap.  Default = 1 (always one vertex, § 3.4.2).
+    pub max_heavy_swaps: usize,
+
+    /// Restarts seed and grow from vertices outside every previous run-best
+    /// set (a coverage bitset) while any remain, instead of re-growing the
+    /// same dense core.  Default = false (frequency-guided seeding, § 3.5).
+    pub complement_restarts: bool,
+
+    /// D: halve the long-term frequency memory every D iterations, so
+    /// restart seeding follows recent history on long runs.
+    /// Default = 0 (no decay; counts reset only when one exceeds k).
+    pub freq_decay_interval: usize,
+
+    /// Check the U1 bound on the run best every this many moves of a run
+    /// (0 = never, e.g. on huge graphs where runs end by stagnation
+    /// anyway).  The bound is recomputed only after the run best changed.
+    /// Default = 1.
+    pub u1_interval: usize,
+
+    /// Rule deriving L from the instance / search progress.
+    /// Default = fixed `stagnation_iter`.
+    pub stagnation_rule: StagnationRule,
+
+    /// Keep the best solution of each restart (up to this many) for
+    /// [`solve_fixed_k_all`](crate::restart::solve_fixed_k_all).
+    /// Default = 0 (only the incumbent is returned).
+    pub restart_bests: usize,
+
+    /// Max-k seeds each k+1 search with the k-solution plus its best
+    /// outsider instead of constructing from scratch.  Default = false.
+    pub warm_start: bool,
+
+    /// Max-k keeps the frequency memory and the last tabu lists across k
+    /// values.  Default = false (fresh memory for every k).
+    pub carry_memory: bool,
+
+    /// Max-k keeps probing up to this many larger k values (still pruned
+    /// by the degree bound) after a fixed-k failure before giving up.
+    /// Default = 0 (stop at the first failure above the best size).
+    pub maxk_lookahead: usize,
+
+    /// Wall-clock slice of each fixed-k search inside max-k; time a search
+    /// leaves unused is added to the next one's slice, so one hard k
+    /// cannot starve the larger sizes.  A search out of time counts as
+    /// failed at that k.  Default = None (each search runs to its
+    /// iteration limits).
+    pub per_k_time_limit: Option<Duration>,
+
+    /// Start vertices for the greedy clique lower bound computed before
+    /// the max-k loop (0 = skip it).  Default = 16.
+    pub clique_starts: usize,
+
+    /// At γ = 1, solve max-k and fixed-k with the dedicated clique search
+    /// of [`crate::clique`] (unless a budget or penalties are set).
+    /// Default = false (the generic quasi-clique search).
+    pub clique_mode: bool,
+
+    /// θ ∈ [0, 1]: on top of density ≥ γ, every member of a feasible set
+    /// needs ⌈θ(k−1)⌉ neighbours inside it, which rules out hub-and-spoke
+    /// sets with nearly isolated members.  Swaps prefer newcomers meeting
+    /// the floor.  Default = 0 (density only).
+    pub min_degree_ratio: f64,
+
+    /// λ in the signed score m⁺(S) − λ·m⁻(S) (see [`crate::signed`]):
+    /// what one internal negative edge costs in positive edges.
+    /// Default = 1.  Unused on unsigned graphs.
+    pub negative_weight: f64,
+
+    /// Vertex costs with a cap B on the total cost of S, enforced by the
+    /// constructors (costly members are swapped for cheaper outsiders),
+    /// by the swaps (no swap may leave S over B unless it lowers the
+    /// cost) and by the feasibility test.  Default = None (no costs).
+    pub budget: Option<Budget>,
+
+    /// Soft per-vertex penalties (length n, non-negative, in edges): a
+    /// swap u→v is scored as its edge gain minus pen(v) − pen(u), so
+    /// penalised vertices (known hubs, bots) enter only when they pay for
+    /// themselves and are the first to leave.  Feasibility is unchanged.
+    /// Default = None.
+    pub penalties: Option<Vec<f64>>,
+
+    /// Generator built by entry points that seed their own (Python,
+    /// CLI, parallel starts).  Default = [`RngKind::ChaCha8`]
+    /// (reproducible); [`RngKind::Xoshiro`] is cheaper per draw.
+    pub rng: RngKind,
+
+    /// Threads for this solve's parallel work (parallel starts).
+    /// Default = 0 (the shared pool, see [`crate::threads`]).
+    pub threads: usize,
 }
 
 impl Default for Params {
@@ -43,8 +276,100 @@ impl Default for Params {
             gamma_target:    0.90,        // example default; override as needed
             stagnation_iter: 1_000,       // L = 1000
             max_iter:        100_000_000, // Itₘₐₓ = 1e8
+            time_limit:      None,
+            seed_degree_percentile: 0.0,  // uniform seed
+            tenure_schedule: TenureSchedule::Deficit,
+            tenure_delta_scale: 0.0,
+            constructor: Constructor::GreedyRandom,
+            construct_pool: 1,
+            plateau_budget: 0,
+            double_swap: false,
+            track_frontier: false,
+            scan: Scan::Full,
+            oscillation_delta: 0,
+            max_heavy_swaps: 1,
+            complement_restarts: false,
+            freq_decay_interval: 0,
+            u1_interval: 1,
+            stagnation_rule: StagnationRule::Fixed,
+            restart_bests: 0,
+            warm_start: false,
+            carry_memory: false,
+            maxk_lookahead: 0,
+            per_k_time_limit: None,
+            clique_starts: 16,
+            clique_mode: false,
+            min_degree_ratio: 0.0,
+            negative_weight: 1.0,
+            budget: None,
+            penalties: None,
+            rng: RngKind::ChaCha8,
+            threads: 0,
+        }
+    }
+}
+
+impl Params {
+    /// `true` if constraints beyond density ≥ γ (degree floor, budget)
+    /// take part in the feasibility test.
+    pub fn has_side_constraints(&self) -> bool {
+        self.min_degree_ratio > 0.0 || self.budget.is_some()
+    }
+
+    /// `true` if solves go to the clique search: `clique_mode` at γ = 1,
+    /// without a budget or penalties (a clique meets any degree floor).
+    pub fn uses_clique_mode(&self) -> bool {
+        self.clique_mode && self.gamma_target >= 1.0 && self.budget.is_none() && self.penalties.is_none()
+    }
+
+    /// `Err(InvalidInput)` if the per-vertex data (budget costs,
+    /// penalties) does not fit an `n`-vertex graph; the solvers index it
+    /// by vertex id.
+    pub fn check(&self, n: usize) -> io::Result<()> {
+        if let Some(b) = &self.budget {
+            b.check(n)?;
+        }
+        match &self.penalties {
+            Some(pen) => check_per_vertex("penalties", pen, n),
+            None => Ok(()),
+        }
+    }
+
+    /// The same configuration on the subgraph induced by `vertices` (new
+    /// vertex `i` is `vertices[i]`): vertex costs and penalties follow the
+    /// ids.
+    pub fn restricted(&self, vertices: &[usize]) -> Params {
+        Params {
+            budget: self.budget.as_ref().map(|b| b.restricted(vertices)),
+            penalties: self.penalties.as_ref().map(|pen| vertices.iter().map(|&v| pen[v]).collect()),
+            ..self.clone()
         }
     }
+
+    /// `true` if `sol` is within the budget (always, without one).
+    pub fn within_budget(&self, sol: &Solution<'_>) -> bool {
+        self.budget.as_ref().is_none_or(|b| b.allows(b.cost_of(sol.bitset())))
+    }
+
+    /// Full feasibility test: density ≥ γ, the minimum-degree floor and
+    /// the budget.
+    pub fn accepts(&self, sol: &Solution<'_>) -> bool {
+        sol.is_feasible(self.gamma_target, self.min_degree_ratio) && self.within_budget(sol)
+    }
+
+    /// Effective L for an n-vertex graph at size k; `mean_gap` is the mean
+    /// number of moves per run-best improvement so far (if any).
+    pub fn stagnation_limit(&self, n: usize, k: usize, mean_gap: Option<f64>) -> usize {
+        let adaptive = match self.stagnation_rule {
+            StagnationRule::Fixed => return self.stagnation_iter,
+            StagnationRule::InstanceSize(f) => f * ((n * k) as f64).sqrt(),
+            StagnationRule::ImprovementRate(f) => match mean_gap {
+                Some(g) => f * g,
+                None => return self.stagnation_iter,
+            },
+        };
+        (adaptive.ceil() as usize).max(MIN_STAGNATION)
+    }
 }
 
 #[cfg(test)]
@@ -58,5 +383,69 @@ mod tests {
         assert!((p.gamma_target - 0.90).abs() < 1e-12);
         assert_eq!(p.stagnation_iter, 1_000);
         assert_eq!(p.max_iter, 100_000_000);
+        assert_eq!(p.time_limit, None);
+        assert_eq!(p.seed_degree_percentile, 0.0);
+        assert_eq!(p.tenure_schedule, TenureSchedule::Deficit);
+        assert_eq!(p.tenure_delta_scale, 0.0);
+        assert_eq!(p.constructor, Constructor::GreedyRandom);
+        assert_eq!(p.construct_pool, 1);
+        assert_eq!(p.plateau_budget, 0);
+        assert!(!p.double_swap);
+        assert!(!p.track_frontier);
+        assert_eq!(p.scan, Scan::Full);
+        assert_eq!(p.oscillation_delta, 0);
+        assert_eq!(p.max_heavy_swaps, 1);
+        assert!(!p.complement_restarts);
+        assert_eq!(p.freq_decay_interval, 0);
+        assert_eq!(p.u1_interval, 1);
+        assert_eq!(p.stagnation_rule, StagnationRule::Fixed);
+        assert_eq!(p.restart_bests, 0);
+        assert!(!p.warm_start);
+        assert!(!p.carry_memory);
+        assert_eq!(p.maxk_lookahead, 0);
+        assert_eq!(p.per_k_time_limit, None);
+        assert_eq!(p.clique_starts, 16);
+        assert!(!p.clique_mode);
+        assert!(!p.uses_clique_mode());
+        assert_eq!(p.min_degree_ratio, 0.0);
+        assert_eq!(p.negative_weight, 1.0);
+        assert_eq!(p.budget, None);
+        assert_eq!(p.penalties, None);
+        assert_eq!(p.rng, RngKind::ChaCha8);
+        assert_eq!(p.threads, 0);
+        assert!(!p.has_side_constraints());
+        assert_eq!(p.stagnation_limit(1_000_000, 50, Some(3.0)), 1_000);
+    }
+
+    #[test]
+    fn adaptive_stagnation() {
+        let p = Params { stagnation_rule: StagnationRule::InstanceSize(2.0), ..Params::default() };
+        assert_eq!(p.stagnation_limit(10_000, 100, None), 2_000);
+        assert_eq!(p.stagnation_limit(4, 2, None), MIN_STAGNATION);
+
+        let p = Params { stagnation_rule: StagnationRule::ImprovementRate(10.0), ..Params::default() };
+        assert_eq!(p.stagnation_limit(100, 10, None), 1_000);
+        assert_eq!(p.stagnation_limit(100, 10, Some(25.5)), 255);
+    }
+
+    #[test]
+    fn budget_must_fit_the_graph() {
+        let p = Params { budget: Some(Budget { costs: vec![1.0, 0.0, 2.5], limit: 3.0 }), ..Params::default() };
+        assert!(p.check(3).is_ok());
+        assert!(p.check(4).is_err());
+        for bad in [-1.0, f64::NAN, f64::INFINITY] {
+            let p = Params { budget: Some(Budget { costs: vec![1.0, bad, 2.5], limit: 3.0 }), ..Params::default() };
+            assert_eq!(p.check(3).unwrap_err().kind(), io::ErrorKind::InvalidInput);
+        }
+        assert!(Params::default().check(0).is_ok());
+    }
+
+    #[test]
+    fn penalties_must_fit_the_graph() {
+        let p = Params { penalties: Some(vec![0.0, 1.5]), ..Params::default() };
+        assert!(p.check(2).is_ok());
+        assert!(p.check(3).unwrap_err().to_string().contains("2 penalties for 3 vertices"));
+        let p = Params { penalties: Some(vec![0.0, -0.5]), ..Params::default() };
+        assert!(p.check(2).unwrap_err().to_string().starts_with("penalties[1] = -0.5"));
     }
 }
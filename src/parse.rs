@@ -0,0 +1,357 @@
+// src/parse.rs
+//! Parallel DIMACS parsing for large inputs.
+//!
+//! The whole file is read into memory, split into chunks on newline
+//! boundaries, and every chunk is parsed on the rayon pool into its own
+//! edge buffer (no per-line allocation).  The buffers are then merged
+//! through a [`GraphBuilder`], which allocates the adjacency matrix once
+//! (or fails with an `OutOfMemory` error wrapping a
+//! [`MemoryError`](crate::MemoryError) if it would not fit).
+//! [`for_each_dimacs_edge`] instead streams the edges line by line, for
+//! files that should not be held in memory (see [`crate::stream`]).
+//!
+//! Plain edge lists are read sequentially:
+//! • [`Graph::parse_edge_list`]: `u v` per line, separated by blanks or a
+//!   comma, ids 0- or 1-based and used as they are;
+//! • [`Graph::parse_snap`]: the SNAP datasets, whose ids are arbitrary
+//!   non-negative integers and get relabelled 0..n in ascending order.
+//!
+//! Both skip blank lines and `#` / `%` comments and ignore extra columns
+//! (weights, timestamps).
+
+use crate::{builder::GraphBuilder, graph::{Graph, Layout}};
+use rayon::prelude::*;
+use std::io::{self, BufRead};
+use std::path::Path;
+
+/// Chunks per rayon thread; a few extra even out uneven line lengths.
+const CHUNKS_PER_THREAD: usize = 4;
+/// Inputs below this size are parsed as a single chunk.
+const MIN_PARALLEL_BYTES: usize = 1 << 20;
+
+#[derive(Default)]
+struct Chunk {
+    header: Option<(usize, usize)>,
+    edges:  Vec<(usize, usize)>,
+}
+
+fn invalid(line: &[u8], what: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("{what}: '{}'", String::from_utf8_lossy(line).trim()),
+    )
+}
+
+/// Parse the next unsigned integer at `pos`, skipping leading blanks.
+fn next_usize(bytes: &[u8], pos: &mut usize) -> Option<usize> {
+    while *pos < bytes.len() && bytes[*pos].is_ascii_whitespace() {
+        *pos += 1;
+    }
+    let start = *pos;
+    let mut v = 0usize;
+    while *pos < bytes.len() && bytes[*pos].is_ascii_digit() {
+        v = v.checked_mul(10)?.checked_add((bytes[*pos] - b'0') as usize)?;
+        *pos += 1;
+    }
+    (*pos > start).then_some(v)
+}
+
+/// Skip the next whitespace-delimited token (e.g. `edge` / `col`).
+fn skip_token(bytes: &[u8], pos: &mut usize) {
+    while *pos < bytes.len() && bytes[*pos].is_ascii_whitespace() {
+        *pos += 1;
+    }
+    while *pos < bytes.len() && !bytes[*pos].is_ascii_whitespace() {
+        *pos += 1;
+    }
+}
+
+/// One meaningful DIMACS line.
+enum Record {
+    /// `e u v`, as 0-based ids.
+    Edge(usize, usize),
+    /// `p <format> n m`.
+    Header(usize, usize),
+}
+
+fn parse_line(line: &[u8]) -> io::Result<Option<Record>> {
+    let line = line.trim_ascii();
+    match line.first() {
+        Some(b'e') => {
+            let mut pos = 1;
+            match (next_usize(line, &mut pos), next_usize(line, &mut pos)) {
+                (Some(u), Some(v)) if u >= 1 && v >= 1 => {
+                    if u32::try_from(u.max(v) - 1).is_err() {
+                        return Err(invalid(line, "vertex id exceeds the u32 range"));
+                    }
+                    Ok(Some(Record::Edge(u - 1, v - 1)))
+                }
+                _ => Err(invalid(line, "malformed DIMACS edge line")),
+            }
+        }
+        Some(b'p') => {
+            let mut pos = 1;
+            skip_token(line, &mut pos);
+            let n = next_usize(line, &mut pos)
+                .ok_or_else(|| invalid(line, "malformed DIMACS problem line"))?;
+            let m = next_usize(line, &mut pos).unwrap_or(0);
+            Ok(Some(Record::Header(n, m)))
+        }
+        _ => Ok(None), // comments, blank lines, unknown records
+    }
+}
+
+/// `err` prefixed with its 1-based line number.
+fn at_line(line: usize, err: io::Error) -> io::Error {
+    io::Error::new(err.kind(), format!("line {line}: {err}"))
+}
+
+/// Parse the records of `data`; an error carries the 0-based index of the
+/// offending line within `data`.
+fn parse_chunk(data: &[u8]) -> Result<Chunk, (usize, io::Error)> {
+    let mut out = Chunk::default();
+    for (i, line) in data.split(|&b| b == b'\n').enumerate() {
+        match parse_line(line).map_err(|e| (i, e))? {
+            Some(Record::Edge(u, v)) => out.edges.push((u, v)),
+            Some(Record::Header(n, m)) => out.header = Some((n, m)),
+            None => {}
+        }
+    }
+    Ok(out)
+}
+
+/// Stream the edges of a DIMACS file to `f` (0-based ids) without
+/// holding the file or the graph in memory; returns the `p` line's
+/// `(n, m)`, if any.
+pub fn for_each_dimacs_edge<P, F>(path: P, mut f: F) -> io::Result<Option<(usize, usize)>>
+where
+    P: AsRef<Path>,
+    F: FnMut(usize, usize),
+{
+    let mut reader = io::BufReader::new(std::fs::File::open(path)?);
+    let mut header = None;
+    let mut line = Vec::new();
+    let mut number = 0;
+    while reader.read_until(b'\n', &mut line)? > 0 {
+        number += 1;
+        match parse_line(&line).map_err(|e| at_line(number, e))? {
+            Some(Record::Edge(u, v)) => f(u, v),
+            Some(Record::Header(n, m)) => header = Some((n, m)),
+            None => {}
+        }
+        line.clear();
+    }
+    Ok(header)
+}
+
+/// Split `data` into about `parts` slices, each ending at a newline.
+fn split_lines(data: &[u8], parts: usize) -> Vec<&[u8]> {
+    let target = data.len().div_ceil(parts.max(1)).max(1);
+    let mut chunks = Vec::with_capacity(parts);
+    let mut start = 0;
+    while start < data.len() {
+        let mut end = (start + target).min(data.len());
+        while end < data.len() && data[end - 1] != b'\n' {
+            end += 1;
+        }
+        chunks.push(&data[start..end]);
+        start = end;
+    }
+    chunks
+}
+
+/// Parse an in-memory DIMACS *.clq file on the rayon pool, in the layout
+/// [`Layout::auto`] picks.
+pub fn parse_dimacs_bytes(data: &[u8]) -> io::Result<Graph> {
+    parse_dimacs_in(data, None)
+}
+
+/// [`parse_dimacs_bytes`] building the given adjacency layout.
+pub fn parse_dimacs_bytes_with(data: &[u8], layout: Layout) -> io::Result<Graph> {
+    parse_dimacs_in(data, Some(layout))
+}
+
+/// DIMACS in `layout`, or the [`GraphBuilder`] default ([`Layout::auto`]).
+fn parse_dimacs_in(data: &[u8], layout: Option<Layout>) -> io::Result<Graph> {
+    let chunks: Vec<Chunk> = crate::threads::install(0, || {
+        let parts = if data.len() < MIN_PARALLEL_BYTES {
+            1
+        } else {
+            rayon::current_num_threads() * CHUNKS_PER_THREAD
+        };
+        let slices = split_lines(data, parts);
+        slices
+            .par_iter()
+            .enumerate()
+            .map(|(c, slice)| parse_chunk(slice).map_err(|(i, e)| (c, i, e)))
+            .collect::<Result<_, _>>()
+            .map_err(|(c, i, e)| {
+                let before: usize = slices[..c].iter().map(|s| s.iter().filter(|&&b| b == b'\n').count()).sum();
+                at_line(before + i + 1, e)
+            })
+    })?;
+
+    let total: usize = chunks.iter().map(|c| c.edges.len()).sum();
+    let mut builder = GraphBuilder::with_capacity(total);
+    if let Some(layout) = layout {
+        builder.layout(layout);
+    }
+    for c in chunks {
+        if let Some((n, _)) = c.header {
+            builder.vertices(n);
+        }
+        builder.extend(c.edges);
+    }
+    Ok(builder.try_build()?)
+}
+
+/// `(n, m)` from the `p` line of a DIMACS file, reading only up to it
+/// (e.g. to size the adjacency before loading).  `None` without a header.
+pub fn peek_dimacs_header<P: AsRef<Path>>(path: P) -> io::Result<Option<(usize, usize)>> {
+    let reader = io::BufReader::new(std::fs::File::open(path)?);
+    for line in reader.split(b'\n') {
+        let line = line?;
+        let line = line.trim_ascii();
+        match line.first() {
+            Some(b'p') => return Ok(parse_chunk(line).map_err(|(_, e)| e)?.header),
+            Some(b'e') => return Ok(None), // edges before any header
+            _ => {}
+        }
+    }
+    Ok(None)
+}
+
+/// The first two ids of an edge-list line; `None` for blank and comment
+/// lines.
+fn edge_ids(line: &str) -> io::Result<Option<(u64, u64)>> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') || line.starts_with('%') {
+        return Ok(None);
+    }
+    let mut ids = line.split(|c: char| c.is_whitespace() || c == ',').filter(|t| !t.is_empty());
+    match (ids.next().map(str::parse), ids.next().map(str::parse)) {
+        (Some(Ok(u)), Some(Ok(v))) => Ok(Some((u, v))),
+        _ => Err(invalid(line.as_bytes(), "malformed edge-list line")),
+    }
+}
+
+/// `id` as a vertex index, checked against the `u32` id range.
+fn vertex(id: u64, line: &str) -> io::Result<usize> {
+    u32::try_from(id).map(|v| v as usize).map_err(|_| invalid(line.as_bytes(), "vertex id exceeds the u32 range"))
+}
+
+impl Graph {
+    /// Parse an edge list of `u v` pairs (see the module docs); ids are
+    /// 1-based if `one_based`, and n is the largest id + 1 (0-based) or
+    /// the largest id (1-based).
+    pub fn parse_edge_list<R: BufRead>(reader: R, one_based: bool) -> io::Result<Self> {
+        let mut builder = GraphBuilder::new();
+        for line in reader.lines() {
+            let line = line?;
+            let Some((u, v)) = edge_ids(&line)? else { continue };
+            let shift = u64::from(one_based);
+            if u < shift || v < shift {
+                return Err(invalid(line.as_bytes(), "vertex id 0 in a 1-based edge list"));
+            }
+            builder.add_edge(vertex(u - shift, &line)?, vertex(v - shift, &line)?);
+        }
+        Ok(builder.try_build()?)
+    }
+
+    /// [`Graph::parse_edge_list`] on a file.
+    pub fn read_edge_list<P: AsRef<Path>>(path: P, one_based: bool) -> io::Result<Self> {
+        Self::parse_edge_list(io::BufReader::new(std::fs::File::open(path)?), one_based)
+    }
+
+    /// Parse a SNAP edge list: returns the graph and the original id of
+    /// each vertex (ascending), so vertex `i` is `ids[i]` in the file.
+    pub fn parse_snap<R: BufRead>(reader: R) -> io::Result<(Self, Vec<u64>)> {
+        let mut edges = Vec::new();
+        for line in reader.lines() {
+            if let Some(e) = edge_ids(&line?)? {
+                edges.push(e);
+            }
+        }
+        let mut ids: Vec<u64> = edges.iter().flat_map(|&(u, v)| [u, v]).collect();
+        ids.sort_unstable();
+        ids.dedup();
+        if u32::try_from(ids.len()).is_err() {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "more vertices than the u32 id range"));
+        }
+        let index = |id| ids.binary_search(&id).unwrap();
+        let mut builder = GraphBuilder::with_capacity(edges.len());
+        builder.vertices(ids.len()).extend(edges.iter().map(|&(u, v)| (index(u), index(v))));
+        Ok((builder.try_build()?, ids))
+    }
+
+    /// [`Graph::parse_snap`] on a file.
+    pub fn read_snap_file<P: AsRef<Path>>(path: P) -> io::Result<(Self, Vec<u64>)> {
+        Self::parse_snap(io::BufReader::new(std::fs::File::open(path)?))
+    }
+
+    /// Read and parse a DIMACS file, in parallel for large inputs.
+    pub fn read_dimacs_file<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        parse_dimacs_bytes(&std::fs::read(path)?)
+    }
+
+    /// [`Graph::read_dimacs_file`] building the given adjacency layout.
+    pub fn read_dimacs_file_with<P: AsRef<Path>>(path: P, layout: Layout) -> io::Result<Self> {
+        parse_dimacs_bytes_with(&std::fs::read(path)?, layout)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn parallel_matches_sequential() {
+        let mut text = String::from("c test\np edge 300 0\n");
+        for u in 1..=300usize {
+            for v in [u * 7 % 300 + 1, u * 13 % 300 + 1] {
+                if u != v {
+                    text.push_str(&format!("e {u} {v}\n"));
+                }
+            }
+        }
+        let seq = Graph::parse_dimacs(Cursor::new(text.as_bytes())).unwrap();
+        let whole = parse_chunk(text.as_bytes()).unwrap().edges;
+        for parts in [3, 17, 1000] {
+            let merged: Vec<_> = split_lines(text.as_bytes(), parts)
+                .into_iter()
+                .flat_map(|c| parse_chunk(c).unwrap().edges)
+                .collect();
+            assert_eq!(merged, whole);
+        }
+        let par = parse_dimacs_bytes(text.as_bytes()).unwrap();
+        assert_eq!(par.n(), seq.n());
+        assert_eq!(par.edge_list(), seq.edge_list());
+
+        assert!(parse_dimacs_bytes(b"p edge 2 1\ne 1 x\n").is_err());
+        let err = parse_dimacs_bytes(b"p edge 2 1\ne 1 2\ne 1 5000000000\n").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err.to_string().starts_with("line 3: vertex id exceeds the u32 range"), "{err}");
+
+        let tri = parse_dimacs_bytes_with(text.as_bytes(), Layout::Triangular).unwrap();
+        assert_eq!(tri.layout(), Layout::Triangular);
+        assert_eq!(tri.edge_list(), seq.edge_list());
+    }
+
+    #[test]
+    fn edge_lists_and_snap() {
+        let text = "# u v\n1 2\n2,3\n\n% comment\n3\t1 0.5\n";
+        let g = Graph::parse_edge_list(text.as_bytes(), false).unwrap();
+        assert_eq!((g.n(), g.edge_list()), (4, vec![(1, 2), (1, 3), (2, 3)]));
+        let g = Graph::parse_edge_list(text.as_bytes(), true).unwrap();
+        assert_eq!((g.n(), g.edge_list()), (3, vec![(0, 1), (0, 2), (1, 2)]));
+        assert!(Graph::parse_edge_list("0 1\n".as_bytes(), true).is_err());
+        assert!(Graph::parse_edge_list("0 x\n".as_bytes(), false).is_err());
+        assert!(Graph::parse_edge_list("0 4294967296\n".as_bytes(), false).is_err());
+
+        let text = "# Directed graph: example.txt\n# FromNodeId\tToNodeId\n30\t7\n7\t9000000000\n30\t9000000000\n9000000000\t7\n";
+        let (g, ids) = Graph::parse_snap(text.as_bytes()).unwrap();
+        assert_eq!(ids, vec![7, 30, 9_000_000_000]);
+        assert_eq!(g.edge_list(), vec![(0, 1), (0, 2), (1, 2)]);
+    }
+}
@@ -0,0 +1,140 @@
+// src/percolation.rs
+//! Clique-percolation-style communities from found quasi-cliques.
+//!
+//! Input is any list of quasi-cliques on one graph (the restart bests of
+//! [`solve_fixed_k_all`](crate::restart::solve_fixed_k_all), a
+//! [`SolutionPool`](crate::pool::SolutionPool), disjoint sets, …).  Two of
+//! them are adjacent when they share at least
+//!
+//! ```text
+//! ⌈min_overlap · min(|A|, |B|)⌉ vertices        (at least one)
+//! ```
+//!
+//! — CPM's k−1 shared vertices for k-cliques corresponds to
+//! `min_overlap = (k−1)/k`.  Connected components of that relation (via
+//! union-find) are the communities; a community is the union of its
+//! quasi-cliques, so communities may overlap, and vertices covered by no
+//! quasi-clique belong to none.
+
+use crate::solution::Solution;
+
+/// Communities found by [`percolate`], largest first.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Communities {
+    /// Vertices of each community, ascending.
+    pub members:    Vec<Vec<usize>>,
+    /// Indices (into the input) of the quasi-cliques merged into each.
+    pub cliques:    Vec<Vec<usize>>,
+    /// Communities containing each vertex, ascending.
+    pub membership: Vec<Vec<usize>>,
+}
+
+impl Communities {
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    /// One community per vertex: the largest one containing it (`None` if
+    /// no quasi-clique covers it).
+    pub fn assignment(&self) -> Vec<Option<usize>> {
+        self.membership.iter().map(|c| c.first().copied()).collect()
+    }
+}
+
+fn find(parent: &mut [usize], mut x: usize) -> usize {
+    while parent[x] != x {
+        parent[x] = parent[parent[x]];
+        x = parent[x];
+    }
+    x
+}
+
+/// Merge `cliques` overlapping by at least `min_overlap` ∈ [0, 1] of the
+/// smaller one into communities.  Empty sets are ignored.
+///
+/// Panics if the quasi-cliques belong to graphs of different sizes.
+pub fn percolate(cliques: &[Solution<'_>], min_overlap: f64) -> Communities {
+    let Some(n) = cliques.first().map(|c| c.graph().n()) else {
+        return Communities::default();
+    };
+    assert!(cliques.iter().all(|c| c.graph().n() == n), "quasi-cliques from different graphs");
+    let live: Vec<usize> = (0..cliques.len()).filter(|&i| cliques[i].size() > 0).collect();
+
+    let mut parent: Vec<usize> = (0..cliques.len()).collect();
+    for (x, &i) in live.iter().enumerate() {
+        for &j in &live[x + 1..] {
+            let need = ((min_overlap * cliques[i].size().min(cliques[j].size()) as f64).ceil() as usize).max(1);
+            let shared = (cliques[i].bitset().clone() & cliques[j].bitset()).count_ones();
+            if shared >= need {
+                let (a, b) = (find(&mut parent, i), find(&mut parent, j));
+                parent[a.max(b)] = a.min(b);
+            }
+        }
+    }
+
+    // group by root, union the members
+    let mut groups: Vec<(Vec<usize>, Vec<usize>)> = Vec::new();
+    let mut slot = vec![usize::MAX; cliques.len()];
+    for &i in &live {
+        let r = find(&mut parent, i);
+        if slot[r] == usize::MAX {
+            slot[r] = groups.len();
+            groups.push((Vec::new(), Vec::new()));
+        }
+        groups[slot[r]].1.push(i);
+    }
+    for (members, ids) in &mut groups {
+        let mut mask = cliques[ids[0]].bitset().clone();
+        for &i in &ids[1..] {
+            mask |= cliques[i].bitset();
+        }
+        *members = mask.iter_ones().collect();
+    }
+    groups.sort_by(|a, b| b.0.len().cmp(&a.0.len()).then_with(|| a.0.cmp(&b.0)));
+
+    let mut membership = vec![Vec::new(); n];
+    for (c, (members, _)) in groups.iter().enumerate() {
+        for &v in members {
+            membership[v].push(c);
+        }
+    }
+    let (members, cliques) = groups.into_iter().unzip();
+    Communities { members, cliques, membership }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Graph;
+
+    #[test]
+    fn overlapping_cliques_merge() {
+        let g = Graph::with_vertices(10);
+        let set = |vs: &[usize]| {
+            let mut s = Solution::new(&g);
+            for &v in vs {
+                s.add(v);
+            }
+            s
+        };
+        // {0..4} and {1..5} share 3 of 4; {5..8} touches the second in one
+        // vertex only; {8, 9} shares vertex 8 with it
+        let found = vec![set(&[0, 1, 2, 3]), set(&[1, 2, 3, 4]), set(&[4, 5, 6, 7]), set(&[7, 8]), Solution::new(&g)];
+
+        let c = percolate(&found, 0.75);
+        assert_eq!(c.members, vec![vec![0, 1, 2, 3, 4], vec![4, 5, 6, 7], vec![7, 8]]);
+        assert_eq!(c.cliques, vec![vec![0, 1], vec![2], vec![3]]);
+        assert_eq!(c.membership[4], vec![0, 1]);
+        assert_eq!(c.assignment()[4], Some(0));
+        assert_eq!(c.assignment()[9], None);
+
+        // any shared vertex links everything
+        let c = percolate(&found, 0.0);
+        assert_eq!(c.len(), 1);
+        assert_eq!(c.members[0], (0..9).collect::<Vec<_>>());
+    }
+}
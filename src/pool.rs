@@ -0,0 +1,193 @@
+// src/pool.rs
+//! Bounded pool of distinct, diverse solutions.
+//!
+//! Shared storage for elite pools, top-N enumeration and populations:
+//! • exact duplicates are rejected in O(1) via the incremental Zobrist
+//!   hash every [`Solution`] maintains;
+//! • a candidate within `min_distance` (symmetric difference) of a member
+//!   replaces that member only if it is better, otherwise it is rejected;
+//! • when full, a new candidate evicts the worst member if it is better.
+//!
+//! "Better" means larger |S|, then higher density.
+
+use crate::solution::Solution;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+/// Zobrist key of vertex `v` (SplitMix64 of the index; no table needed).
+#[inline]
+pub fn zobrist_key(v: usize) -> u64 {
+    let mut z = (v as u64).wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Zobrist hash of a set given by its distinct members: the value
+/// [`Solution::zobrist`] has for that set, without building one.
+pub fn zobrist_of(vertices: impl IntoIterator<Item = usize>) -> u64 {
+    vertices.into_iter().fold(0, |h, v| h ^ zobrist_key(v))
+}
+
+/// |A △ B| of two solutions on the same graph.
+pub fn distance(a: &Solution<'_>, b: &Solution<'_>) -> usize {
+    a.bitset().as_raw_slice()
+        .iter()
+        .zip(b.bitset().as_raw_slice())
+        .map(|(x, y)| (x ^ y).count_ones() as usize)
+        .sum()
+}
+
+/// Quality order used by the pool (greater = better).
+pub fn compare(a: &Solution<'_>, b: &Solution<'_>) -> Ordering {
+    a.size().cmp(&b.size()).then(a.density().total_cmp(&b.density()))
+}
+
+#[derive(Clone, Debug)]
+pub struct SolutionPool<'g> {
+    members:      Vec<Solution<'g>>,
+    hashes:       HashSet<u64>,
+    capacity:     usize,
+    min_distance: usize,
+}
+
+impl<'g> SolutionPool<'g> {
+    /// Pool of at most `capacity` members, pairwise more than
+    /// `min_distance` apart (0 = only exact duplicates are rejected).
+    pub fn new(capacity: usize, min_distance: usize) -> Self {
+        Self {
+            members:      Vec::with_capacity(capacity),
+            hashes:       HashSet::with_capacity(capacity),
+            capacity,
+            min_distance,
+        }
+    }
+
+    pub fn len(&self) -> usize      { self.members.len() }
+    pub fn is_empty(&self) -> bool  { self.members.is_empty() }
+    pub fn capacity(&self) -> usize { self.capacity }
+
+    /// Is a set with this Zobrist hash stored?
+    pub fn contains_hash(&self, hash: u64) -> bool {
+        self.hashes.contains(&hash)
+    }
+
+    /// Offer `sol`; returns `true` if it was stored.
+    pub fn insert(&mut self, sol: &Solution<'g>) -> bool {
+        if self.capacity == 0 || sol.size() == 0 || self.contains_hash(sol.zobrist()) {
+            return false;
+        }
+        if self.min_distance > 0 {
+            let close = self.members
+                .iter()
+                .enumerate()
+                .filter(|(_, m)| distance(m, sol) <= self.min_distance)
+                .min_by(|a, b| compare(a.1, b.1))
+                .map(|(i, _)| i);
+            if let Some(i) = close {
+                if compare(sol, &self.members[i]) != Ordering::Greater {
+                    return false;
+                }
+                self.replace(i, sol);
+                return true;
+            }
+        }
+        if self.members.len() < self.capacity {
+            self.hashes.insert(sol.zobrist());
+            self.members.push(sol.clone());
+            return true;
+        }
+        let worst = self.worst_index().unwrap();
+        if compare(sol, &self.members[worst]) == Ordering::Greater {
+            self.replace(worst, sol);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Best member, if any.
+    pub fn best(&self) -> Option<&Solution<'g>> {
+        self.members.iter().max_by(|a, b| compare(a, b))
+    }
+
+    /// Remove and return the best member.
+    pub fn take_best(&mut self) -> Option<Solution<'g>> {
+        let i = (0..self.members.len())
+            .max_by(|&a, &b| compare(&self.members[a], &self.members[b]))?;
+        let sol = self.members.swap_remove(i);
+        self.hashes.remove(&sol.zobrist());
+        Some(sol)
+    }
+
+    /// Members in insertion/replacement order.
+    pub fn iter(&self) -> impl Iterator<Item = &Solution<'g>> {
+        self.members.iter()
+    }
+
+    /// Members sorted best first.
+    pub fn into_sorted_vec(mut self) -> Vec<Solution<'g>> {
+        self.members.sort_by(|a, b| compare(b, a));
+        self.members
+    }
+
+    pub fn clear(&mut self) {
+        self.members.clear();
+        self.hashes.clear();
+    }
+
+    fn worst_index(&self) -> Option<usize> {
+        (0..self.members.len()).min_by(|&a, &b| compare(&self.members[a], &self.members[b]))
+    }
+
+    fn replace(&mut self, i: usize, sol: &Solution<'g>) {
+        self.hashes.remove(&self.members[i].zobrist());
+        self.hashes.insert(sol.zobrist());
+        self.members[i] = sol.clone();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::Graph;
+
+    fn sol<'g>(g: &'g Graph, vs: &[usize]) -> Solution<'g> {
+        let mut s = Solution::new(g);
+        for &v in vs {
+            s.add(v);
+        }
+        s
+    }
+
+    #[test]
+    fn dedup_diversity_and_eviction() {
+        // triangle 0-1-2, path 3-4-5
+        let g = Graph::from_edge_list(6, &[(0, 1), (1, 2), (0, 2), (3, 4), (4, 5)]);
+        let mut pool = SolutionPool::new(2, 1);
+
+        assert!(pool.insert(&sol(&g, &[0, 1, 2])));
+        assert!(!pool.insert(&sol(&g, &[2, 1, 0])));       // duplicate
+        assert_eq!(sol(&g, &[2, 1, 0]).zobrist(), zobrist_of([0, 1, 2]));
+        assert!(!pool.insert(&sol(&g, &[0, 1])));          // close and worse
+        assert!(pool.insert(&sol(&g, &[3, 4, 5])));
+        assert!(!pool.insert(&sol(&g, &[0, 3])));          // full, not better
+        assert!(pool.insert(&sol(&g, &[0, 1, 2, 3])));     // close, better
+        assert_eq!(pool.len(), 2);
+        assert_eq!(pool.best().unwrap().size(), 4);
+
+        let s = sol(&g, &[3, 4, 5]);
+        assert!(pool.contains_hash(s.zobrist()));
+        let mut t = sol(&g, &[3, 4]);
+        t.add(5);
+        assert_eq!(t.zobrist(), s.zobrist());
+
+        let mut taken = pool.clone();
+        assert_eq!(taken.take_best().unwrap().size(), 4);
+        assert_eq!(taken.len(), 1);
+        assert!(!taken.contains_hash(sol(&g, &[0, 1, 2, 3]).zobrist()));
+
+        let sorted = pool.into_sorted_vec();
+        assert_eq!(sorted[0].size(), 4);
+    }
+}
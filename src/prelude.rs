@@ -0,0 +1,13 @@
+// src/prelude.rs
+//! The common imports in one: `use tsqc::prelude::*;`.
+
+pub use crate::facade::{Found, Report, Tsqc};
+pub use crate::graph::{Graph, Layout};
+pub use crate::incumbents::{solve_fixed_k_iter, Incumbent};
+pub use crate::maxk::{solve_maxk, solve_maxk_with};
+pub use crate::observer::{Observer, StopReason};
+pub use crate::params::{Params, Scan};
+pub use crate::restart::{solve_fixed_k, solve_fixed_k_with};
+pub use crate::rng::{RngKind, SolverRng};
+pub use crate::solution::Solution;
+pub use rand::SeedableRng;
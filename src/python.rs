@@ -0,0 +1,1338 @@
+// src/python.rs
+//! PyO3 bindings: the `tsqc._native` extension module (feature `python`).
+//!
+//! Every `graph_path` argument takes a DIMACS path or a `Graph` handle
+//! (parsed once and shared between calls).
+//! Every γ must lie in (0, 1]; anything else (NaN included) raises
+//! `ValueError` before a search starts.
+
+use crate::*;
+use pyo3::prelude::*;
+use pyo3::wrap_pyfunction;
+use pyo3::types::PyModule;
+use pyo3::prelude::Bound;       // Bound<'py, T> API in PyO3 v0.25
+
+/*───────── extern util ─────────*/
+use rand_chacha::ChaCha8Rng;
+use rand::SeedableRng;
+use std::fs::File;
+use std::sync::Arc;
+
+/*======================================================================
+│  Python-functies
+└=====================================================================*/
+
+/// Read and parse a DIMACS file (in parallel for large inputs), mapping
+/// errors to Python exceptions.
+fn load_dimacs(graph_path: &str) -> PyResult<Graph> {
+    load_with(graph_path, parse::parse_dimacs_bytes)
+}
+
+/// Read the file at `path` and hand its bytes to `parse`, mapping errors
+/// as [`load_dimacs`] does.
+fn load_with<T>(path: &str, parse: impl FnOnce(&[u8]) -> std::io::Result<T>) -> PyResult<T> {
+    let data = std::fs::read(path)
+        .map_err(|e| pyo3::exceptions::PyIOError::new_err(e.to_string()))?;
+    parse(&data).map_err(|e| match e.kind() {
+        std::io::ErrorKind::OutOfMemory => pyo3::exceptions::PyMemoryError::new_err(e.to_string()),
+        _ => pyo3::exceptions::PyValueError::new_err(e.to_string()),
+    })
+}
+
+type FileTrace = TraceWriter<std::io::BufWriter<File>>;
+
+/// Optional iteration trace + optional restart log, as one observer.
+type PyTrace = (Option<FileTrace>, Option<FileTrace>);
+
+/// Open the sinks for the optional `trace_path` / `restart_log` arguments.
+fn open_traces(trace_path: Option<String>, restart_log: Option<String>) -> PyResult<PyTrace> {
+    let io_err = |e: std::io::Error| pyo3::exceptions::PyIOError::new_err(e.to_string());
+    let iters = trace_path.map(TraceWriter::create).transpose().map_err(io_err)?;
+    let restarts = restart_log.map(TraceWriter::create_restarts).transpose().map_err(io_err)?;
+    Ok((iters, restarts))
+}
+
+/// Flush both sinks, surfacing any deferred write error.
+fn close_traces((iters, restarts): PyTrace) -> PyResult<()> {
+    for t in [iters, restarts].into_iter().flatten() {
+        t.finish()
+            .map_err(|e| pyo3::exceptions::PyIOError::new_err(e.to_string()))?;
+    }
+    Ok(())
+}
+
+/// Best set of one `solve_*_py` call.
+#[pyclass(name = "Solution", frozen)]
+struct PySolution {
+    /// Vertices of the set (0-based, ascending).
+    #[pyo3(get)]
+    vertices: Vec<usize>,
+    #[pyo3(get)]
+    size:     usize,
+    /// Edges inside the set.
+    #[pyo3(get)]
+    edges:    usize,
+    #[pyo3(get)]
+    density:  f64,
+}
+
+impl From<&Solution<'_>> for PySolution {
+    fn from(sol: &Solution<'_>) -> Self {
+        Self { vertices: sol.bitset().iter_ones().collect(), size: sol.size(), edges: sol.edges(), density: sol.density() }
+    }
+}
+
+#[pymethods]
+impl PySolution {
+    fn __len__(&self) -> usize {
+        self.size
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Solution(size={}, edges={}, density={:.4})", self.size, self.edges, self.density)
+    }
+}
+
+/// Fixed-k solver – returns the best k-subset as a `Solution`
+/// (vertices, size, edges, density).
+/// `graph_path` is a DIMACS path or a `Graph` (parsed once, reused).
+/// With `trace_path` (`*.csv` or `*.jsonl`) every iteration is logged,
+/// with `restart_log` one summary line per restart.  `initial_vertices`
+/// (e.g. a set from an earlier run) replaces the constructed start of the
+/// first run; it is trimmed or grown greedily to k vertices if needed.
+#[pyfunction]
+#[pyo3(signature = (graph_path, k, gamma, seed, trace_path=None, restart_log=None, initial_vertices=None))]
+#[pyo3(text_signature = "(graph_path, k, gamma, seed, trace_path=None, restart_log=None, initial_vertices=None)")]
+#[allow(clippy::too_many_arguments)]
+fn solve_k_py(
+    py: Python<'_>,
+    graph_path: GraphArg<'_>,
+    k: usize,
+    gamma: f64,
+    seed: u64,
+    trace_path: Option<String>,
+    restart_log: Option<String>,
+    initial_vertices: Option<Vec<usize>>,
+) -> PyResult<PySolution> {
+    let (graph, _) = graph_path.load()?;
+    check_k_gamma(&graph, k, gamma)?;
+
+    let p = gamma_params(gamma)?;
+
+    if let Some(vertices) = &initial_vertices {
+        check_vertices(&graph, vertices)?;
+    }
+    let mut rng = SolverRng::seeded(p.rng, seed);
+    let mut trace = open_traces(trace_path, restart_log)?;
+    let sol = py.allow_threads(|| match initial_vertices {
+        Some(vertices) => {
+            let mut start = Solution::new(&graph);
+            for v in vertices {
+                if !start.bitset()[v] {
+                    start.add(v);
+                }
+            }
+            construct::resize_to_k(&mut start, k, &mut rng);
+            solve_fixed_k_from(start, &mut rng, &p, &mut trace)
+        }
+        None => solve_fixed_k_with(&graph, k, &mut rng, &p, &mut trace),
+    });
+    close_traces(trace)?;
+    Ok(PySolution::from(&sol))
+}
+
+/// Max-k solver – returns the best quasi-clique as a `Solution`.
+/// `graph_path` is a DIMACS path or a `Graph`; `trace_path` /
+/// `restart_log` cover every inner fixed-k run.
+#[pyfunction]
+#[pyo3(signature = (graph_path, gamma, seed, trace_path=None, restart_log=None))]
+#[pyo3(text_signature = "(graph_path, gamma, seed, trace_path=None, restart_log=None)")]
+fn solve_max_py(
+    py: Python<'_>,
+    graph_path: GraphArg<'_>,
+    gamma: f64,
+    seed: u64,
+    trace_path: Option<String>,
+    restart_log: Option<String>,
+) -> PyResult<PySolution> {
+    let (graph, _) = graph_path.load()?;
+
+    let p = gamma_params(gamma)?;
+
+    let mut rng = SolverRng::seeded(p.rng, seed);
+    let mut trace = open_traces(trace_path, restart_log)?;
+    let sol = py.allow_threads(|| solve_maxk_with(&graph, &mut rng, &p, &mut trace));
+    close_traces(trace)?;
+    Ok(PySolution::from(&sol))
+}
+
+/// Maximum clique by the dedicated γ = 1 search (see `clique.rs`);
+/// `params` overrides [`Params`] fields by name (`max_iter`,
+/// `time_limit`, `stagnation_iter`, `clique_starts`, …).
+#[pyfunction]
+#[pyo3(signature = (graph_path, seed=0, params=None))]
+#[pyo3(text_signature = "(graph_path, seed=0, params=None)")]
+fn solve_clique_py(
+    py: Python<'_>,
+    graph_path: GraphArg<'_>,
+    seed: u64,
+    params: Option<&Bound<'_, pyo3::types::PyDict>>,
+) -> PyResult<PySolution> {
+    let (graph, _) = graph_path.load()?;
+    let p = params_from(1.0, params)?;
+    check_params(&graph, &p)?;
+    py.allow_threads(|| {
+        let mut rng = SolverRng::seeded(p.rng, seed);
+        Ok(PySolution::from(&solve_clique(&graph, &mut rng, &p)))
+    })
+}
+
+/// Graph from an n×n array-like (numpy bool / integer / float, or any
+/// object exposing a 2-D byte buffer); non-zero entries are edges.
+fn dense_graph(adjacency: &Bound<'_, PyAny>) -> PyResult<Graph> {
+    use pyo3::buffer::PyBuffer;
+    let buf = match PyBuffer::<u8>::get(adjacency) {
+        Ok(buf) => buf,
+        // bool / wider dtypes: let numpy map non-zero entries to 1
+        Err(_) => {
+            let nonzero = adjacency.call_method1("__ne__", (0,))?;
+            PyBuffer::<u8>::get(&nonzero.call_method1("astype", ("u1",))?)?
+        }
+    };
+    let n = match buf.shape() {
+        [a, b] if a == b => *a,
+        shape => {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "adjacency must be square, got shape {shape:?}"
+            )));
+        }
+    };
+    let data = buf.to_vec(adjacency.py())?;
+    npy::graph_from_dense(n, &data)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+}
+
+/// Fixed-k solver on a dense n×n adjacency array – returns density.
+#[pyfunction]
+#[pyo3(text_signature = "(adjacency, k, gamma, seed)")]
+fn solve_k_dense_py(py: Python<'_>, adjacency: &Bound<'_, PyAny>, k: usize, gamma: f64, seed: u64) -> PyResult<f64> {
+    let graph = dense_graph(adjacency)?;
+    check_k_gamma(&graph, k, gamma)?;
+    let p = gamma_params(gamma)?;
+    let mut rng = SolverRng::seeded(p.rng, seed);
+    Ok(py.allow_threads(|| solve_fixed_k(&graph, k, &mut rng, &p).density()))
+}
+
+/// Max-k solver on a dense n×n adjacency array – returns (size, density).
+#[pyfunction]
+#[pyo3(text_signature = "(adjacency, gamma, seed)")]
+fn solve_max_dense_py(py: Python<'_>, adjacency: &Bound<'_, PyAny>, gamma: f64, seed: u64) -> PyResult<(usize, f64)> {
+    let graph = dense_graph(adjacency)?;
+    let p = gamma_params(gamma)?;
+    let mut rng = SolverRng::seeded(p.rng, seed);
+    let sol = py.allow_threads(|| solve_maxk(&graph, &mut rng, &p));
+    Ok((sol.size(), sol.density()))
+}
+
+/// Batch max-k: parse and solve every DIMACS file on a Rust thread pool
+/// (`n_threads` = 0 → the shared pool, see `set_threads`) with the GIL
+/// released.  Returns one
+/// (size, density) per path, in input order; each file uses `seed`.
+#[pyfunction]
+#[pyo3(signature = (paths, gamma, seed, n_threads=0))]
+#[pyo3(text_signature = "(paths, gamma, seed, n_threads=0)")]
+fn solve_max_files(
+    py: Python<'_>,
+    paths: Vec<String>,
+    gamma: f64,
+    seed: u64,
+    n_threads: usize,
+) -> PyResult<Vec<(usize, f64)>> {
+    use rayon::prelude::*;
+    let p = gamma_params(gamma)?;
+
+    let results: Vec<std::io::Result<(usize, f64)>> = py.allow_threads(|| {
+        threads::install(n_threads, || {
+            paths
+                .par_iter()
+                .map(|path| {
+                    let graph = parse::parse_dimacs_bytes(&std::fs::read(path)?)?;
+                    let mut rng = SolverRng::seeded(p.rng, seed);
+                    let sol = solve_maxk(&graph, &mut rng, &p);
+                    Ok((sol.size(), sol.density()))
+                })
+                .collect()
+        })
+    });
+    results
+        .into_iter()
+        .zip(&paths)
+        .map(|(r, path)| {
+            r.map_err(|e| pyo3::exceptions::PyIOError::new_err(format!("{path}: {e}")))
+        })
+        .collect()
+}
+
+/// [`RunCounter`] with an optional `time_limit` in seconds.
+fn run_counter(time_limit: Option<f64>) -> PyResult<RunCounter> {
+    Ok(RunCounter::start(seconds("time_limit", time_limit)?))
+}
+
+/// A duration argument given in seconds (`None` = no limit).
+fn seconds(name: &str, secs: Option<f64>) -> PyResult<Option<std::time::Duration>> {
+    secs.map(|t| {
+        std::time::Duration::try_from_secs_f64(t).map_err(|_| {
+            pyo3::exceptions::PyValueError::new_err(format!("{name} must be a non-negative number of seconds, got {t}"))
+        })
+    })
+    .transpose()
+}
+
+/// Pairs from an (m, 2) integer array (read through the buffer protocol,
+/// converted to int64 by NumPy if needed) or a sequence of pairs.
+fn pair_array(pairs: &Bound<'_, PyAny>) -> PyResult<Vec<(usize, usize)>> {
+    use pyo3::buffer::PyBuffer;
+    let buf = match PyBuffer::<i64>::get(pairs) {
+        Ok(buf) => buf,
+        Err(_) if pairs.hasattr("astype")? => PyBuffer::<i64>::get(&pairs.call_method1("astype", ("i8",))?)?,
+        Err(_) => return pairs.extract(),
+    };
+    match buf.shape() {
+        [_, 2] => {}
+        [0] => return Ok(Vec::new()),
+        shape => {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "pairs must have shape (m, 2), got {shape:?}"
+            )));
+        }
+    }
+    let flat = buf.to_vec(pairs.py())?;
+    flat.chunks_exact(2)
+        .map(|p| match (usize::try_from(p[0]), usize::try_from(p[1])) {
+            (Ok(u), Ok(v)) => Ok((u, v)),
+            _ => Err(pyo3::exceptions::PyValueError::new_err(format!("negative vertex in pair ({}, {})", p[0], p[1]))),
+        })
+        .collect()
+}
+
+/// Graph from 0-based edges, with at least `n` vertices.
+fn edge_graph(edges: Vec<(usize, usize)>, n: Option<usize>) -> PyResult<Graph> {
+    if let Some(n) = n
+        && let Some(&(u, v)) = edges.iter().find(|&&(u, v)| u.max(v) >= n)
+    {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!("edge ({u}, {v}) out of range (n = {n})")));
+    }
+    if let Some(&(u, v)) = edges.iter().find(|&&(u, v)| u.max(v) > u32::MAX as usize) {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!("edge ({u}, {v}) exceeds the u32 id range")));
+    }
+    let mut builder = GraphBuilder::with_capacity(edges.len());
+    builder.vertices(n.unwrap_or(0)).extend(edges);
+    builder.try_build().map_err(|e| pyo3::exceptions::PyMemoryError::new_err(e.to_string()))
+}
+
+/// Read-only graph handle, shared with the results computed on it.
+#[pyclass(name = "Graph", frozen)]
+struct PyGraph {
+    inner:  Arc<Graph>,
+    /// Where the graph came from (file path, `<dense>`, `<edges>`,
+    /// `<arrays>` or `<subgraph>`).
+    source: String,
+}
+
+#[pymethods]
+impl PyGraph {
+    /// Parse a DIMACS file.
+    #[staticmethod]
+    fn from_dimacs(graph_path: &str) -> PyResult<Self> {
+        Ok(Self { inner: Arc::new(load_dimacs(graph_path)?), source: graph_path.to_string() })
+    }
+
+    /// Parse an edge list file (`u v` per line, blank- or comma-separated,
+    /// `#` / `%` comments); ids are 0-based unless `one_based`.
+    #[staticmethod]
+    #[pyo3(signature = (path, one_based=false))]
+    fn from_edge_list(path: &str, one_based: bool) -> PyResult<Self> {
+        let graph = load_with(path, |data| Graph::parse_edge_list(data, one_based))?;
+        Ok(Self { inner: Arc::new(graph), source: path.to_string() })
+    }
+
+    /// Parse a SNAP edge list; returns the graph and `ids[i]`, the id in
+    /// the file of vertex `i` (ids are relabelled 0..n in ascending order).
+    #[staticmethod]
+    fn from_snap(path: &str) -> PyResult<(Self, Vec<u64>)> {
+        let (graph, ids) = load_with(path, |data| Graph::parse_snap(data))?;
+        Ok((Self { inner: Arc::new(graph), source: path.to_string() }, ids))
+    }
+
+    /// Graph from a dense n×n adjacency array (see `solve_max_dense_py`).
+    #[staticmethod]
+    fn from_dense(adjacency: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(Self { inner: Arc::new(dense_graph(adjacency)?), source: "<dense>".to_string() })
+    }
+
+    /// Graph from 0-based `(u, v)` pairs; `n` defaults to the largest id + 1.
+    #[staticmethod]
+    #[pyo3(signature = (edges, n=None))]
+    fn from_edges(edges: Vec<(usize, usize)>, n: Option<usize>) -> PyResult<Self> {
+        Ok(Self { inner: Arc::new(edge_graph(edges, n)?), source: "<edges>".to_string() })
+    }
+
+    /// Graph from two equally long integer arrays (e.g. NumPy) of edge
+    /// endpoints, as in a COO matrix; `n` as for `from_edges`.
+    #[staticmethod]
+    #[pyo3(signature = (src, dst, n=None))]
+    fn from_arrays(src: Vec<usize>, dst: Vec<usize>, n: Option<usize>) -> PyResult<Self> {
+        if src.len() != dst.len() {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "src and dst differ in length ({} vs {})",
+                src.len(),
+                dst.len()
+            )));
+        }
+        let edges = src.into_iter().zip(dst).collect();
+        Ok(Self { inner: Arc::new(edge_graph(edges, n)?), source: "<arrays>".to_string() })
+    }
+
+    #[getter]
+    fn n(&self) -> usize {
+        self.inner.n()
+    }
+
+    #[getter]
+    fn m(&self) -> usize {
+        self.inner.m()
+    }
+
+    /// Edge list as 0-based `(u, v)` pairs with `u < v`.
+    fn edges(&self) -> Vec<(usize, usize)> {
+        self.inner.edge_list()
+    }
+
+    /// Edge test for every 0-based `(u, v)` row of a (m, 2) integer array
+    /// (or any sequence of pairs), in one call: a NumPy bool array when
+    /// NumPy is installed, otherwise a list.
+    fn has_edges(&self, py: Python<'_>, pairs: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        let pairs = pair_array(pairs)?;
+        let n = self.inner.n();
+        if let Some(&(u, v)) = pairs.iter().find(|&&(u, v)| u.max(v) >= n) {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!("pair ({u}, {v}) out of range (n = {n})")));
+        }
+        let hits: Vec<bool> = py.allow_threads(|| self.inner.has_edges(&pairs).iter().by_vals().collect());
+        let hits = hits.into_pyobject(py)?.into_any();
+        match py.import("numpy") {
+            Ok(np) => Ok(np.call_method1("asarray", (hits, "bool"))?.unbind()),
+            Err(_) => Ok(hits.unbind()),
+        }
+    }
+
+    /// Density of an arbitrary (0-based) vertex set.
+    fn density_of(&self, vertices: Vec<usize>) -> PyResult<f64> {
+        check_vertices(&self.inner, &vertices)?;
+        Ok(density_of(&self.inner, &vertices))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Graph(n={}, m={})", self.inner.n(), self.inner.m())
+    }
+}
+
+/// Outcome of one solver call, with its run statistics.
+#[pyclass(name = "RunResult", frozen)]
+struct PyRunResult {
+    graph:         Arc<Graph>,
+    #[pyo3(get)]
+    instance:      String,
+    #[pyo3(get)]
+    n:             usize,
+    #[pyo3(get)]
+    m:             usize,
+    /// Target size (fixed-k) or size reached (max-k).
+    #[pyo3(get)]
+    k:             usize,
+    #[pyo3(get)]
+    gamma:         f64,
+    #[pyo3(get)]
+    seed:          u64,
+    /// ChaCha stream the RNG ran on (0 unless a `(seed, stream)` was given).
+    #[pyo3(get)]
+    stream:        u64,
+    #[pyo3(get)]
+    density:       f64,
+    #[pyo3(get)]
+    size:          usize,
+    /// Vertices of the returned set (0-based, ascending).
+    #[pyo3(get)]
+    vertices:      Vec<usize>,
+    /// Intensification moves over all fixed-k searches.
+    #[pyo3(get)]
+    iterations:    usize,
+    /// Restarts over all fixed-k searches.
+    #[pyo3(get)]
+    restarts:      usize,
+    #[pyo3(get)]
+    seconds:       f64,
+    #[pyo3(get)]
+    stop_reason:   String,
+    /// The `time_limit` expired; the result is the best found until then.
+    #[pyo3(get)]
+    timed_out:     bool,
+    /// How many restart-best sets each vertex was in (the consensus of
+    /// the restarts; empty if none ended).
+    #[pyo3(get)]
+    participation: Vec<u32>,
+    /// Iterations and perturbations by type.
+    moves:         MoveStats,
+    /// Version, commit, params, seed and graph fingerprint of the run.
+    manifest:      Manifest,
+}
+
+impl PyRunResult {
+    fn new(
+        instance: String,
+        graph: &Arc<Graph>,
+        k: Option<usize>,
+        p: &Params,
+        (seed, stream): (u64, u64),
+        sol: &Solution<'_>,
+        counter: RunCounter,
+    ) -> Self {
+        Self {
+            graph: Arc::clone(graph),
+            instance,
+            n: graph.n(),
+            m: graph.m(),
+            k: k.unwrap_or(sol.size()),
+            gamma: p.gamma_target,
+            seed,
+            stream,
+            density: sol.density(),
+            size: sol.size(),
+            vertices: sol.bitset().iter_ones().collect(),
+            iterations: counter.iterations,
+            restarts: counter.restarts,
+            seconds: counter.seconds,
+            stop_reason: counter.reason.map_or("", |r| r.as_str()).to_string(),
+            timed_out: counter.timed_out,
+            participation: counter.participation,
+            moves: counter.moves,
+            manifest: Manifest::new(graph, p, seed, stream),
+        }
+    }
+
+    /// Mark a result read from the cache.
+    fn cached(mut self, hit: bool) -> Self {
+        if hit {
+            self.stop_reason = "cached".to_string();
+        }
+        self
+    }
+}
+
+#[pymethods]
+impl PyRunResult {
+    /// Flat dict of the scalar fields, one row for `pandas.DataFrame`.
+    fn stats<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, pyo3::types::PyDict>> {
+        let d = pyo3::types::PyDict::new(py);
+        d.set_item("instance", &self.instance)?;
+        d.set_item("n", self.n)?;
+        d.set_item("m", self.m)?;
+        d.set_item("k", self.k)?;
+        d.set_item("gamma", self.gamma)?;
+        d.set_item("seed", self.seed)?;
+        d.set_item("stream", self.stream)?;
+        d.set_item("density", self.density)?;
+        d.set_item("size", self.size)?;
+        d.set_item("iterations", self.iterations)?;
+        d.set_item("restarts", self.restarts)?;
+        d.set_item("seconds", self.seconds)?;
+        d.set_item("stop_reason", &self.stop_reason)?;
+        d.set_item("timed_out", self.timed_out)?;
+        for (name, count) in self.moves.entries() {
+            d.set_item(format!("moves_{name}"), count)?;
+        }
+        d.set_item("version", self.manifest.version)?;
+        d.set_item("git_hash", self.manifest.git_hash)?;
+        d.set_item("graph_hash", format!("{:016x}", self.manifest.graph.edge_hash))?;
+        Ok(d)
+    }
+
+    /// Accepted steps by type: `improving` / `sideways` / `worsening`
+    /// allowed swaps, `aspiration`, `idle`, and the `mild` / `heavy` /
+    /// `oscillate` perturbations.
+    #[getter]
+    fn moves<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, pyo3::types::PyDict>> {
+        let d = pyo3::types::PyDict::new(py);
+        for (name, count) in self.moves.entries() {
+            d.set_item(name, count)?;
+        }
+        Ok(d)
+    }
+
+    /// Reproducibility manifest as a JSON string: crate version, git
+    /// commit, full params, seed / stream and graph fingerprint.
+    #[getter]
+    fn manifest(&self) -> String {
+        self.manifest.to_json()
+    }
+
+    /// Induced subgraph of the found set, plus `map[i]` = original index
+    /// of its vertex `i`.
+    fn subgraph(&self) -> (PyGraph, Vec<usize>) {
+        let sub = self.graph.induced_subgraph(&self.vertices);
+        let sub = PyGraph { inner: Arc::new(sub), source: "<subgraph>".to_string() };
+        (sub, self.vertices.clone())
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "RunResult(instance={:?}, size={}, density={:.4}, stop_reason={:?})",
+            self.instance, self.size, self.density, self.stop_reason
+        )
+    }
+}
+
+/// `seed` argument of the `run_*` functions: one seed, a `(seed, stream)`
+/// pair selecting a non-overlapping ChaCha stream, or a list of seeds.
+#[derive(FromPyObject)]
+enum PySeed {
+    One(u64),
+    Stream((u64, u64)),
+    Many(Vec<u64>),
+}
+
+impl PySeed {
+    /// Every `(seed, stream)` to run.
+    fn streams(&self) -> Vec<(u64, u64)> {
+        match self {
+            PySeed::One(s) => vec![(*s, 0)],
+            PySeed::Stream(pair) => vec![*pair],
+            PySeed::Many(seeds) => seeds.iter().map(|&s| (s, 0)).collect(),
+        }
+    }
+
+    /// One result for a single seed / stream, a list for a list of seeds.
+    /// With `strict`, a timed-out run raises `TimeoutError` instead.
+    fn wrap(&self, py: Python<'_>, mut results: Vec<PyRunResult>, strict: bool) -> PyResult<PyObject> {
+        if let Some(r) = results.iter().find(|r| strict && r.timed_out) {
+            return Err(pyo3::exceptions::PyTimeoutError::new_err(format!(
+                "time limit reached after {:.3} s (best size {}, density {:.4})",
+                r.seconds, r.size, r.density
+            )));
+        }
+        Ok(match self {
+            PySeed::Many(_) => results.into_pyobject(py)?.into_any().unbind(),
+            _ => results.swap_remove(0).into_pyobject(py)?.into_any().unbind(),
+        })
+    }
+}
+
+/// One `run_*` call: the cached set when `cache` holds this instance
+/// (stop reason `"cached"`), else a fresh solve, stored unless timed out.
+fn run_cached<'g>(
+    cache: Option<&ResultCache>,
+    graph: &'g Graph,
+    p: &Params,
+    (seed, stream): (u64, u64),
+    k: Option<usize>,
+    mut counter: RunCounter,
+) -> (Solution<'g>, RunCounter, bool) {
+    let mut rng = SolverRng::with_stream(p.rng, seed, stream);
+    let mut solve = || {
+        let sol = match k {
+            Some(k) => solve_fixed_k_with(graph, k, &mut rng, p, &mut counter),
+            None => solve_maxk_with(graph, &mut rng, p, &mut counter),
+        };
+        let complete = !counter.timed_out;
+        (sol, complete)
+    };
+    // streams other than 0 are not part of the key
+    let (sol, hit) = match cache.filter(|_| stream == 0) {
+        Some(c) => c.get_or_solve(graph, instance_key(graph, p, seed, k), solve),
+        None => (solve().0, false),
+    };
+    (sol, counter, hit)
+}
+
+/// The cache of the `run_*` functions, if requested and a cache directory
+/// is known.
+fn py_cache(cache: bool) -> Option<ResultCache> {
+    cache.then(ResultCache::default_dir).flatten().map(ResultCache::new)
+}
+
+/// Fixed-k solver returning a [`RunResult`] (set, density and statistics);
+/// a list of seeds gives a list of results.  After `time_limit` seconds
+/// (per seed) the best-so-far set is returned with `timed_out=True`, or
+/// `TimeoutError` is raised if `strict`.  With `cache`, results are kept
+/// on disk (`$TSQC_CACHE_DIR` or the user cache directory) and a repeated
+/// call on the same instance, parameters and seed returns them at once.
+#[pyfunction]
+#[pyo3(signature = (graph_path, k, gamma, seed, time_limit=None, strict=false, cache=false))]
+#[pyo3(text_signature = "(graph_path, k, gamma, seed, time_limit=None, strict=False, cache=False)")]
+#[allow(clippy::too_many_arguments)]
+fn run_k_py(
+    py: Python<'_>,
+    graph_path: GraphArg<'_>,
+    k: usize,
+    gamma: f64,
+    seed: PySeed,
+    time_limit: Option<f64>,
+    strict: bool,
+    cache: bool,
+) -> PyResult<PyObject> {
+    let (graph, source) = graph_path.load()?;
+    check_k_gamma(&graph, k, gamma)?;
+    let p = gamma_params(gamma)?;
+    let cache = py_cache(cache);
+    let streams = seed.streams();
+    let results = py.allow_threads(|| {
+        streams
+            .into_iter()
+            .map(|s| {
+                let (sol, counter, hit) = run_cached(cache.as_ref(), &graph, &p, s, Some(k), run_counter(time_limit)?);
+                Ok(PyRunResult::new(source.clone(), &graph, Some(k), &p, s, &sol, counter).cached(hit))
+            })
+            .collect::<PyResult<_>>()
+    })?;
+    seed.wrap(py, results, strict)
+}
+
+/// Max-k solver returning a [`RunResult`] (set, density and statistics);
+/// a list of seeds gives a list of results.  `time_limit` / `strict` /
+/// `cache` as for `run_k_py`.
+#[pyfunction]
+#[pyo3(signature = (graph_path, gamma, seed, time_limit=None, strict=false, cache=false))]
+#[pyo3(text_signature = "(graph_path, gamma, seed, time_limit=None, strict=False, cache=False)")]
+fn run_max_py(
+    py: Python<'_>,
+    graph_path: GraphArg<'_>,
+    gamma: f64,
+    seed: PySeed,
+    time_limit: Option<f64>,
+    strict: bool,
+    cache: bool,
+) -> PyResult<PyObject> {
+    let (graph, source) = graph_path.load()?;
+    let p = gamma_params(gamma)?;
+    let cache = py_cache(cache);
+    let streams = seed.streams();
+    let results = py.allow_threads(|| {
+        streams
+            .into_iter()
+            .map(|s| {
+                let (sol, counter, hit) = run_cached(cache.as_ref(), &graph, &p, s, None, run_counter(time_limit)?);
+                Ok(PyRunResult::new(source.clone(), &graph, None, &p, s, &sol, counter).cached(hit))
+            })
+            .collect::<PyResult<_>>()
+    })?;
+    seed.wrap(py, results, strict)
+}
+
+/// Params with `gamma_target = gamma` and any overrides from `overrides`
+/// (field name → value; scalar fields, `rng` as `"chacha8"` / `"xoshiro"`,
+/// `budget` as a `(costs, limit)` pair and `penalties` as a list).
+fn params_from(gamma: f64, overrides: Option<&Bound<'_, pyo3::types::PyDict>>) -> PyResult<Params> {
+    let mut p = gamma_params(gamma)?;
+    apply_overrides(&mut p, overrides)?;
+    Ok(p)
+}
+
+/// Set the [`Params`] fields named in `overrides` (see [`params_from`]).
+fn apply_overrides(p: &mut Params, overrides: Option<&Bound<'_, pyo3::types::PyDict>>) -> PyResult<()> {
+    for (key, value) in overrides.into_iter().flat_map(|d| d.iter()) {
+        let key: String = key.extract()?;
+        match key.as_str() {
+            "tenure_u" => p.tenure_u = value.extract()?,
+            "tenure_v" => p.tenure_v = value.extract()?,
+            "stagnation_iter" => p.stagnation_iter = value.extract()?,
+            "max_iter" => p.max_iter = value.extract()?,
+            "seed_degree_percentile" => p.seed_degree_percentile = value.extract()?,
+            "tenure_delta_scale" => p.tenure_delta_scale = value.extract()?,
+            "construct_pool" => p.construct_pool = value.extract()?,
+            "plateau_budget" => p.plateau_budget = value.extract()?,
+            "double_swap" => p.double_swap = value.extract()?,
+            "track_frontier" => p.track_frontier = value.extract()?,
+            "oscillation_delta" => p.oscillation_delta = value.extract()?,
+            "max_heavy_swaps" => p.max_heavy_swaps = value.extract()?,
+            "complement_restarts" => p.complement_restarts = value.extract()?,
+            "freq_decay_interval" => p.freq_decay_interval = value.extract()?,
+            "u1_interval" => p.u1_interval = value.extract()?,
+            "warm_start" => p.warm_start = value.extract()?,
+            "carry_memory" => p.carry_memory = value.extract()?,
+            "maxk_lookahead" => p.maxk_lookahead = value.extract()?,
+            "time_limit" => p.time_limit = seconds("time_limit", value.extract()?)?,
+            "per_k_time_limit" => p.per_k_time_limit = seconds("per_k_time_limit", value.extract()?)?,
+            "clique_starts" => p.clique_starts = value.extract()?,
+            "clique_mode" => p.clique_mode = value.extract()?,
+            "min_degree_ratio" => p.min_degree_ratio = value.extract()?,
+            "negative_weight" => p.negative_weight = value.extract()?,
+            "rng" => {
+                p.rng = match value.extract::<String>()?.as_str() {
+                    "chacha8" => RngKind::ChaCha8,
+                    "xoshiro" => RngKind::Xoshiro,
+                    other => {
+                        return Err(pyo3::exceptions::PyValueError::new_err(format!("unknown rng '{other}'")));
+                    }
+                };
+            }
+            "threads" => p.threads = value.extract()?,
+            "penalties" => p.penalties = value.extract()?,
+            "budget" => {
+                let (costs, limit): (Vec<f64>, f64) = value.extract()?;
+                p.budget = Some(Budget { costs, limit });
+            }
+            _ => {
+                return Err(pyo3::exceptions::PyKeyError::new_err(format!("unknown parameter '{key}'")));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// A graph handle or a DIMACS path.
+#[derive(FromPyObject)]
+enum GraphArg<'py> {
+    Handle(PyRef<'py, PyGraph>),
+    Path(String),
+}
+
+impl GraphArg<'_> {
+    /// The graph (shared with the handle, or parsed now) and its source.
+    fn load(self) -> PyResult<(Arc<Graph>, String)> {
+        match self {
+            GraphArg::Handle(g) => Ok((Arc::clone(&g.inner), g.source.clone())),
+            GraphArg::Path(path) => Ok((Arc::new(load_dimacs(&path)?), path)),
+        }
+    }
+}
+
+/// Best sets a `Solver` keeps between calls.
+const SOLVER_ELITE: usize = 8;
+
+/// Persistent solver: parses the graph and builds the configuration once,
+/// and keeps one RNG across calls (reproducible from `seed`).
+///
+/// It also remembers its search: the frequency memory of the last
+/// fixed-k search and an elite pool of the best sets returned so far.
+/// The next call starts from them (a fixed-k search from the best set
+/// resized to k, max-k from the best set if it is still feasible), so
+/// `set_params` followed by another call continues where the last one
+/// stopped.
+#[pyclass(name = "Solver")]
+struct PySolver {
+    graph:  Arc<Graph>,
+    source: String,
+    params: Params,
+    seed:   u64,
+    rng:    SolverRng,
+    /// Frequency memory of the last fixed-k search (empty = fresh).
+    freq:   Vec<usize>,
+    /// Elite pool members, best first.
+    elite:  Vec<Vec<usize>>,
+}
+
+#[pymethods]
+impl PySolver {
+    /// `params` overrides scalar [`Params`] fields by name.
+    #[new]
+    #[pyo3(signature = (graph, gamma, seed=0, params=None))]
+    #[pyo3(text_signature = "(graph, gamma, seed=0, params=None)")]
+    fn new(
+        graph: GraphArg<'_>,
+        gamma: f64,
+        seed: u64,
+        params: Option<&Bound<'_, pyo3::types::PyDict>>,
+    ) -> PyResult<Self> {
+        let (graph, source) = graph.load()?;
+        let params = params_from(gamma, params)?;
+        check_params(&graph, &params)?;
+        let rng = SolverRng::seeded(params.rng, seed);
+        Ok(Self { graph, source, params, seed, rng, freq: Vec::new(), elite: Vec::new() })
+    }
+
+    /// Change the configuration between calls, keeping the RNG, the
+    /// frequency memory and the elite pool: `gamma` replaces γ and
+    /// `params` overrides fields by name as in the constructor (`rng`
+    /// only takes effect in a new solver).
+    #[pyo3(signature = (gamma=None, params=None))]
+    #[pyo3(text_signature = "(gamma=None, params=None)")]
+    fn set_params(&mut self, gamma: Option<f64>, params: Option<&Bound<'_, pyo3::types::PyDict>>) -> PyResult<()> {
+        let mut p = self.params.clone();
+        if let Some(gamma) = gamma {
+            p.gamma_target = gamma;
+        }
+        check_gamma(p.gamma_target)?;
+        apply_overrides(&mut p, params)?;
+        check_params(&self.graph, &p)?;
+        self.params = p;
+        Ok(())
+    }
+
+    #[getter]
+    fn gamma(&self) -> f64 {
+        self.params.gamma_target
+    }
+
+    /// Vertex sets of the elite pool, best (largest, then densest) first.
+    #[getter]
+    fn elite(&self) -> Vec<Vec<usize>> {
+        self.elite.clone()
+    }
+
+    /// Forget the frequency memory and the elite pool.
+    fn reset(&mut self) {
+        self.freq.clear();
+        self.elite.clear();
+    }
+
+    #[getter]
+    fn graph(&self) -> PyGraph {
+        PyGraph { inner: Arc::clone(&self.graph), source: self.source.clone() }
+    }
+
+    /// Best γ-quasi-clique of size `k`.
+    #[pyo3(signature = (k, time_limit=None))]
+    fn solve_k(&mut self, py: Python<'_>, k: usize, time_limit: Option<f64>) -> PyResult<PyRunResult> {
+        check_k_gamma(&self.graph, k, self.params.gamma_target)?;
+        let mut counter = run_counter(time_limit)?;
+        let graph = Arc::clone(&self.graph);
+        Ok(py.allow_threads(|| {
+            let start = elite_pool(&graph, &self.elite).take_best().map(|mut s| {
+                construct::resize_to_k(&mut s, k, &mut self.rng);
+                s
+            });
+            let sol = self.search(&graph, start, k, &mut counter);
+            self.result(Some(k), &sol, counter)
+        }))
+    }
+
+    /// Largest γ-quasi-clique found.
+    #[pyo3(signature = (time_limit=None))]
+    fn solve_max(&mut self, py: Python<'_>, time_limit: Option<f64>) -> PyResult<PyRunResult> {
+        let mut counter = run_counter(time_limit)?;
+        let graph = Arc::clone(&self.graph);
+        Ok(py.allow_threads(|| {
+            let prev = elite_pool(&graph, &self.elite).take_best();
+            let sol = maxk::maxk_from(&graph, prev, &mut self.rng, &self.params, &mut counter);
+            self.elite = remember(&graph, &self.elite, &sol);
+            self.result(None, &sol, counter)
+        }))
+    }
+
+    /// Fixed-k search (k = len(vertices)) starting from `vertices`.
+    #[pyo3(signature = (vertices, time_limit=None))]
+    fn refine(&mut self, py: Python<'_>, vertices: Vec<usize>, time_limit: Option<f64>) -> PyResult<PyRunResult> {
+        check_vertices(&self.graph, &vertices)?;
+        let graph = Arc::clone(&self.graph);
+        let mut start = Solution::new(&graph);
+        for &v in &vertices {
+            if !start.bitset()[v] {
+                start.add(v);
+            }
+        }
+        let k = start.size();
+        if k < 2 {
+            return Err(pyo3::exceptions::PyValueError::new_err("refine needs at least 2 distinct vertices"));
+        }
+        let mut counter = run_counter(time_limit)?;
+        Ok(py.allow_threads(|| {
+            let sol = self.search(&graph, Some(start), k, &mut counter);
+            self.result(Some(k), &sol, counter)
+        }))
+    }
+}
+
+/// The pool holding `elite` (vertex lists) on `graph`.
+fn elite_pool<'g>(graph: &'g Graph, elite: &[Vec<usize>]) -> SolutionPool<'g> {
+    let mut pool = SolutionPool::new(SOLVER_ELITE, 0);
+    for members in elite {
+        let mut sol = Solution::new(graph);
+        for &v in members {
+            sol.add(v);
+        }
+        pool.insert(&sol);
+    }
+    pool
+}
+
+/// `elite` with `sol` offered to its pool.
+fn remember(graph: &Graph, elite: &[Vec<usize>], sol: &Solution<'_>) -> Vec<Vec<usize>> {
+    let mut pool = elite_pool(graph, elite);
+    if sol.size() > 0 {
+        pool.insert(sol);
+    }
+    pool.into_sorted_vec().iter().map(|s| s.bitset().iter_ones().collect()).collect()
+}
+
+impl PySolver {
+    /// Fixed-k search from `start` (or a constructed set) on the kept
+    /// frequency memory; records the memory and the result.
+    fn search<'g>(
+        &mut self,
+        graph: &'g Graph,
+        start: Option<Solution<'g>>,
+        k: usize,
+        counter: &mut RunCounter,
+    ) -> Solution<'g> {
+        let mut carry = restart::Carry { start, freq: std::mem::take(&mut self.freq), tabu: None };
+        let sol = restart::search(graph, k, &mut self.rng, &self.params, counter, &mut Vec::new(), Some(&mut carry));
+        self.freq = carry.freq;
+        self.elite = remember(graph, &self.elite, &sol);
+        sol
+    }
+
+    fn result(&self, k: Option<usize>, sol: &Solution<'_>, counter: RunCounter) -> PyRunResult {
+        let source = self.source.clone();
+        PyRunResult::new(source, &self.graph, k, &self.params, (self.seed, 0), sol, counter)
+    }
+}
+
+/// Helper: parse DIMACS, return (n, m).
+#[pyfunction]
+#[pyo3(text_signature = "(graph_path)")]
+fn parse_dimacs_py(graph_path: GraphArg<'_>) -> PyResult<(usize, usize)> {
+    let (graph, _) = graph_path.load()?;
+    Ok((graph.n(), graph.m()))
+}
+
+/// Reject vertex indices outside `0..n` before handing them to Rust.
+fn check_vertices(graph: &Graph, vertices: &[usize]) -> PyResult<()> {
+    match vertices.iter().find(|&&v| v >= graph.n()) {
+        Some(v) => Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "vertex {v} out of range for graph with {} vertices", graph.n()
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Reject k outside `2..=n` and γ outside (0, 1] before a fixed-k solve.
+fn check_k_gamma(graph: &Graph, k: usize, gamma: f64) -> PyResult<()> {
+    if k < 2 || k > graph.n() {
+        let msg = format!("k must be in 2..={}, got {k}", graph.n());
+        return Err(pyo3::exceptions::PyValueError::new_err(msg));
+    }
+    check_gamma(gamma)
+}
+
+/// Reject γ outside (0, 1] (NaN included).
+fn check_gamma(gamma: f64) -> PyResult<()> {
+    if !(gamma > 0.0 && gamma <= 1.0) {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!("gamma must be in (0, 1], got {gamma}")));
+    }
+    Ok(())
+}
+
+/// Reject per-vertex [`Params`] data (budget costs, penalties) that does
+/// not fit `graph`.
+fn check_params(graph: &Graph, p: &Params) -> PyResult<()> {
+    p.check(graph.n()).map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+}
+
+/// Default [`Params`] at `gamma`, checked by [`check_gamma`].
+fn gamma_params(gamma: f64) -> PyResult<Params> {
+    check_gamma(gamma)?;
+    Ok(Params { gamma_target: gamma, ..Params::default() })
+}
+
+/// Density of an arbitrary (0-based) vertex set, without running a solver.
+#[pyfunction]
+#[pyo3(text_signature = "(graph_path, vertices)")]
+fn density_of_py(graph_path: GraphArg<'_>, vertices: Vec<usize>) -> PyResult<f64> {
+    let (graph, _) = graph_path.load()?;
+    check_vertices(&graph, &vertices)?;
+    Ok(density_of(&graph, &vertices))
+}
+
+/// Number of edges induced by an arbitrary (0-based) vertex set.
+#[pyfunction]
+#[pyo3(text_signature = "(graph_path, vertices)")]
+fn edges_of_py(graph_path: GraphArg<'_>, vertices: Vec<usize>) -> PyResult<usize> {
+    let (graph, _) = graph_path.load()?;
+    check_vertices(&graph, &vertices)?;
+    Ok(edges_of(&graph, &vertices))
+}
+
+/// Feasibility of an arbitrary (0-based) vertex set across thresholds:
+/// `(density, max_gamma, edges_needed)`, where `max_gamma` is the largest
+/// γ the set meets and `edges_needed` the edges missing for `gamma`
+/// (`None` without a target).
+#[pyfunction]
+#[pyo3(signature = (graph_path, vertices, gamma=None))]
+#[pyo3(text_signature = "(graph_path, vertices, gamma=None)")]
+fn feasibility_spectrum_py(graph_path: GraphArg<'_>, vertices: Vec<usize>, gamma: Option<f64>) -> PyResult<(f64, f64, Option<usize>)> {
+    gamma.map(check_gamma).transpose()?;
+    let (graph, _) = graph_path.load()?;
+    check_vertices(&graph, &vertices)?;
+    let s = feasibility_spectrum(&graph, &vertices);
+    Ok((s.density, s.max_gamma, gamma.map(|g| s.edges_needed(g))))
+}
+
+/// The fewest missing pairs inside a (0-based) vertex set whose addition
+/// makes its density reach `gamma`, as `(u, v)` with u < v, most common
+/// neighbours inside the set first.
+#[pyfunction]
+#[pyo3(signature = (graph_path, vertices, gamma))]
+#[pyo3(text_signature = "(graph_path, vertices, gamma)")]
+fn missing_links_py(graph_path: GraphArg<'_>, vertices: Vec<usize>, gamma: f64) -> PyResult<Vec<(usize, usize)>> {
+    check_gamma(gamma)?;
+    let (graph, _) = graph_path.load()?;
+    check_vertices(&graph, &vertices)?;
+    Ok(missing_links(&graph, &vertices, gamma))
+}
+
+/// Quick instance statistics: (n, m, density, estimated transitivity)
+/// from `samples` uniformly sampled edges.
+#[pyfunction]
+#[pyo3(signature = (graph_path, samples=10_000, seed=0))]
+#[pyo3(text_signature = "(graph_path, samples=10000, seed=0)")]
+fn estimate_stats_py(graph_path: GraphArg<'_>, samples: usize, seed: u64) -> PyResult<(usize, usize, f64, f64)> {
+    let (graph, _) = graph_path.load()?;
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let st = graph.estimate_stats(samples, &mut rng);
+    Ok((st.n, st.m, st.density, st.transitivity))
+}
+
+/// Helper: all vertex degrees of a DIMACS graph, ascending.
+#[pyfunction]
+#[pyo3(text_signature = "(graph_path)")]
+fn sorted_degrees_py(graph_path: GraphArg<'_>) -> PyResult<Vec<usize>> {
+    let (graph, _) = graph_path.load()?;
+    Ok(graph.sorted_degrees())
+}
+
+/// Helper: degree at each requested percentile (0–100, nearest rank).
+#[pyfunction]
+#[pyo3(text_signature = "(graph_path, percentiles)")]
+fn degree_percentiles_py(graph_path: GraphArg<'_>, percentiles: Vec<f64>) -> PyResult<Vec<usize>> {
+    let (graph, _) = graph_path.load()?;
+    let degs = graph.sorted_degrees();
+    Ok(percentiles.iter().map(|&p| graph::percentile_of_sorted(&degs, p)).collect())
+}
+
+/// Feasibility profile: max-k for every γ in `gammas`, largest first,
+/// each search warm-started from the previous one.  Returns `(γ, size)`
+/// pairs in descending γ; `budget` caps the moves of each fixed-k search.
+#[pyfunction]
+#[pyo3(signature = (graph_path, gammas, budget=None, seed=0))]
+#[pyo3(text_signature = "(graph_path, gammas, budget=None, seed=0)")]
+fn gamma_profile_py(
+    py: Python<'_>,
+    graph_path: GraphArg<'_>,
+    gammas: Vec<f64>,
+    budget: Option<usize>,
+    seed: u64,
+) -> PyResult<Vec<(f64, usize)>> {
+    for &g in &gammas {
+        check_gamma(g)?;
+    }
+    let (graph, _) = graph_path.load()?;
+    let mut p = Params::default();
+    if let Some(b) = budget {
+        p.max_iter = b;
+    }
+    let mut rng = SolverRng::seeded(p.rng, seed);
+    Ok(py.allow_threads(|| {
+        gamma_profile(&graph, &gammas, &mut rng, &p).iter().map(|(g, s)| (*g, s.size())).collect()
+    }))
+}
+
+/// Densest set found for each size of `sizes` (in that order) by one
+/// shared search; `budget` moves are split over the sizes.  Sizes
+/// outside 2..=n give empty solutions.
+#[pyfunction]
+#[pyo3(signature = (graph_path, sizes, budget, seed=0))]
+#[pyo3(text_signature = "(graph_path, sizes, budget, seed=0)")]
+fn densest_for_sizes_py(
+    py: Python<'_>,
+    graph_path: GraphArg<'_>,
+    sizes: Vec<usize>,
+    budget: usize,
+    seed: u64,
+) -> PyResult<Vec<PySolution>> {
+    let (graph, _) = graph_path.load()?;
+    let p = Params::default();
+    let mut rng = SolverRng::seeded(p.rng, seed);
+    Ok(py.allow_threads(|| {
+        densest_for_sizes(&graph, &sizes, budget, &mut rng, &p).iter().map(PySolution::from).collect()
+    }))
+}
+
+/// Growth curve of a seed set: `(size, density)` after every one-vertex
+/// step, up to and including the first step that stays below γ (see
+/// `grow_profile`); `budget` caps the moves of each repair search.
+#[pyfunction]
+#[pyo3(signature = (graph_path, seeds, gamma, budget=None, seed=0))]
+#[pyo3(text_signature = "(graph_path, seeds, gamma, budget=None, seed=0)")]
+fn grow_profile_py(
+    py: Python<'_>,
+    graph_path: GraphArg<'_>,
+    seeds: Vec<usize>,
+    gamma: f64,
+    budget: Option<usize>,
+    seed: u64,
+) -> PyResult<Vec<(usize, f64)>> {
+    let (graph, _) = graph_path.load()?;
+    if seeds.is_empty() {
+        return Err(pyo3::exceptions::PyValueError::new_err("seeds must not be empty"));
+    }
+    check_vertices(&graph, &seeds)?;
+    let mut p = gamma_params(gamma)?;
+    if let Some(b) = budget {
+        p.max_iter = b;
+    }
+    let mut rng = SolverRng::seeded(p.rng, seed);
+    Ok(py.allow_threads(|| {
+        grow_profile(&graph, &seeds, &mut rng, &p).iter().map(|s| (s.size, s.density)).collect()
+    }))
+}
+
+/// Max-k within distance `radius` of each center, solved in parallel:
+/// one `(vertices, density)` per center, in order.
+#[pyfunction]
+#[pyo3(signature = (graph_path, centers, radius, gamma, seed=0))]
+#[pyo3(text_signature = "(graph_path, centers, radius, gamma, seed=0)")]
+fn solve_ego_py(
+    py: Python<'_>,
+    graph_path: GraphArg<'_>,
+    centers: Vec<usize>,
+    radius: usize,
+    gamma: f64,
+    seed: u64,
+) -> PyResult<Vec<(Vec<usize>, f64)>> {
+    let (graph, _) = graph_path.load()?;
+    if let Some(&c) = centers.iter().find(|&&c| c >= graph.n()) {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!("center {c} out of range (n = {})", graph.n())));
+    }
+    let p = gamma_params(gamma)?;
+    Ok(py.allow_threads(|| {
+        solve_ego(&graph, &centers, radius, seed, &p)
+            .iter()
+            .map(|s| (s.bitset().iter_ones().collect(), s.density()))
+            .collect()
+    }))
+}
+
+/// Per-vertex features from up to `budget` rounds of sequential
+/// γ-quasi-clique extraction: `(sizes, densities)` of the set each vertex
+/// was extracted with (0 and NaN for vertices in none), as NumPy arrays
+/// when NumPy is installed, otherwise as lists.
+#[pyfunction]
+#[pyo3(signature = (graph_path, gamma, budget, seed=0))]
+#[pyo3(text_signature = "(graph_path, gamma, budget, seed=0)")]
+fn membership_scores_py(
+    py: Python<'_>,
+    graph_path: GraphArg<'_>,
+    gamma: f64,
+    budget: usize,
+    seed: u64,
+) -> PyResult<(PyObject, PyObject)> {
+    let (graph, _) = graph_path.load()?;
+    let p = gamma_params(gamma)?;
+    let mut rng = SolverRng::seeded(p.rng, seed);
+    let scores = py.allow_threads(|| membership_scores(&graph, budget, &mut rng, &p));
+    let sizes: Vec<usize> = scores.iter().map(|m| m.map_or(0, |m| m.size)).collect();
+    let densities: Vec<f64> = scores.iter().map(|m| m.map_or(f64::NAN, |m| m.density)).collect();
+    let (sizes, densities) = (sizes.into_pyobject(py)?.into_any(), densities.into_pyobject(py)?.into_any());
+    match py.import("numpy") {
+        Ok(np) => {
+            let array = |xs| np.call_method1("asarray", (xs,)).map(Bound::unbind);
+            Ok((array(sizes)?, array(densities)?))
+        }
+        Err(_) => Ok((sizes.unbind(), densities.unbind())),
+    }
+}
+
+/// Max-k on a DIMACS file too large to load: the graph is streamed from
+/// disk and only the subgraph induced by at most `max_candidates`
+/// high-degree, peeled vertices is built.  Returns `(vertices, density)`
+/// in original ids; the density is that of the built subgraph.  A `Graph`
+/// handle is already in memory and is solved whole.
+#[pyfunction]
+#[pyo3(signature = (graph_path, gamma, max_candidates, seed=0))]
+#[pyo3(text_signature = "(graph_path, gamma, max_candidates, seed=0)")]
+fn solve_max_streaming_py(
+    py: Python<'_>,
+    graph_path: GraphArg<'_>,
+    gamma: f64,
+    max_candidates: usize,
+    seed: u64,
+) -> PyResult<(Vec<usize>, f64)> {
+    let p = gamma_params(gamma)?;
+    let graph_path = match graph_path {
+        GraphArg::Path(path) => path,
+        GraphArg::Handle(g) => {
+            let graph = Arc::clone(&g.inner);
+            return Ok(py.allow_threads(|| {
+                let sol = solve_maxk(&graph, &mut SolverRng::seeded(p.rng, seed), &p);
+                (sol.bitset().iter_ones().collect(), sol.density())
+            }));
+        }
+    };
+    py.allow_threads(|| {
+        let core = stream_core(&graph_path, max_candidates, stream::PEEL_PASSES).map_err(|e| match e.kind() {
+            std::io::ErrorKind::OutOfMemory => pyo3::exceptions::PyMemoryError::new_err(e.to_string()),
+            std::io::ErrorKind::NotFound => pyo3::exceptions::PyIOError::new_err(e.to_string()),
+            _ => pyo3::exceptions::PyValueError::new_err(e.to_string()),
+        })?;
+        if core.graph.n() < 2 {
+            return Ok((core.map, 0.0));
+        }
+        let mut rng = SolverRng::seeded(p.rng, seed);
+        let sol = solve_maxk(&core.graph, &mut rng, &p);
+        Ok((sol.bitset().iter_ones().map(|v| core.map[v]).collect(), sol.density()))
+    })
+}
+
+/// Size the pool used by the parallel features (0 = one thread per
+/// physical core).
+#[pyfunction]
+#[pyo3(text_signature = "(n)")]
+fn set_threads_py(n: usize) -> PyResult<()> {
+    set_threads(n).map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))
+}
+
+/// Cap the adjacency matrix a graph may allocate, in bytes (`None` = no
+/// cap); larger inputs raise `MemoryError` instead of aborting.
+#[pyfunction]
+#[pyo3(signature = (limit=None))]
+#[pyo3(text_signature = "(limit=None)")]
+fn set_memory_limit_py(limit: Option<usize>) {
+    set_memory_limit(limit);
+}
+
+/*======================================================================
+│  PyO3 module-init
+└=====================================================================*/
+
+/// ***Important***: name `_native` must match `pyproject.toml -> module-name`.
+#[pymodule]
+fn _native(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(solve_k_py, m)?)?;
+    m.add_function(wrap_pyfunction!(solve_max_py, m)?)?;
+    m.add_function(wrap_pyfunction!(solve_clique_py, m)?)?;
+    m.add_function(wrap_pyfunction!(solve_k_dense_py, m)?)?;
+    m.add_function(wrap_pyfunction!(solve_max_dense_py, m)?)?;
+    m.add_function(wrap_pyfunction!(solve_max_files, m)?)?;
+    m.add_function(wrap_pyfunction!(run_k_py, m)?)?;
+    m.add_function(wrap_pyfunction!(run_max_py, m)?)?;
+    m.add_class::<PyRunResult>()?;
+    m.add_class::<PySolution>()?;
+    m.add_class::<PyGraph>()?;
+    m.add_class::<PySolver>()?;
+    m.add_function(wrap_pyfunction!(parse_dimacs_py, m)?)?;
+    m.add_function(wrap_pyfunction!(sorted_degrees_py, m)?)?;
+    m.add_function(wrap_pyfunction!(degree_percentiles_py, m)?)?;
+    m.add_function(wrap_pyfunction!(density_of_py, m)?)?;
+    m.add_function(wrap_pyfunction!(edges_of_py, m)?)?;
+    m.add_function(wrap_pyfunction!(feasibility_spectrum_py, m)?)?;
+    m.add_function(wrap_pyfunction!(missing_links_py, m)?)?;
+    m.add_function(wrap_pyfunction!(estimate_stats_py, m)?)?;
+    m.add_function(wrap_pyfunction!(gamma_profile_py, m)?)?;
+    m.add_function(wrap_pyfunction!(densest_for_sizes_py, m)?)?;
+    m.add_function(wrap_pyfunction!(grow_profile_py, m)?)?;
+    m.add_function(wrap_pyfunction!(solve_ego_py, m)?)?;
+    m.add_function(wrap_pyfunction!(membership_scores_py, m)?)?;
+    m.add_function(wrap_pyfunction!(solve_max_streaming_py, m)?)?;
+    m.add_function(wrap_pyfunction!(set_threads_py, m)?)?;
+    m.add_function(wrap_pyfunction!(set_memory_limit_py, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,249 @@
+// src/realtime.rs
+//! Soft real-time stepping for interactive use (e.g. a render loop that
+//! solves a little between frames).
+//!
+//! A [`Stepper`] runs the fixed-k tabu search one move per
+//! [`Stepper::step`] call and keeps each call within a latency budget by
+//! bounding the candidate scan: outsiders are sampled (`Scan::Sampled`),
+//! and the sample size adapts to the measured step time —
+//!
+//! • halved when a step took more than half the budget,
+//! • grown by 1/8 when it took less than a quarter.
+//!
+//! The depth-2 lookahead is off and stagnation triggers a random swap
+//! instead of the scanning perturbations, so no step touches all
+//! outsiders.  The bound is soft: a step still pays for the frontier
+//! updates of the two swapped vertices, and [`Stepper::new`] (the greedy
+//! start) is not bounded.  Steps over budget are counted
+//! ([`Stepper::overruns`]).
+
+use crate::{
+    construct::greedy_random_k,
+    neighbour::{improve_once_with, MoveKind},
+    params::{Params, Scan},
+    snapshot::{At, Snapshot},
+    solution::Solution,
+    tabu::DualTabu,
+    Graph,
+};
+use rand::Rng;
+use std::time::{Duration, Instant};
+
+/// Outsiders sampled by the first step.
+const INITIAL_SAMPLE: usize = 64;
+
+/// Fixed-k search advanced one bounded-latency move at a time.
+pub struct Stepper<'g, R> {
+    cur:        Solution<'g>,
+    /// Members and edge count of the best set so far (kept as a list so
+    /// that recording it costs O(k), not a copy of the solution).
+    best:       Vec<usize>,
+    best_edges: usize,
+    best_ok:    bool,
+    tabu:       DualTabu,
+    freq:       Vec<usize>,
+    rng:        R,
+    p:          Params,
+    latency:    Duration,
+    sample:     usize,
+    stagnation: usize,
+    limit:      usize,
+    moves:      usize,
+    overruns:   usize,
+}
+
+impl<'g, R: Rng> Stepper<'g, R> {
+    /// Search for a γ-quasi-clique (per `p`) of size `k` whose steps each
+    /// aim to return within `latency`.  `p.scan` and `p.double_swap` are
+    /// overridden; `p.max_iter` is not enforced (the caller decides when
+    /// to stop stepping).
+    ///
+    /// Panics unless 1 ≤ k ≤ n.
+    pub fn new(graph: &'g Graph, k: usize, mut rng: R, p: &Params, latency: Duration) -> Self {
+        assert!(k >= 1 && k <= graph.n(), "k must be in 1..=n");
+        let p = Params { scan: Scan::Sampled(INITIAL_SAMPLE), double_swap: false, ..p.clone() };
+        let mut cur = greedy_random_k(graph, k, &mut rng);
+        cur.track_frontier();
+        let mut tabu = DualTabu::new(graph.n(), p.tenure_u, p.tenure_v).with_schedule(p.tenure_schedule);
+        tabu.update_tenures(k, cur.edges(), p.gamma_target, &mut rng);
+        Self {
+            best: cur.bitset().iter_ones().collect(),
+            best_edges: cur.edges(),
+            best_ok: p.accepts(&cur),
+            limit: p.stagnation_limit(graph.n(), k, None),
+            freq: vec![0; graph.n()],
+            cur,
+            tabu,
+            rng,
+            p,
+            latency,
+            sample: INITIAL_SAMPLE,
+            stagnation: 0,
+            moves: 0,
+            overruns: 0,
+        }
+    }
+
+    /// One move: a swap from the sampled critical sets, or a random swap
+    /// after `limit` steps without improvement.  Does nothing once a
+    /// feasible set was found.
+    pub fn step(&mut self) -> MoveKind {
+        if self.best_ok {
+            return MoveKind::Idle;
+        }
+        let started = Instant::now();
+        let kind = if self.stagnation >= self.limit {
+            self.stagnation = 0;
+            self.shake();
+            MoveKind::Heavy
+        } else {
+            let rho = self.best_density();
+            improve_once_with(
+                &mut self.cur,
+                &mut self.tabu,
+                rho,
+                &mut self.freq,
+                &self.p,
+                Scan::Sampled(self.sample),
+                &mut self.rng,
+            )
+        };
+        self.moves += 1;
+
+        let ok = self.p.accepts(&self.cur);
+        if self.cur.edges() > self.best_edges || ok {
+            self.best.clear();
+            self.best.extend(self.cur.bitset().iter_ones());
+            self.best_edges = self.cur.edges();
+            self.best_ok = ok;
+            self.stagnation = 0;
+        } else {
+            self.stagnation += 1;
+        }
+
+        let took = started.elapsed();
+        if took > self.latency {
+            self.overruns += 1;
+        }
+        if took * 2 > self.latency {
+            self.sample = (self.sample / 2).max(1);
+        } else if took * 4 < self.latency {
+            self.sample = (self.sample + self.sample / 8 + 1).min(self.cur.graph().n());
+        }
+        kind
+    }
+
+    /// Swap a random member for a random outsider and reset the tabu lists.
+    fn shake(&mut self) {
+        let (sol, rng) = (&mut self.cur, &mut self.rng);
+        let n = sol.graph().n();
+        if sol.size() == n {
+            return;
+        }
+        let u = sol.bitset().iter_ones().nth(rng.gen_range(0..sol.size())).unwrap();
+        let v = loop {
+            let v = rng.gen_range(0..n);
+            if !sol.bitset()[v] {
+                break v;
+            }
+        };
+        sol.remove(u);
+        sol.add(v);
+        self.freq[u] = self.freq[u].saturating_add(1);
+        self.freq[v] = self.freq[v].saturating_add(1);
+        self.tabu.reset();
+        self.tabu.update_tenures(sol.size(), sol.edges(), self.p.gamma_target, rng);
+    }
+
+    fn best_density(&self) -> f64 {
+        let k = self.best.len();
+        if k < 2 { 0.0 } else { 2.0 * self.best_edges as f64 / (k * (k - 1)) as f64 }
+    }
+
+    /// The best set so far (densest, or the first one meeting every
+    /// constraint); built on each call.
+    pub fn best(&self) -> Solution<'g> {
+        let mut sol = Solution::new(self.cur.graph());
+        for &v in &self.best {
+            sol.add(v);
+        }
+        sol
+    }
+
+    /// The search state now (restart index 0, `best` as [`Stepper::best`]).
+    pub fn snapshot(&self) -> Snapshot {
+        let at = At { k: self.cur.size(), iter: self.moves, restart: 0, stagnation: self.stagnation };
+        Snapshot::capture(at, &self.cur, &self.best(), &self.tabu, &self.freq)
+    }
+
+    /// The set the search is at.
+    pub fn current(&self) -> &Solution<'g> {
+        &self.cur
+    }
+
+    /// `true` once the best set meets the target (further steps are no-ops).
+    pub fn is_feasible(&self) -> bool {
+        self.best_ok
+    }
+
+    /// Steps taken.
+    pub fn moves(&self) -> usize {
+        self.moves
+    }
+
+    /// Steps that took longer than the budget.
+    pub fn overruns(&self) -> usize {
+        self.overruns
+    }
+
+    /// Outsiders the next step samples.
+    pub fn sample_size(&self) -> usize {
+        self.sample
+    }
+
+    pub fn latency(&self) -> Duration {
+        self.latency
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testgraphs::clique_edges;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    /// K8 on 0..8 planted in a G(200, 0.05).
+    fn planted() -> Graph {
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        let edges: Vec<_> = clique_edges(0..200).into_iter().filter(|&(_, v)| v < 8 || rng.gen_bool(0.05)).collect();
+        Graph::from_edge_list(200, &edges)
+    }
+
+    #[test]
+    fn steps_reach_the_planted_clique() {
+        let g = planted();
+        let p = Params { gamma_target: 1.0, ..Params::default() };
+        let mut s = Stepper::new(&g, 8, ChaCha8Rng::seed_from_u64(3), &p, Duration::from_millis(5));
+        while !s.is_feasible() && s.moves() < 100_000 {
+            s.step();
+        }
+        assert!(s.is_feasible(), "{} moves", s.moves());
+        assert_eq!(s.best().bitset().iter_ones().collect::<Vec<_>>(), (0..8).collect::<Vec<_>>());
+        assert_eq!(s.step(), MoveKind::Idle);
+        let snap = s.snapshot();
+        assert_eq!((snap.iter, snap.best.len()), (s.moves(), 8));
+    }
+
+    #[test]
+    fn tight_budget_shrinks_the_sample() {
+        let g = planted();
+        let p = Params { gamma_target: 1.0, ..Params::default() };
+        let mut s = Stepper::new(&g, 8, ChaCha8Rng::seed_from_u64(3), &p, Duration::ZERO);
+        for _ in 0..10 {
+            s.step();
+        }
+        assert_eq!(s.sample_size(), 1);
+        assert_eq!(s.overruns(), s.moves());
+    }
+}
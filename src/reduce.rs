@@ -0,0 +1,203 @@
+// src/reduce.rs
+//! Safe reductions before a max-k search, with an audit trail.
+//!
+//! Given a feasible set of size L (the lower bound), only sets of more
+//! than L vertices matter.  A member v of such a set S (|S| = k) has
+//!
+//! ```text
+//! deg_S(v) ≥ ⌈γ·C(k,2)⌉ − C(k−1,2)     (S − v holds at most C(k−1,2) edges)
+//! deg_S(v) ≥ ⌈θ(k−1)⌉                  (the degree floor, θ = p.min_degree_ratio)
+//! ```
+//!
+//! so with t = the smallest of these bounds over L < k ≤ n, two rules
+//! never remove a vertex of any feasible set larger than L:
+//!
+//! • [`Rule::Degree`] peels vertices of degree < t (the t-core);
+//! • [`Rule::Component`] drops connected components of at most L
+//!   vertices, applied only when the density bound alone is ≥ 1 (then
+//!   m(S) > C(k−1,2), so every such S is connected).
+//!
+//! Both rules repeat until neither removes anything (n shrinks, so t can
+//! only grow).  [`Reduction`] keeps the reduced graph, the original id of
+//! each of its vertices and what every rule removed.
+
+use crate::{
+    graph::Graph,
+    params::Params,
+    solution::{required_degree, required_edges},
+};
+use std::collections::VecDeque;
+
+/// A reduction rule.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rule {
+    /// Vertex of degree below the threshold.
+    Degree,
+    /// Connected component too small to beat the lower bound.
+    Component,
+}
+
+impl Rule {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Rule::Degree    => "degree",
+            Rule::Component => "component",
+        }
+    }
+}
+
+/// What one rule removed, over all rounds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RuleStats {
+    pub rule:     Rule,
+    pub vertices: usize,
+    /// Edges of the graph left when their first endpoint went.
+    pub edges:    usize,
+}
+
+/// The audit part of a [`Reduction`] (no graph), e.g. for run reports.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReductionReport {
+    /// L: size of the feasible set the reduction was safe against.
+    pub lower_bound: usize,
+    /// Final degree threshold t (the vertex count once no larger set
+    /// can exist).
+    pub threshold:   usize,
+    /// One entry per rule, in [`Rule`] order.
+    pub rules:       Vec<RuleStats>,
+    /// Original id of each kept vertex, ascending.
+    pub map:         Vec<usize>,
+}
+
+/// A reduced graph and how it was obtained.
+#[derive(Clone, Debug)]
+pub struct Reduction {
+    pub graph:  Graph,
+    pub report: ReductionReport,
+}
+
+/// Degree bound t for sets of L < k ≤ n vertices (see the module docs),
+/// and whether the density part alone is ≥ 1 for all of them.  `None`
+/// when no such k exists.
+fn threshold(lower_bound: usize, n: usize, p: &Params) -> Option<(usize, bool)> {
+    ((lower_bound + 1).max(2)..=n)
+        .map(|k| {
+            let rest = (k - 1) * (k - 2) / 2;
+            let density = required_edges(k, p.gamma_target).saturating_sub(rest);
+            (density.max(required_degree(k, p.min_degree_ratio)), density >= 1)
+        })
+        .reduce(|(t, c), (t2, c2)| (t.min(t2), c && c2))
+}
+
+/// Reduce `graph` against a known feasible set of `lower_bound` vertices
+/// under the constraints of `p` (density γ, degree floor θ).
+pub fn reduce(graph: &Graph, lower_bound: usize, p: &Params) -> Reduction {
+    let n = graph.n();
+    let mut alive = vec![true; n];
+    let mut left = n;
+    let mut deg: Vec<usize> = (0..n).map(|v| graph.degree(v)).collect();
+    let mut stats = [Rule::Degree, Rule::Component].map(|rule| RuleStats { rule, vertices: 0, edges: 0 });
+    let mut last_t = 0;
+
+    // drop v, counting it and its remaining edges for `rule`
+    let mut remove = |v: usize, rule: usize, alive: &mut [bool], deg: &mut [usize], left: &mut usize| {
+        alive[v] = false;
+        *left -= 1;
+        stats[rule].vertices += 1;
+        stats[rule].edges += deg[v];
+        for u in graph.neighbors(v) {
+            if alive[u] {
+                deg[u] -= 1;
+            }
+        }
+    };
+
+    loop {
+        let before = left;
+        let (t, connected) = threshold(lower_bound, left, p).unwrap_or((usize::MAX, true));
+        last_t = last_t.max(t.min(left));
+
+        // degree rule: peel to the t-core
+        let mut queue: VecDeque<usize> = (0..n).filter(|&v| alive[v] && deg[v] < t).collect();
+        while let Some(v) = queue.pop_front() {
+            if !alive[v] {
+                continue;
+            }
+            remove(v, 0, &mut alive, &mut deg, &mut left);
+            for u in graph.neighbors(v) {
+                if alive[u] && deg[u] + 1 == t {
+                    queue.push_back(u);
+                }
+            }
+        }
+
+        // component rule: components of at most L vertices
+        if connected {
+            let mut seen = vec![false; n];
+            for s in 0..n {
+                if !alive[s] || seen[s] {
+                    continue;
+                }
+                seen[s] = true;
+                let mut comp = vec![s];
+                let mut i = 0;
+                while i < comp.len() {
+                    for u in graph.neighbors(comp[i]) {
+                        if alive[u] && !seen[u] {
+                            seen[u] = true;
+                            comp.push(u);
+                        }
+                    }
+                    i += 1;
+                }
+                if comp.len() <= lower_bound {
+                    for v in comp {
+                        remove(v, 1, &mut alive, &mut deg, &mut left);
+                    }
+                }
+            }
+        }
+
+        if left == before {
+            break;
+        }
+    }
+
+    let map: Vec<usize> = (0..n).filter(|&v| alive[v]).collect();
+    Reduction {
+        graph:  graph.induced_subgraph(&map),
+        report: ReductionReport { lower_bound, threshold: last_t, rules: stats.to_vec(), map },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testgraphs::clique_edges;
+
+    #[test]
+    fn peels_tails_and_small_components() {
+        // K6 {0..6} with a pendant 11 on 0, and a separate K5 {6..11}
+        let mut edges = [clique_edges(0..6), clique_edges(6..11)].concat();
+        edges.push((0, 11));
+        let g = Graph::from_edge_list(12, &edges);
+        let p = Params { gamma_target: 0.9, ..Params::default() };
+
+        // against a K5: a 6-set at γ = 0.9 needs 14 of 15 edges, t = 4
+        let r = reduce(&g, 5, &p);
+        assert_eq!(r.report.map, (0..6).collect::<Vec<_>>());
+        assert_eq!(r.graph.m(), 15);
+        assert_eq!(r.report.threshold, 4);
+        assert_eq!(r.report.rules[0], RuleStats { rule: Rule::Degree, vertices: 1, edges: 1 });
+        assert_eq!(r.report.rules[1], RuleStats { rule: Rule::Component, vertices: 5, edges: 10 });
+
+        // at γ = 0.5 a triangle plus an isolated vertex is feasible:
+        // no degree bound, and sets need not be connected
+        let r = reduce(&g, 5, &Params { gamma_target: 0.5, ..p.clone() });
+        assert_eq!(r.report.map.len(), 12);
+        assert_eq!(r.report.threshold, 0);
+
+        // nothing beats a set of all vertices
+        assert!(reduce(&g, 12, &p).report.map.is_empty());
+    }
+}
@@ -2,24 +2,58 @@
 //! Multi-start Tabu Search for fixed-k γ-quasi-clique (TSQC Alg. 1 & 2).
 //! Implements:
 //!  1) Greedy-random initialisation (§ 3.3).
-//!  2) Intensification via `improve_once` (§ 3.4.1).
-//!  3) Tight one-swap UB stopping (“U1-tight”, § 3.4.3).
+//!  2) Intensification via `improve_once` (§ 3.4.1), scanning outsiders
+//!     per `p.scan` (resolved over the solve by a `ScanSelector`).
+//!  3) Tight one-swap UB stopping (“U1-tight”, § 3.4.3), every
+//!     `p.u1_interval` moves, recomputed only when the run best changes.
 //!  4) Adaptive heavy/mild diversification (§ 3.4.2).
 //!  5) Restart strategy with long-term frequency memory (§ 3.5).
 //!  6) Global cap on total moves (`p.max_iter`).
+//!  7) Early return of the best-so-far set when `Observer::should_stop` fires
+//!     or the wall-clock limit `p.time_limit` expires.
+//!  8) Optional side constraints: a minimum-degree floor
+//!     (`p.min_degree_ratio`, every member has ⌈θ(k−1)⌉ internal
+//!     neighbours) and a vertex-cost budget (`p.budget`, starts are fitted
+//!     to it); a set only counts as found once it is dense enough *and*
+//!     meets them.
+//!  9) With `p.clique_mode` at γ = 1 (and no given start), the clique
+//!     search of `clique` instead (restart bests are not collected).
+//! 10) With feature `cross-check`, U1 cuts and results on small graphs are
+//!     verified by brute force (see `crosscheck`).
+//!
+//! [`solve_fixed_k_with`] additionally reports every step to an [`Observer`]
+//! (and state snapshots on request);
+//! [`solve_fixed_k_all`] also returns the best set of each restart,
+//! [`solve_fixed_k_from`] starts from a given set, and
+//! [`solve_fixed_k_parallel`] runs independent searches on the thread pool.
 
 use crate::{
-    construct::greedy_random_k,
-    diversify::{heavy_perturbation, mild_perturbation},
-    neighbour::improve_once,
+    clique::fixed_k_clique,
+    construct::{construct_parallel, fit_budget, greedy_from_seed, greedy_from_seed_avoiding},
+    diversify::{decay_frequencies, heavy_perturbation_n, mild_perturbation, oscillate},
+    neighbour::{improve_once_with, MoveKind, ScanSelector},
+    observer::{IterRecord, NoObserver, Observer, RestartRecord, StopReason},
     params::Params,
-    solution::Solution,
+    pool::SolutionPool,
+    snapshot::{At, Snapshot},
+    solution::{required_edges, Solution},
     tabu::DualTabu,
     Graph,
 };
+use bitvec::prelude::*;
 use rand::seq::SliceRandom;
 use rand::Rng;
+use rayon::prelude::*;
+use std::collections::HashSet;
 use std::f64;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::Instant;
+
+/// Fruitless diversifications after which a run whose best set is dense
+/// enough but misses a side constraint (degree floor, budget) is abandoned
+/// (the U1 bound never ends such a run).
+const SIDE_STALL_LIMIT: usize = 8;
 
 /// Solve the fixed-k γ-quasi-clique problem on `graph`, returning the best
 /// γ-quasi-clique of size `k` found (or an empty solution if none feasible).
@@ -32,127 +66,399 @@ pub fn solve_fixed_k<'g, R>(
 where
     R: Rng + ?Sized,
 {
+    solve_fixed_k_with(graph, k, rng, p, &mut NoObserver)
+}
+
+/// Best run-best set over the workers of [`solve_fixed_k_parallel`]:
+/// (feasible, edges, members), compared in that order.
+type SharedBest = Mutex<(bool, usize, Vec<usize>)>;
+
+/// One parallel worker: merges its run bests into the shared best and
+/// stops once any worker found a feasible set.
+struct Worker<'a> {
+    p:     &'a Params,
+    best:  &'a SharedBest,
+    found: &'a AtomicBool,
+}
+
+impl Observer for Worker<'_> {
+    fn on_run_best(&mut self, sol: &Solution<'_>) {
+        let ok = self.p.accepts(sol);
+        let mut best = self.best.lock().unwrap();
+        if (ok, sol.edges()) > (best.0, best.1) || best.2.is_empty() {
+            *best = (ok, sol.edges(), sol.bitset().iter_ones().collect());
+        }
+        if ok {
+            self.found.store(true, Ordering::Relaxed);
+        }
+    }
+
+    fn should_stop(&mut self) -> bool {
+        self.found.load(Ordering::Relaxed)
+    }
+}
+
+/// [`solve_fixed_k`] as `starts` independent multi-start searches on the
+/// pool for `p.threads` (see [`crate::threads`]), search i drawing from
+/// stream i of `seed`.  Every restart's best set is merged into one shared
+/// best, and all searches stop as soon as one finds a feasible set.  Each
+/// search has its own `p.max_iter`.
+///
+/// Which worker gets there first depends on scheduling, so unlike the
+/// sequential solver the result is not reproducible from `seed` alone.
+pub fn solve_fixed_k_parallel<'g>(graph: &'g Graph, k: usize, starts: usize, seed: u64, p: &Params) -> Solution<'g> {
+    let best: SharedBest = Mutex::new((false, 0, Vec::new()));
+    let found = AtomicBool::new(false);
+    crate::threads::install(p.threads, || {
+        (0..starts.max(1) as u64).into_par_iter().for_each(|i| {
+            let mut rng = crate::rng::SolverRng::with_stream(p.rng, seed, i);
+            let mut worker = Worker { p, best: &best, found: &found };
+            search(graph, k, &mut rng, p, &mut worker, &mut Vec::new(), None);
+        });
+    });
+    let mut sol = Solution::new(graph);
+    for v in best.into_inner().unwrap().2 {
+        sol.add(v);
+    }
+    sol
+}
+
+/// U1 bound of `sol`: m(S) + max(0, max_out − min_in), the most edges a
+/// single swap can reach.  O(n) (one pass over members and outsiders).
+fn u1_bound(sol: &Solution<'_>) -> usize {
+    let batch = sol.batch_degrees();
+    let deg = |v: usize| batch.as_ref().map_or_else(|| sol.deg_in(v), |d| d[v] as usize);
+    let min_in = sol.bitset().iter_ones().map(deg).min().unwrap_or(usize::MAX);
+    let max_out = sol.outsiders_to_scan().map(deg).max().unwrap_or(0);
+    sol.edges() + max_out.saturating_sub(min_in)
+}
+
+/// Mean moves per improvement, once there is one.
+fn mean_gap(moves: usize, improvements: usize) -> Option<f64> {
+    (improvements > 0).then(|| moves as f64 / improvements as f64)
+}
+
+/// Forward one step to `obs` (only called when `obs.wants_iters()`);
+/// `before` is m(S) before the step.
+fn emit_iter<O: Observer + ?Sized>(
+    obs: &mut O,
+    k: usize,
+    iter: usize,
+    sol: &Solution<'_>,
+    tabu: &DualTabu,
+    kind: MoveKind,
+    before: usize,
+) {
+    let (tu, tv) = tabu.tenures();
+    let (tabu_u, tabu_v) = tabu.active_counts();
+    obs.on_iter(&IterRecord {
+        k,
+        iter,
+        size: sol.size(),
+        edges: sol.edges(),
+        delta: sol.edges() as isize - before as isize,
+        density: sol.density(),
+        kind,
+        tu,
+        tv,
+        tabu_u,
+        tabu_v,
+    });
+}
+
+/// [`solve_fixed_k`] reporting every move and perturbation to `obs`.
+pub fn solve_fixed_k_with<'g, R, O>(
+    graph: &'g Graph,
+    k: usize,
+    rng: &mut R,
+    p: &Params,
+    obs: &mut O,
+) -> Solution<'g>
+where
+    R: Rng + ?Sized,
+    O: Observer + ?Sized,
+{
+    search(graph, k, rng, p, obs, &mut Vec::new(), None)
+}
+
+/// [`solve_fixed_k`] that also returns the run-best solution of the first
+/// `p.restart_bests` restarts, in restart order (the incumbent is one of
+/// them unless it came from a later restart).
+pub fn solve_fixed_k_all<'g, R>(
+    graph: &'g Graph,
+    k: usize,
+    rng: &mut R,
+    p: &Params,
+) -> (Solution<'g>, Vec<Solution<'g>>)
+where
+    R: Rng + ?Sized,
+{
+    let mut bests = Vec::with_capacity(p.restart_bests.min(64));
+    let best = search(graph, k, rng, p, &mut NoObserver, &mut bests, None);
+    (best, bests)
+}
+
+/// [`solve_fixed_k_with`] whose first run starts from `start` (k = its size)
+/// instead of a constructed set, e.g. to refine a user-supplied set.
+pub fn solve_fixed_k_from<'g, R, O>(
+    start: Solution<'g>,
+    rng: &mut R,
+    p: &Params,
+    obs: &mut O,
+) -> Solution<'g>
+where
+    R: Rng + ?Sized,
+    O: Observer + ?Sized,
+{
+    let (graph, k) = (start.graph(), start.size());
+    let mut carry = Carry { start: Some(start), ..Carry::default() };
+    search(graph, k, rng, p, obs, &mut Vec::new(), Some(&mut carry))
+}
+
+/// State handed from one fixed-k search to the next (max-k warm starts).
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Carry<'g> {
+    /// First-run start used instead of the configured constructor.
+    pub start: Option<Solution<'g>>,
+    /// Long-term frequency memory (empty = fresh).
+    pub freq:  Vec<usize>,
+    /// Tabu lists of the last run (None = fresh).
+    pub tabu:  Option<DualTabu>,
+}
+
+/// The restart loop; run-best sets are pushed to `bests` up to the cap.
+/// With `carry`, its start and memory are consumed and the final
+/// frequency memory and tabu lists are written back.
+///
+/// Panics if the per-vertex data in `p` does not fit `graph` (see
+/// [`Params::check`]); the fallible entry points reject it first.
+pub(crate) fn search<'g, R, O>(
+    graph: &'g Graph,
+    k: usize,
+    rng: &mut R,
+    p: &Params,
+    obs: &mut O,
+    bests: &mut Vec<Solution<'g>>,
+    mut carry: Option<&mut Carry<'g>>,
+) -> Solution<'g>
+where
+    R: Rng + ?Sized,
+    O: Observer + ?Sized,
+{
+    if let Err(e) = p.check(graph.n()) {
+        panic!("{e}");
+    }
     // 0) Precompute required edges for feasibility: ceil(γ·C(k,2))
-    let needed_edges = ((p.gamma_target * ((k * (k - 1) / 2) as f64)).ceil()) as usize;
+    let needed_edges = required_edges(k, p.gamma_target);
     // Quick impossibility check
     if (k * (k - 1) / 2) < needed_edges {
+        obs.on_finish(StopReason::Infeasible);
         return Solution::new(graph);
     }
+    if p.uses_clique_mode() && carry.as_ref().is_none_or(|c| c.start.is_none()) {
+        return fixed_k_clique(graph, k, rng, p, obs);
+    }
 
     // Long-term frequency memory for restarts
-    let mut freq = vec![0usize; graph.n()];
+    let mut freq = match carry.as_deref_mut() {
+        Some(c) if c.freq.len() == graph.n() => std::mem::take(&mut c.freq),
+        _ => vec![0usize; graph.n()],
+    };
+    // Outsider scan mode, re-decided as the search goes under Scan::Auto
+    let mut scan = ScanSelector::new(graph.n(), p);
     // Global best solution over all restarts (for aspiration & final return)
     let mut best_global = Solution::new(graph);
     let mut best_global_rho = 0.0;
     // Total moves across all restarts
     let mut total_moves = 0usize;
+    let mut restart = 0usize;
+    // Run-best improvements and the moves they took (adaptive L)
+    let mut improvements = 0usize;
+    let mut improvement_moves = 0usize;
+    let mut last_improvement;
+    // Union of all run-best sets (for complement-biased restarts)
+    let mut coverage = bitvec![0; graph.n()];
+    // Spare first-run starts, consumed by the next restarts
+    let mut elite = SolutionPool::new(p.construct_pool.saturating_sub(1), 0);
+    // Set once the observer asks to stop or the time limit expires
+    let mut stopped = false;
+    let deadline = p.time_limit.and_then(|t| Instant::now().checked_add(t));
 
     // Outer restart loop
-    while total_moves < p.max_iter {
+    while total_moves < p.max_iter && !stopped {
         // 1) INITIAL SOLUTION
-        let mut cur = if best_global.size() == 0 {
-            // First run: pure greedy-random
-            greedy_random_k(graph, k, rng)
+        let (seed, mut cur) = if let Some(s) = carry.as_deref_mut().and_then(|c| c.start.take()) {
+            // Warm start handed over by the caller (first run only)
+            (s.bitset().first_one().unwrap_or(0), s)
+        } else if best_global.size() == 0 {
+            // First run: configured constructor(s); spare starts go to the pool
+            let mut starts = construct_parallel(graph, k, p, p.construct_pool.max(1), rng);
+            for (_, s) in &starts[1..] {
+                elite.insert(s);
+            }
+            starts.swap_remove(0)
+        } else if let Some(s) = elite.take_best() {
+            // Restart from the best unused spare start
+            (s.bitset().first_one().unwrap_or(0), s)
+        } else if p.complement_restarts && coverage.not_all() {
+            // Restart: least-used uncovered seed, grown outside the coverage
+            let min_f = coverage.iter_zeros().map(|v| freq[v]).min().unwrap();
+            let mut pool: Vec<usize> =
+                coverage.iter_zeros().filter(|&v| freq[v] == min_f).collect();
+            pool.shuffle(rng);
+            (pool[0], greedy_from_seed_avoiding(graph, pool[0], k, &coverage, rng))
         } else {
             // Restart: seed from least-used vertex + greedy fill (§ 3.5)
             let min_f = *freq.iter().min().unwrap();
             let mut pool: Vec<usize> =
                 (0..graph.n()).filter(|&v| freq[v] == min_f).collect();
             pool.shuffle(rng);
-            let seed = pool[0];
-
-            let mut s = Solution::new(graph);
-            s.add(seed);
-            while s.size() < k {
-                // pick outsider with max internal edges
-                let mut best_deg = 0;
-                let mut cand = Vec::new();
-                for v in 0..graph.n() {
-                    if s.bitset()[v] { continue; }
-                    let deg = graph
-                        .neigh_row(v)
-                        .iter_ones()
-                        .filter(|&u| s.bitset()[u])
-                        .count();
-                    match deg.cmp(&best_deg) {
-                        std::cmp::Ordering::Greater => {
-                            best_deg = deg;
-                            cand.clear();
-                            cand.push(v);
-                        }
-                        std::cmp::Ordering::Equal => cand.push(v),
-                        _ => {}
-                    }
-                }
-                s.add(*cand.choose(rng).unwrap());
-            }
-            s
+            (pool[0], greedy_from_seed(graph, pool[0], k, rng))
         };
+        if let Some(b) = &p.budget {
+            fit_budget(&mut cur, b);
+        }
+        if p.track_frontier || scan.tracks_frontier() {
+            cur.track_frontier();
+        } else {
+            // every move reads deg_in of all outsiders
+            cur.track_degrees();
+        }
+        let start_rho = cur.density();
+        let run_start_moves = total_moves;
+        last_improvement = total_moves;
+        let mut perturbations = 0usize;
 
         // 2) INITIALISE TABU STRUCTURE and one initial tenure adaptation
-        let mut tabu = DualTabu::new(graph.n(), p.tenure_u, p.tenure_v);
+        let mut tabu = carry.as_deref_mut().and_then(|c| c.tabu.take()).unwrap_or_else(|| {
+            DualTabu::new(graph.n(), p.tenure_u, p.tenure_v).with_schedule(p.tenure_schedule)
+        });
         tabu.update_tenures(cur.size(), cur.edges(), p.gamma_target, rng);
 
         // Track best in this run
         let mut best_run = cur.clone();
         let mut rho_run = cur.density();
         let mut stagnation = 0usize;
+        // Sideways (equal-density) moves allowed before they count as
+        // stagnation; revisiting a set on the plateau always counts.
+        let mut plateau_left = p.plateau_budget;
+        let mut plateau_seen: HashSet<u64> = HashSet::new();
+        // Diversifications since the last run-best improvement
+        let mut failed_diversifications = 0usize;
+        // U1 bound of best_run (None once best_run changed)
+        let mut u1 = None;
 
         // 3) LOCAL SEARCH LOOP until stagnation or global cap
-        while stagnation < p.stagnation_iter && total_moves < p.max_iter {
+        let mut limit = p.stagnation_limit(graph.n(), k, mean_gap(improvement_moves, improvements));
+        while stagnation < limit && total_moves < p.max_iter {
+            if obs.should_stop() || deadline.is_some_and(|d| Instant::now() >= d) {
+                stopped = true;
+                break;
+            }
             // Intensification step (§ 3.4.1)
-            let _moved = improve_once(
+            let rho_before = cur.density();
+            let edges_before = cur.edges();
+            let kind = improve_once_with(
                 &mut cur,
                 &mut tabu,
                 best_global_rho,
                 &mut freq,
                 p,
+                scan.mode(),
                 rng,
             );
+            scan.observe(&cur);
             total_moves += 1;
+            if p.freq_decay_interval > 0 && total_moves.is_multiple_of(p.freq_decay_interval) {
+                decay_frequencies(&mut freq);
+            }
+            if obs.wants_iters() {
+                emit_iter(obs, k, total_moves, &cur, &tabu, kind, edges_before);
+            }
+            if obs.wants_snapshot(total_moves) {
+                let at = At { k, iter: total_moves, restart, stagnation };
+                obs.on_snapshot(&Snapshot::capture(at, &cur, &best_run, &tabu, &freq));
+            }
 
             // Update run-best
             let rho = cur.density();
             if rho > rho_run {
                 rho_run = rho;
                 best_run = cur.clone();
+                u1 = None;
+                improvements += 1;
+                improvement_moves += total_moves - last_improvement;
+                last_improvement = total_moves;
+                limit = p.stagnation_limit(graph.n(), k, mean_gap(improvement_moves, improvements));
                 stagnation = 0;
+                plateau_left = p.plateau_budget;
+                plateau_seen.clear();
+                failed_diversifications = 0;
+                if rho_run > best_global_rho {
+                    obs.on_incumbent(&best_run);
+                }
+            } else if kind.is_move()
+                && rho == rho_before
+                && plateau_left > 0
+                && plateau_seen.insert(cur.zobrist())
+            {
+                plateau_left -= 1;
             } else {
                 stagnation += 1;
             }
 
-            // If feasible, return immediately
-            if rho_run + f64::EPSILON >= p.gamma_target {
+            // If feasible, return immediately; under side constraints the
+            // current set may qualify while the densest one does not
+            let found = if p.has_side_constraints() {
+                if !p.accepts(&best_run) && p.accepts(&cur) {
+                    best_run = cur.clone();
+                    rho_run = rho;
+                    u1 = None;
+                }
+                p.accepts(&best_run)
+            } else {
+                rho_run + f64::EPSILON >= p.gamma_target
+            };
+            if found {
+                obs.on_restart(&RestartRecord {
+                    k,
+                    restart,
+                    seed_vertex: seed,
+                    start_density: start_rho,
+                    best_density: rho_run,
+                    iterations: total_moves - run_start_moves,
+                    perturbations,
+                });
+                obs.on_run_best(&best_run);
+                if bests.len() < p.restart_bests {
+                    bests.push(best_run.clone());
+                }
+                if let Some(c) = carry {
+                    c.freq = freq;
+                    c.tabu = Some(tabu);
+                }
+                #[cfg(feature = "cross-check")]
+                crate::crosscheck::result(graph, k, needed_edges, &best_run);
+                obs.on_finish(StopReason::Feasible);
                 return best_run;
             }
 
-            // 3a) U1-tight stopping (§ 3.4.3)
-            let mut min_in = usize::MAX;
-            for u in best_run.bitset().iter_ones() {
-                let d = graph
-                    .neigh_row(u)
-                    .iter_ones()
-                    .filter(|&j| best_run.bitset()[j])
-                    .count();
-                min_in = min_in.min(d);
-            }
-            let mut max_out = 0;
-            for v in 0..graph.n() {
-                if best_run.bitset()[v] { continue; }
-                let d = graph
-                    .neigh_row(v)
-                    .iter_ones()
-                    .filter(|&j| best_run.bitset()[j])
-                    .count();
-                max_out = max_out.max(d);
+            // 3a) U1-tight stopping (§ 3.4.3), cached while best_run stands
+            let due = p.u1_interval > 0 && (total_moves - run_start_moves).is_multiple_of(p.u1_interval);
+            if due && *u1.get_or_insert_with(|| u1_bound(&best_run)) < needed_edges {
+                #[cfg(feature = "cross-check")]
+                crate::crosscheck::u1_cut(&best_run, needed_edges);
+                break;
             }
-            let ub = best_run.edges() + max_out.saturating_sub(min_in);
-            if ub < needed_edges {
+            if p.has_side_constraints()
+                && rho_run + f64::EPSILON >= p.gamma_target
+                && failed_diversifications >= SIDE_STALL_LIMIT
+            {
                 break;
             }
 
             // 3b) Diversification if stagnated (§ 3.4.2)
-            if stagnation >= p.stagnation_iter {
+            if stagnation >= limit {
                 // Compute heavy-shake probability
                 let max_edges = k * (k - 1) / 2;
                 let deficit = if cur.edges() < needed_edges {
@@ -163,10 +469,22 @@ where
                 };
                 let p_heavy = (deficit + 2.0 / (k as f64)).min(1.0);
 
-                if rng.gen_bool(p_heavy) {
-                    heavy_perturbation(&mut cur, &mut tabu, rng, p, &mut freq);
+                let edges_before = cur.edges();
+                let kind = if rng.gen_bool(p_heavy) {
+                    let swaps = (1 + failed_diversifications).min(p.max_heavy_swaps.max(1));
+                    heavy_perturbation_n(&mut cur, &mut tabu, rng, p, &mut freq, swaps);
+                    MoveKind::Heavy
+                } else if p.oscillation_delta > 0 {
+                    oscillate(&mut cur, &mut tabu, rng, p, &mut freq);
+                    MoveKind::Oscillate
                 } else {
                     mild_perturbation(&mut cur, &mut tabu, rng, p, &mut freq);
+                    MoveKind::Mild
+                };
+                perturbations += 1;
+                failed_diversifications += 1;
+                if obs.wants_iters() {
+                    emit_iter(obs, k, total_moves, &cur, &tabu, kind, edges_before);
                 }
 
                 // reset stagnation
@@ -174,6 +492,26 @@ where
             }
         }
 
+        obs.on_restart(&RestartRecord {
+            k,
+            restart,
+            seed_vertex: seed,
+            start_density: start_rho,
+            best_density: rho_run,
+            iterations: total_moves - run_start_moves,
+            perturbations,
+        });
+        obs.on_run_best(&best_run);
+        restart += 1;
+
+        coverage |= best_run.bitset();
+        if bests.len() < p.restart_bests {
+            bests.push(best_run.clone());
+        }
+        if let Some(c) = carry.as_deref_mut() {
+            c.tabu = Some(tabu);
+        }
+
         // 4) Update global best if run-best improved
         if rho_run > best_global_rho {
             best_global_rho = rho_run;
@@ -181,6 +519,12 @@ where
         }
     }
 
+    if let Some(c) = carry {
+        c.freq = freq;
+    }
+    #[cfg(feature = "cross-check")]
+    crate::crosscheck::result(graph, k, needed_edges, &best_global);
+    obs.on_finish(if stopped { StopReason::Interrupted } else { StopReason::MaxIter });
     // Return overall best found
     best_global
 }
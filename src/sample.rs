@@ -0,0 +1,205 @@
+// src/sample.rs
+//! Sampling utilities for approximate instance statistics.
+//!
+//! • `Graph::sample_edges` – uniform edges (with replacement) via
+//!   degree-proportional vertex choice + uniform neighbour.
+//! • `Graph::estimate_stats` – n, m, density and an edge-sampled estimate
+//!   of the transitivity (global clustering coefficient).  Only degrees
+//!   are read in full (one popcount per row); no edge scan.
+//! • `Graph::sample_induced` – random or BFS-ball induced subsample.
+//! • `pilot_params` – tune `stagnation_iter` on a subsample before the
+//!   full run.
+
+use crate::{graph::Graph, maxk::solve_maxk, params::Params};
+use rand::seq::{IteratorRandom, SliceRandom};
+use rand::Rng;
+use std::collections::VecDeque;
+
+/// How [`Graph::sample_induced`] picks its vertices.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SampleMode {
+    /// Uniformly random vertex subset.
+    Uniform,
+    /// BFS ball around a random centre (restarting from a fresh random
+    /// centre if the component is exhausted); keeps local density intact.
+    Ball,
+}
+
+/// Cheap characterization of an instance.
+#[derive(Clone, Debug)]
+pub struct InstanceStats {
+    pub n:            usize,
+    pub m:            usize,
+    /// 2m / (n(n−1)), exact (derived from the degree sum).
+    pub density:      f64,
+    /// 3·triangles / wedges, estimated from sampled edges.
+    pub transitivity: f64,
+    /// Number of edges the estimate is based on.
+    pub samples:      usize,
+}
+
+impl Graph {
+    /// `k` edges drawn uniformly at random (with replacement), each as
+    /// `(u, v)` with `u < v`.  Empty if the graph has no edges.
+    pub fn sample_edges<R>(&self, k: usize, rng: &mut R) -> Vec<(usize, usize)>
+    where
+        R: Rng + ?Sized,
+    {
+        // cumulative degrees: P(u) = deg(u) / 2m, then a uniform neighbour
+        let mut cum = Vec::with_capacity(self.n());
+        let mut total = 0usize;
+        for v in 0..self.n() {
+            total += self.degree(v);
+            cum.push(total);
+        }
+        if total == 0 {
+            return Vec::new();
+        }
+
+        let mut out = Vec::with_capacity(k);
+        for _ in 0..k {
+            let r = rng.gen_range(0..total);
+            let u = cum.partition_point(|&c| c <= r);
+            let d = self.degree(u);
+            let w = self.neighbors(u).nth(rng.gen_range(0..d)).unwrap();
+            out.push((u.min(w), u.max(w)));
+        }
+        out
+    }
+
+    /// Induced subgraph on `n_sample` vertices chosen by `mode`.
+    /// Returns the subgraph and `map[i]` = original index of new vertex `i`.
+    pub fn sample_induced<R>(
+        &self,
+        n_sample: usize,
+        mode: SampleMode,
+        rng: &mut R,
+    ) -> (Graph, Vec<usize>)
+    where
+        R: Rng + ?Sized,
+    {
+        let n_sample = n_sample.min(self.n());
+        let mut verts = match mode {
+            SampleMode::Uniform => (0..self.n()).choose_multiple(rng, n_sample),
+            SampleMode::Ball => {
+                let mut seen = vec![false; self.n()];
+                let mut out = Vec::with_capacity(n_sample);
+                let mut queue = VecDeque::new();
+                while out.len() < n_sample {
+                    if queue.is_empty() {
+                        let c = (0..self.n()).filter(|&v| !seen[v]).choose(rng).unwrap();
+                        seen[c] = true;
+                        queue.push_back(c);
+                    }
+                    let v = queue.pop_front().unwrap();
+                    out.push(v);
+                    let mut next: Vec<usize> = self.neighbors(v).filter(|&w| !seen[w]).collect();
+                    next.shuffle(rng);
+                    for w in next {
+                        seen[w] = true;
+                        queue.push_back(w);
+                    }
+                }
+                out
+            }
+        };
+        verts.sort_unstable();
+        (self.induced_subgraph(&verts), verts)
+    }
+
+    /// Estimate [`InstanceStats`] from `samples` uniformly sampled edges.
+    ///
+    /// Triangles are estimated as `m/3 · E[common_neighbours(u,v)]`, wedges
+    /// are exact (Σ C(deg, 2)).
+    pub fn estimate_stats<R>(&self, samples: usize, rng: &mut R) -> InstanceStats
+    where
+        R: Rng + ?Sized,
+    {
+        let n = self.n();
+        let mut deg_sum = 0usize;
+        let mut wedges = 0f64;
+        for v in 0..n {
+            let d = self.degree(v);
+            deg_sum += d;
+            wedges += (d * d.saturating_sub(1)) as f64 / 2.0;
+        }
+        let m = deg_sum / 2;
+        let density = if n < 2 { 0.0 } else { deg_sum as f64 / (n * (n - 1)) as f64 };
+
+        let edges = self.sample_edges(samples, rng);
+        let transitivity = if edges.is_empty() || wedges == 0.0 {
+            0.0
+        } else {
+            let cn: usize = edges.iter().map(|&(u, v)| self.common_neighbor_count(u, v)).sum();
+            let mean_cn = cn as f64 / edges.len() as f64;
+            // 3T / W with T ≈ m · mean_cn / 3
+            (m as f64 * mean_cn / wedges).min(1.0)
+        };
+
+        InstanceStats { n, m, density, transitivity, samples: edges.len() }
+    }
+}
+
+/// Pilot run: try each `stagnation_iter` in `candidates` with max-k on an
+/// `n_sample`-vertex BFS-ball subsample and return `base` with the value
+/// that reached the largest quasi-clique (ties → the smaller value).
+pub fn pilot_params<R>(
+    graph: &Graph,
+    base: &Params,
+    n_sample: usize,
+    candidates: &[usize],
+    rng: &mut R,
+) -> Params
+where
+    R: Rng + ?Sized,
+{
+    let (sub, _) = graph.sample_induced(n_sample, SampleMode::Ball, rng);
+    let mut best = (0usize, base.stagnation_iter);
+    let mut sorted = candidates.to_vec();
+    sorted.sort_unstable();
+    for (i, &l) in sorted.iter().enumerate() {
+        let p = Params { stagnation_iter: l, ..base.clone() };
+        let size = solve_maxk(&sub, rng, &p).size();
+        if i == 0 || size > best.0 {
+            best = (size, l);
+        }
+    }
+    Params { stagnation_iter: best.1, ..base.clone() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testgraphs::clique_edges;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn complete_graph_stats() {
+        let g = Graph::from_edge_list(6, &clique_edges(0..6));
+        let mut rng = ChaCha8Rng::seed_from_u64(5);
+
+        for (u, v) in g.sample_edges(50, &mut rng) {
+            assert!(u < v && g.has_edge(u, v));
+        }
+        let st = g.estimate_stats(100, &mut rng);
+        assert_eq!(st.m, 15);
+        assert!((st.density - 1.0).abs() < 1e-12);
+        assert!((st.transitivity - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn ball_sample_is_induced() {
+        // path 0-1-2-3-4-5
+        let g = Graph::from_edge_list(6, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5)]);
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        let (sub, map) = g.sample_induced(3, SampleMode::Ball, &mut rng);
+        assert_eq!(sub.n(), 3);
+        assert_eq!(map.len(), 3);
+        // a BFS ball of 3 on a path is itself a path: 2 edges
+        assert_eq!(sub.m(), 2);
+        for (a, b) in sub.edge_list() {
+            assert!(g.has_edge(map[a], map[b]));
+        }
+    }
+}
@@ -0,0 +1,267 @@
+// src/setsearch.rs
+//! Multi-start tabu search on an additive set score, shared by the signed
+//! ([`crate::signed`]), directed ([`crate::directed`]) and edge-weighted
+//! ([`crate::weighted`]) variants.
+//!
+//! A [`ScoredSet`] exposes f(S) together with the per-vertex weight w(v)
+//! (the change of f when v joins S) and the pair term of {u, v}, so the
+//! swap u → v changes f by w(v) − w(u) − pair(u, v).
+//!
+//! • Construction: least-used seed (§ 3.5) grown by highest weight.
+//! • Step: best non-tabu, non-deteriorating swap between the lowest-weight
+//!   members and the highest-weight outsiders (random tie-break).
+//! • Stagnation (`p.stagnation_iter`) triggers a random shake; after
+//!   `SHAKES_PER_RUN` of them the run restarts.
+//! • Tenures follow the deficit target − f(S) in the score's own unit;
+//!   `p.time_limit` bounds a whole solve (max-k: over all k).
+
+use crate::{
+    observer::{Observer, StopReason},
+    params::Params,
+    solution::Solution,
+    tabu::DualTabu,
+};
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::time::Instant;
+
+/// Stagnation periods (each followed by a shake) per run.
+const SHAKES_PER_RUN: usize = 4;
+
+/// Weights within this of each other count as equal (real-valued scores
+/// pick up rounding error as vertices come and go).
+const EPS: f64 = 1e-9;
+
+/// Vertex set with an additive score.
+pub(crate) trait ScoredSet<'g>: Clone {
+    fn n(&self) -> usize;
+    fn contains(&self, v: usize) -> bool;
+    fn size(&self) -> usize;
+    fn add(&mut self, v: usize);
+    fn remove(&mut self, v: usize);
+    /// f(S).
+    fn score(&self) -> f64;
+    /// Score v contributes with respect to S \ {v}.
+    fn weight(&self, v: usize) -> f64;
+    /// Score of the pair {u, v}.
+    fn pair(&self, u: usize, v: usize) -> f64;
+    /// The members, as an unsigned / undirected solution.
+    fn solution(&self) -> &Solution<'g>;
+}
+
+fn construct<'g, S, R>(empty: &S, k: usize, freq: &[usize], rng: &mut R) -> S
+where
+    S: ScoredSet<'g>,
+    R: Rng + ?Sized,
+{
+    let mut sol = empty.clone();
+    let min_f = *freq.iter().min().unwrap();
+    let seeds: Vec<usize> = (0..sol.n()).filter(|&v| freq[v] == min_f).collect();
+    sol.add(*seeds.choose(rng).unwrap());
+    let mut ties = Vec::new();
+    while sol.size() < k {
+        let mut best = f64::NEG_INFINITY;
+        ties.clear();
+        for v in (0..sol.n()).filter(|&v| !sol.contains(v)) {
+            let w = sol.weight(v);
+            if w > best {
+                best = w;
+                ties.clear();
+            }
+            if w == best {
+                ties.push(v);
+            }
+        }
+        sol.add(*ties.choose(rng).unwrap());
+    }
+    sol
+}
+
+/// Non-tabu vertices inside (`members`) or outside S of extreme weight:
+/// lowest for members, highest for outsiders.
+fn critical<'g, S: ScoredSet<'g>>(sol: &S, members: bool, allowed: impl Fn(usize) -> bool) -> Vec<usize> {
+    let cand: Vec<(usize, f64)> = (0..sol.n())
+        .filter(|&v| sol.contains(v) == members && allowed(v))
+        .map(|v| (v, sol.weight(v)))
+        .collect();
+    let pick = if members { f64::min } else { f64::max };
+    let Some(target) = cand.iter().map(|c| c.1).reduce(pick) else { return Vec::new() };
+    cand.into_iter().filter(|c| (c.1 - target).abs() <= EPS).map(|c| c.0).collect()
+}
+
+fn improve_once<'g, S, R>(sol: &mut S, tabu: &mut DualTabu, freq: &mut [usize], rng: &mut R)
+where
+    S: ScoredSet<'g>,
+    R: Rng + ?Sized,
+{
+    let a_set = critical(sol, true, |u| !tabu.is_tabu_u(u));
+    let b_set = critical(sol, false, |v| !tabu.is_tabu_v(v));
+
+    let mut best: Option<(f64, usize, usize)> = None;
+    let mut ties = 0u32;
+    for &u in &a_set {
+        for &v in &b_set {
+            let d = sol.weight(v) - sol.weight(u) - sol.pair(u, v);
+            if d < -EPS {
+                continue;
+            }
+            match best {
+                Some((bd, _, _)) if d < bd - EPS => {}
+                Some((bd, _, _)) if d <= bd + EPS => {
+                    // reservoir tie-break
+                    ties += 1;
+                    if rng.gen_range(0..ties) == 0 {
+                        best = Some((d, u, v));
+                    }
+                }
+                _ => {
+                    best = Some((d, u, v));
+                    ties = 1;
+                }
+            }
+        }
+    }
+    if let Some((_, u, v)) = best {
+        sol.remove(u);
+        sol.add(v);
+        freq[u] = freq[u].saturating_add(1);
+        freq[v] = freq[v].saturating_add(1);
+        tabu.forbid_u(u);
+        tabu.forbid_v(v);
+    }
+}
+
+/// Swap a random member for a random outsider, both made tabu.
+fn shake<'g, S, R>(sol: &mut S, tabu: &mut DualTabu, rng: &mut R)
+where
+    S: ScoredSet<'g>,
+    R: Rng + ?Sized,
+{
+    let ins: Vec<usize> = (0..sol.n()).filter(|&v| sol.contains(v)).collect();
+    let outs: Vec<usize> = (0..sol.n()).filter(|&v| !sol.contains(v)).collect();
+    if let (Some(&u), Some(&v)) = (ins.choose(rng), outs.choose(rng)) {
+        sol.remove(u);
+        sol.add(v);
+        tabu.forbid_u(u);
+        tabu.forbid_v(v);
+    }
+}
+
+/// Best k-set by score, returning as soon as f(S) ≥ `target`.  `empty` is
+/// the empty set; k must be in 2..=n.
+pub(crate) fn solve_k<'g, S, R, O>(empty: &S, k: usize, target: f64, rng: &mut R, p: &Params, obs: &mut O) -> S
+where
+    S: ScoredSet<'g>,
+    R: Rng + ?Sized,
+    O: Observer + ?Sized,
+{
+    let deadline = p.time_limit.and_then(|t| Instant::now().checked_add(t));
+    search(empty, k, target, rng, p, obs, deadline)
+}
+
+/// [`solve_k`] until `deadline`.
+fn search<'g, S, R, O>(
+    empty: &S,
+    k: usize,
+    target: f64,
+    rng: &mut R,
+    p: &Params,
+    obs: &mut O,
+    deadline: Option<Instant>,
+) -> S
+where
+    S: ScoredSet<'g>,
+    R: Rng + ?Sized,
+    O: Observer + ?Sized,
+{
+    let n = empty.n();
+    let mut freq = vec![0usize; n];
+    let mut best = empty.clone();
+    let mut best_score = f64::NEG_INFINITY;
+    let mut moves = 0usize;
+
+    while moves < p.max_iter {
+        let mut cur = construct(empty, k, &freq, rng);
+        let mut tabu = DualTabu::new(n, p.tenure_u, p.tenure_v).with_schedule(p.tenure_schedule);
+        let mut run_best = f64::NEG_INFINITY;
+        let mut stagnation = 0usize;
+        let mut shakes = 0usize;
+        while moves < p.max_iter {
+            if obs.should_stop() || deadline.is_some_and(|d| Instant::now() >= d) {
+                obs.on_finish(StopReason::Interrupted);
+                return best;
+            }
+            let score = cur.score();
+            if score > best_score {
+                best_score = score;
+                best = cur.clone();
+                obs.on_incumbent(best.solution());
+            }
+            if score + 1e-9 >= target {
+                obs.on_finish(StopReason::Feasible);
+                return best;
+            }
+            if score > run_best {
+                run_best = score;
+                stagnation = 0;
+            } else {
+                stagnation += 1;
+            }
+            if stagnation >= p.stagnation_iter {
+                if shakes == SHAKES_PER_RUN {
+                    break;
+                }
+                shake(&mut cur, &mut tabu, rng);
+                shakes += 1;
+                stagnation = 0;
+            } else {
+                improve_once(&mut cur, &mut tabu, &mut freq, rng);
+            }
+            moves += 1;
+            tabu.step();
+            tabu.update_tenures_for_deficit(k, (target - score).max(0.0).ceil() as usize, rng);
+        }
+    }
+    obs.on_finish(StopReason::MaxIter);
+    best
+}
+
+/// Largest k whose best set reaches `target(k)`, growing k from 2 until
+/// more than `p.maxk_lookahead` consecutive sizes fail.
+pub(crate) fn solve_maxk<'g, S, R, O>(
+    empty: &S,
+    target: impl Fn(usize) -> f64,
+    rng: &mut R,
+    p: &Params,
+    obs: &mut O,
+) -> S
+where
+    S: ScoredSet<'g>,
+    R: Rng + ?Sized,
+    O: Observer + ?Sized,
+{
+    let deadline = p.time_limit.and_then(|t| Instant::now().checked_add(t));
+    let mut best = empty.clone();
+    let mut failures = 0usize;
+    let mut reason = StopReason::Exhausted;
+    for k in 2..=empty.n() {
+        let sol = search(empty, k, target(k), rng, p, &mut crate::observer::NoObserver, deadline);
+        if sol.size() == k && sol.score() + 1e-9 >= target(k) {
+            best = sol;
+            obs.on_incumbent(best.solution());
+            failures = 0;
+        } else {
+            failures += 1;
+            if failures > p.maxk_lookahead {
+                reason = StopReason::Failure;
+                break;
+            }
+        }
+        if obs.should_stop() || deadline.is_some_and(|d| Instant::now() >= d) {
+            reason = StopReason::Interrupted;
+            break;
+        }
+    }
+    obs.on_finish(reason);
+    best
+}
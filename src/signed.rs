@@ -0,0 +1,275 @@
+// src/signed.rs
+//! Quasi-cliques in signed graphs (social-balance style analyses).
+//!
+//! A [`SignedGraph`] keeps its positive and negative edges as two [`Graph`]s
+//! over the same vertices.  The score of a set S is
+//!
+//! ```text
+//! f(S) = m⁺(S) − λ·m⁻(S)          λ = Params::negative_weight
+//! ```
+//!
+//! and its signed density f(S) / C(|S|,2) must reach γ.  Without negative
+//! edges this is the plain γ-quasi-clique.
+//!
+//! • [`solve_signed_k`]: the tabu search of `setsearch` on f,
+//!   where a vertex weighs d⁺(v) − λ·d⁻(v) inside S.
+//! • [`solve_signed_maxk`]: grows k until more than `p.maxk_lookahead`
+//!   consecutive sizes fail.
+
+use crate::{
+    graph::Graph,
+    observer::{NoObserver, Observer, StopReason},
+    params::Params,
+    setsearch::{self, ScoredSet},
+    solution::{edges_of, Solution},
+};
+use rand::Rng;
+use std::io::{self, BufRead};
+use std::path::Path;
+
+/// Undirected graph whose edges carry a sign.
+#[derive(Clone, Debug)]
+pub struct SignedGraph {
+    pos: Graph,
+    neg: Graph,
+}
+
+impl SignedGraph {
+    /// Pair of positive / negative edge graphs on the same vertices.
+    ///
+    /// Panics if the vertex counts differ.
+    pub fn new(pos: Graph, neg: Graph) -> Self {
+        assert_eq!(pos.n(), neg.n(), "positive and negative graphs differ in n");
+        Self { pos, neg }
+    }
+
+    /// Graph on `n` vertices from `(u, v, sign)` triples: sign > 0 is a
+    /// positive edge, sign < 0 a negative one, 0 is ignored.
+    pub fn from_signed_edges(n: usize, edges: &[(usize, usize, i8)]) -> Self {
+        let pick = |keep: fn(i8) -> bool| -> Vec<(usize, usize)> {
+            edges.iter().filter(|e| keep(e.2)).map(|&(u, v, _)| (u, v)).collect()
+        };
+        Self::new(
+            Graph::from_edge_list(n, &pick(|s| s > 0)),
+            Graph::from_edge_list(n, &pick(|s| s < 0)),
+        )
+    }
+
+    /// Parse a whitespace-separated `u v sign` edge list (0-based ids, sign
+    /// `1` / `-1` or `+` / `-`), as used by the SNAP signed networks.  Lines
+    /// starting with `#` or `%` are comments; n is the largest id + 1.
+    pub fn parse_edge_list<R: BufRead>(reader: R) -> io::Result<Self> {
+        let invalid = |line: &str| {
+            io::Error::new(io::ErrorKind::InvalidData, format!("malformed signed edge: '{line}'"))
+        };
+        let mut edges = Vec::new();
+        let mut n = 0;
+        for line in reader.lines() {
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with('%') {
+                continue;
+            }
+            let mut it = line.split_whitespace();
+            let (u, v, s) = match (it.next(), it.next(), it.next()) {
+                (Some(u), Some(v), Some(s)) => (u, v, s),
+                _ => return Err(invalid(line)),
+            };
+            let u: usize = u.parse().map_err(|_| invalid(line))?;
+            let v: usize = v.parse().map_err(|_| invalid(line))?;
+            let sign: i8 = match s {
+                "+" => 1,
+                "-" => -1,
+                s => s.parse::<i8>().map_err(|_| invalid(line))?.signum(),
+            };
+            n = n.max(u + 1).max(v + 1);
+            edges.push((u, v, sign));
+        }
+        Ok(Self::from_signed_edges(n, &edges))
+    }
+
+    /// [`SignedGraph::parse_edge_list`] on a file.
+    pub fn read_edge_list<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::parse_edge_list(io::BufReader::new(std::fs::File::open(path)?))
+    }
+
+    pub fn n(&self) -> usize {
+        self.pos.n()
+    }
+
+    /// The positive edges; solver results are [`Solution`]s over this graph.
+    pub fn positive(&self) -> &Graph {
+        &self.pos
+    }
+
+    pub fn negative(&self) -> &Graph {
+        &self.neg
+    }
+
+    /// f(S) = m⁺(S) − λ·m⁻(S) of an arbitrary vertex list (duplicates are
+    /// ignored).
+    pub fn score_of(&self, vertices: &[usize], lambda: f64) -> f64 {
+        edges_of(&self.pos, vertices) as f64 - lambda * edges_of(&self.neg, vertices) as f64
+    }
+
+    /// Signed density f(S) / C(|S|,2); 0 for fewer than two vertices.
+    pub fn signed_density(&self, vertices: &[usize], lambda: f64) -> f64 {
+        let mut distinct = vertices.to_vec();
+        distinct.sort_unstable();
+        distinct.dedup();
+        let k = distinct.len();
+        if k < 2 { 0.0 }
+        else { 2.0 * self.score_of(&distinct, lambda) / (k * (k - 1)) as f64 }
+    }
+}
+
+/// One vertex set tracked in both sign graphs.
+#[derive(Clone)]
+struct Signed<'g> {
+    pos:    Solution<'g>,
+    neg:    Solution<'g>,
+    lambda: f64,
+}
+
+impl<'g> Signed<'g> {
+    fn new(graph: &'g SignedGraph, lambda: f64) -> Self {
+        let (mut pos, mut neg) = (Solution::new(&graph.pos), Solution::new(&graph.neg));
+        pos.track_degrees();
+        neg.track_degrees();
+        Self { pos, neg, lambda }
+    }
+}
+
+impl<'g> ScoredSet<'g> for Signed<'g> {
+    fn n(&self) -> usize {
+        self.pos.graph().n()
+    }
+
+    fn contains(&self, v: usize) -> bool {
+        self.pos.bitset()[v]
+    }
+
+    fn size(&self) -> usize {
+        self.pos.size()
+    }
+
+    fn add(&mut self, v: usize) {
+        self.pos.add(v);
+        self.neg.add(v);
+    }
+
+    fn remove(&mut self, v: usize) {
+        self.pos.remove(v);
+        self.neg.remove(v);
+    }
+
+    fn score(&self) -> f64 {
+        self.pos.edges() as f64 - self.lambda * self.neg.edges() as f64
+    }
+
+    /// d⁺(v) − λ·d⁻(v) with respect to the set.
+    fn weight(&self, v: usize) -> f64 {
+        self.pos.deg_in(v) as f64 - self.lambda * self.neg.deg_in(v) as f64
+    }
+
+    fn pair(&self, u: usize, v: usize) -> f64 {
+        self.pos.graph().has_edge(u, v) as u8 as f64
+            - self.lambda * self.neg.graph().has_edge(u, v) as u8 as f64
+    }
+
+    fn solution(&self) -> &Solution<'g> {
+        &self.pos
+    }
+}
+
+/// f(S) a k-set needs for signed density γ.
+fn target(k: usize, gamma: f64) -> f64 {
+    gamma * (k * k.saturating_sub(1) / 2) as f64
+}
+
+/// Best set of size `k` by signed score; returned as a [`Solution`] over
+/// [`SignedGraph::positive`] (empty if k is out of range).
+pub fn solve_signed_k<'g, R>(graph: &'g SignedGraph, k: usize, rng: &mut R, p: &Params) -> Solution<'g>
+where
+    R: Rng + ?Sized,
+{
+    solve_signed_k_with(graph, k, rng, p, &mut NoObserver)
+}
+
+/// [`solve_signed_k`] reporting incumbents, honouring `should_stop` and
+/// reporting the stop reason to `obs`.
+pub fn solve_signed_k_with<'g, R, O>(
+    graph: &'g SignedGraph,
+    k: usize,
+    rng: &mut R,
+    p: &Params,
+    obs: &mut O,
+) -> Solution<'g>
+where
+    R: Rng + ?Sized,
+    O: Observer + ?Sized,
+{
+    if k < 2 || k > graph.n() {
+        obs.on_finish(StopReason::Infeasible);
+        return Solution::new(&graph.pos);
+    }
+    let empty = Signed::new(graph, p.negative_weight);
+    setsearch::solve_k(&empty, k, target(k, p.gamma_target), rng, p, obs).pos
+}
+
+/// Largest set whose signed density reaches γ.
+pub fn solve_signed_maxk<'g, R>(graph: &'g SignedGraph, rng: &mut R, p: &Params) -> Solution<'g>
+where
+    R: Rng + ?Sized,
+{
+    solve_signed_maxk_with(graph, rng, p, &mut NoObserver)
+}
+
+/// [`solve_signed_maxk`] with an observer (feasible incumbents only).
+pub fn solve_signed_maxk_with<'g, R, O>(graph: &'g SignedGraph, rng: &mut R, p: &Params, obs: &mut O) -> Solution<'g>
+where
+    R: Rng + ?Sized,
+    O: Observer + ?Sized,
+{
+    let empty = Signed::new(graph, p.negative_weight);
+    setsearch::solve_maxk(&empty, |k| target(k, p.gamma_target), rng, p, obs).pos
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testgraphs::clique_edges;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn negative_edges_split_a_clique() {
+        // K6 on 0..6 where 0-1, 0-2, 1-2 are hostile; 3..6 plus one of them
+        // is balanced, all six are not
+        let edges: Vec<_> =
+            clique_edges(0..6).into_iter().map(|(u, v)| (u, v, if v < 3 { -1 } else { 1 })).collect();
+        let g = SignedGraph::from_signed_edges(6, &edges);
+        assert_eq!((g.positive().m(), g.negative().m()), (12, 3));
+        assert_eq!(g.score_of(&[0, 1, 2, 3, 4, 5], 1.0), 9.0);
+
+        let p = Params { gamma_target: 1.0, negative_weight: 1.0, max_iter: 10_000, ..Params::default() };
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        let sol = solve_signed_maxk(&g, &mut rng, &p);
+        assert_eq!(sol.size(), 4);
+        assert_eq!(sol.bitset().iter_ones().filter(|&v| v < 3).count(), 1);
+
+        // counting hostile edges as ties, the whole clique qualifies
+        let p = Params { negative_weight: -1.0, ..p };
+        assert_eq!(solve_signed_maxk(&g, &mut rng, &p).size(), 6);
+    }
+
+    #[test]
+    fn parse_signed_edge_list() {
+        let text = "# FromNodeId ToNodeId Sign\n0 1 1\n1 2 -1\n2 3 +\n";
+        let g = SignedGraph::parse_edge_list(text.as_bytes()).unwrap();
+        assert_eq!(g.n(), 4);
+        assert_eq!((g.positive().m(), g.negative().m()), (2, 1));
+        assert!(g.negative().has_edge(2, 1));
+        assert!(SignedGraph::parse_edge_list("0 1\n".as_bytes()).is_err());
+    }
+}
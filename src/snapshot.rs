@@ -0,0 +1,141 @@
+// src/snapshot.rs
+//! Snapshots of the internal search state, for studying memory dynamics.
+//!
+//! A [`Snapshot`] copies what the fixed-k search keeps between moves:
+//! current and run-best sets, long-term frequency memory, the remaining
+//! tabu time of every vertex and the tenures.  Solvers build one when
+//! [`Observer::wants_snapshot`] asks for it (never otherwise, so the
+//! O(n) copy is opt-in); a [`Stepper`](crate::realtime::Stepper) hands
+//! one out on demand.  [`Snapshot::to_json`] renders it as one JSON
+//! object, [`SnapshotRecorder`] collects one every so many moves.
+
+use crate::{observer::Observer, solution::Solution, tabu::DualTabu};
+
+/// Search state after one move.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Snapshot {
+    /// Target size of the fixed-k search.
+    pub k:          usize,
+    /// Moves performed so far in this fixed-k search (all restarts).
+    pub iter:       usize,
+    /// 0-based restart index.
+    pub restart:    usize,
+    /// Members of the current set, ascending.
+    pub current:    Vec<usize>,
+    /// Members of the best set of this restart, ascending.
+    pub best:       Vec<usize>,
+    /// Long-term frequency memory, per vertex.
+    pub freq:       Vec<usize>,
+    /// Moves until each vertex may be re-added (0 = not tabu).
+    pub tabu_u:     Vec<usize>,
+    /// Moves until each vertex may be removed (0 = not tabu).
+    pub tabu_v:     Vec<usize>,
+    /// Current tenures (Tu, Tv).
+    pub tu:         usize,
+    pub tv:         usize,
+    /// Moves since the last improvement of the run best.
+    pub stagnation: usize,
+}
+
+/// Where a snapshot is taken: counters of the search loop.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct At {
+    pub k:          usize,
+    pub iter:       usize,
+    pub restart:    usize,
+    pub stagnation: usize,
+}
+
+impl Snapshot {
+    pub(crate) fn capture(at: At, cur: &Solution<'_>, best: &Solution<'_>, tabu: &DualTabu, freq: &[usize]) -> Self {
+        let (tabu_u, tabu_v) = tabu.remaining();
+        let (tu, tv) = tabu.tenures();
+        Self {
+            k: at.k,
+            iter: at.iter,
+            restart: at.restart,
+            current: cur.bitset().iter_ones().collect(),
+            best: best.bitset().iter_ones().collect(),
+            freq: freq.to_vec(),
+            tabu_u,
+            tabu_v,
+            tu,
+            tv,
+            stagnation: at.stagnation,
+        }
+    }
+
+    /// The snapshot as one JSON object (keys as the field names).
+    pub fn to_json(&self) -> String {
+        fn list(xs: &[usize]) -> String {
+            let items: Vec<String> = xs.iter().map(usize::to_string).collect();
+            format!("[{}]", items.join(","))
+        }
+        format!(
+            "{{\"k\":{},\"iter\":{},\"restart\":{},\"current\":{},\"best\":{},\"freq\":{},\
+\"tabu_u\":{},\"tabu_v\":{},\"tu\":{},\"tv\":{},\"stagnation\":{}}}",
+            self.k,
+            self.iter,
+            self.restart,
+            list(&self.current),
+            list(&self.best),
+            list(&self.freq),
+            list(&self.tabu_u),
+            list(&self.tabu_v),
+            self.tu,
+            self.tv,
+            self.stagnation
+        )
+    }
+}
+
+/// Observer keeping a [`Snapshot`] every `every` moves (`every = 0`
+/// keeps none).
+#[derive(Clone, Debug, Default)]
+pub struct SnapshotRecorder {
+    pub every:     usize,
+    pub snapshots: Vec<Snapshot>,
+}
+
+impl SnapshotRecorder {
+    pub fn new(every: usize) -> Self {
+        Self { every, snapshots: Vec::new() }
+    }
+}
+
+impl Observer for SnapshotRecorder {
+    fn wants_snapshot(&mut self, iter: usize) -> bool {
+        self.every > 0 && iter.is_multiple_of(self.every)
+    }
+
+    fn on_snapshot(&mut self, snap: &Snapshot) {
+        self.snapshots.push(snap.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{params::Params, restart::solve_fixed_k_with, Graph};
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn recorder_samples_the_search() {
+        // C6 has no triangle: the search runs to max_iter
+        let g = Graph::from_edge_list(6, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (0, 5)]);
+        let p = Params { gamma_target: 1.0, max_iter: 100, ..Params::default() };
+        let mut rec = SnapshotRecorder::new(10);
+        solve_fixed_k_with(&g, 3, &mut ChaCha8Rng::seed_from_u64(0), &p, &mut rec);
+
+        assert_eq!(rec.snapshots.len(), 10);
+        for (i, s) in rec.snapshots.iter().enumerate() {
+            assert_eq!(s.iter, 10 * (i + 1));
+            assert_eq!((s.current.len(), s.best.len()), (3, 3));
+            assert_eq!((s.freq.len(), s.tabu_u.len(), s.tabu_v.len()), (6, 6, 6));
+        }
+        let json = rec.snapshots[0].to_json();
+        assert!(json.starts_with("{\"k\":3,\"iter\":10,"));
+        assert!(json.ends_with('}'));
+    }
+}
@@ -2,10 +2,20 @@
 //!
 //! • O(1) access to size and edge count.  
 //! • O(n / 64) per add/remove operation.  
-//! • Works together with [`Graph`] and [`DualTabu`].
+//! • Works together with [`Graph`] and [`DualTabu`].  
+//! • Optional move journal: `checkpoint()` / `rollback()` / `commit()`
+//!   revert multi-step modifications without cloning the bitset.  
+//! • Incremental Zobrist hash of S (see [`crate::pool`]).  
+//! • Optional degree tracking (`track_degrees`): per-vertex internal
+//!   degrees kept up to date in O(deg(v)) per add/remove, so `deg_in` is
+//!   O(1) instead of a row scan.  Frontier tracking (`track_frontier`)
+//!   also keeps the outsiders adjacent to S, so scans touch only N(S) on
+//!   sparse graphs.
+//! • Batched internal degrees (`internal_degrees` / `batch_degrees`): all
+//!   |N(v) ∩ S| from one pass over the members' rows.
 
 use bitvec::prelude::*;
-use crate::graph::Graph;
+use crate::{graph::Graph, pool::{zobrist_key, zobrist_of}};
 
 /// Mutable quasi-clique candidate bound to a single [`Graph`].
 #[derive(Clone, Debug)]
@@ -14,6 +24,49 @@ pub struct Solution<'g> {
     vertices:   BitVec,
     edge_count: usize,
     size:       usize,
+    /// XOR of `zobrist_key(v)` over v ∈ S.
+    hash:       u64,
+    /// Toggled vertices since the oldest open checkpoint.
+    journal:    Vec<usize>,
+    /// Journal lengths at each open checkpoint (innermost last).
+    marks:      Vec<usize>,
+    /// Incremental internal degrees / frontier, if tracked.
+    frontier:   Option<Frontier>,
+}
+
+/// |N(v) ∩ S| for every vertex and, if `listed`, the list of outsiders
+/// with a positive count (indexed for O(1) insert/remove).
+#[derive(Clone, Debug)]
+struct Frontier {
+    deg:    Vec<u32>,
+    list:   Vec<usize>,
+    pos:    Vec<u32>, // index in `list`, or u32::MAX; empty unless listed
+    listed: bool,
+}
+
+impl Frontier {
+    const ABSENT: u32 = u32::MAX;
+
+    fn push(&mut self, v: usize) {
+        if self.listed && self.pos[v] == Self::ABSENT {
+            self.pos[v] = self.list.len() as u32;
+            self.list.push(v);
+        }
+    }
+
+    fn remove(&mut self, v: usize) {
+        if !self.listed {
+            return;
+        }
+        let i = self.pos[v];
+        if i != Self::ABSENT {
+            self.list.swap_remove(i as usize);
+            if let Some(&moved) = self.list.get(i as usize) {
+                self.pos[moved] = i;
+            }
+            self.pos[v] = Self::ABSENT;
+        }
+    }
 }
 
 /*───────────────────────── impl ─────────────────────────*/
@@ -28,6 +81,10 @@ impl<'g> Solution<'g> {
             vertices: bitvec![0; graph.n()],
             edge_count: 0,
             size: 0,
+            hash: 0,
+            journal: Vec::new(),
+            marks: Vec::new(),
+            frontier: None,
         }
     }
 
@@ -39,7 +96,7 @@ impl<'g> Solution<'g> {
         let mut e = 0usize;
         for i in 0..graph.n() {
             if subset[i] {
-                for j in graph.neigh_row(i).iter_ones().filter(|&j| j > i) {
+                for j in graph.neighbors(i).filter(|&j| j > i) {
                     if subset[j] { e += 1; }
                 }
             }
@@ -48,7 +105,12 @@ impl<'g> Solution<'g> {
         let mut vertices = BitVec::repeat(false, graph.n());
         vertices |= subset;
 
-        Self { graph, vertices, edge_count: e, size }
+        let hash = zobrist_of(vertices.iter_ones());
+
+        Self {
+            graph, vertices, edge_count: e, size, hash,
+            journal: Vec::new(), marks: Vec::new(), frontier: None,
+        }
     }
 
     /* queries */
@@ -56,7 +118,62 @@ impl<'g> Solution<'g> {
     #[inline] pub fn size(&self) -> usize          { self.size }
     #[inline] pub fn edges(&self) -> usize         { self.edge_count }
     #[inline] pub fn bitset(&self) -> &BitVec      { &self.vertices }
-    #[inline] pub fn graph(&self) -> &Graph        { self.graph }
+    #[inline] pub fn graph(&self) -> &'g Graph     { self.graph }
+    /// Order-independent 64-bit fingerprint of S (XOR of per-vertex keys),
+    /// kept up to date by every add / remove: O(1).
+    #[inline] pub fn zobrist(&self) -> u64         { self.hash }
+
+    /// Internal degree |N(v) ∩ S|; O(1) with degree tracking, otherwise
+    /// a word-level popcount ([`Graph::deg_into`]).
+    #[inline]
+    pub fn deg_in(&self, v: usize) -> usize {
+        match &self.frontier {
+            Some(f) => f.deg[v] as usize,
+            None => self.graph.deg_into(v, &self.vertices),
+        }
+    }
+
+    /// |N(v) ∩ S| for every vertex v: the tracked counts with degree
+    /// tracking, otherwise one pass over the members' rows
+    /// ([`Graph::degrees_into`]).
+    pub fn internal_degrees(&self) -> Vec<u32> {
+        match &self.frontier {
+            Some(f) => f.deg.clone(),
+            None => self.graph.degrees_into(&self.vertices),
+        }
+    }
+
+    /// Batched internal degrees when they beat per-vertex [`deg_in`]
+    /// calls over all outsiders: on sparse graphs without degree
+    /// tracking.
+    ///
+    /// [`deg_in`]: Solution::deg_in
+    pub fn batch_degrees(&self) -> Option<Vec<u32>> {
+        (self.frontier.is_none() && self.graph.is_sparse()).then(|| self.graph.degrees_into(&self.vertices))
+    }
+
+    /// The tracked internal degrees, borrowed (`None` unless
+    /// [`Solution::track_degrees`] or [`Solution::track_frontier`] was
+    /// called).
+    pub fn degrees(&self) -> Option<&[u32]> {
+        self.frontier.as_ref().map(|f| f.deg.as_slice())
+    }
+
+    /// Outsiders adjacent to S, in no particular order (`None` unless
+    /// [`Solution::track_frontier`] was called).
+    pub fn frontier(&self) -> Option<&[usize]> {
+        self.frontier.as_ref().filter(|f| f.listed).map(|f| f.list.as_slice())
+    }
+
+    /// Outsiders that can attain the maximum internal degree: the frontier
+    /// when tracked and non-empty, otherwise every outsider (ascending).
+    pub fn outsiders_to_scan(&self) -> impl Iterator<Item = usize> + '_ {
+        let (front, all) = match self.frontier() {
+            Some(f) if !f.is_empty() => (Some(f.iter().copied()), None),
+            _ => (None, Some((0..self.graph.n()).filter(|&v| !self.vertices[v]))),
+        };
+        front.into_iter().flatten().chain(all.into_iter().flatten())
+    }
 
     /// Density 2 m(S) / (|S|·(|S|−1)); returns 0 for |S| < 2.
     pub fn density(&self) -> f64 {
@@ -67,30 +184,100 @@ impl<'g> Solution<'g> {
     pub fn is_gamma_feasible(&self, gamma: f64) -> bool {
        self.density() + f64::EPSILON >= gamma
     }
+
+    /// Smallest internal degree over the members (0 if S is empty).
+    pub fn min_deg_in(&self) -> usize {
+        self.vertices.iter_ones().map(|v| self.deg_in(v)).min().unwrap_or(0)
+    }
+
+    /// Every member has at least ⌈θ(|S|−1)⌉ neighbours inside S.
+    pub fn meets_min_degree(&self, theta: f64) -> bool {
+        theta <= 0.0 || self.min_deg_in() >= required_degree(self.size, theta)
+    }
+
+    /// Hybrid feasibility: density ≥ γ and internal degrees ≥ ⌈θ(|S|−1)⌉
+    /// (θ = 0 is plain γ-feasibility).
+    pub fn is_feasible(&self, gamma: f64, theta: f64) -> bool {
+        self.is_gamma_feasible(gamma) && self.meets_min_degree(theta)
+    }
+
+    /// The `k` members with the smallest internal degree, as `(v, deg)`
+    /// pairs in ascending degree order (ties → smaller `v`).  Fewer than
+    /// `k` pairs if |S| < k.
+    pub fn weakest(&self, k: usize) -> Vec<(usize, usize)> {
+        let mut degs: Vec<(usize, usize)> = self.vertices
+            .iter_ones()
+            .map(|v| (v, self.deg_in(v)))
+            .collect();
+        degs.sort_unstable_by_key(|&(v, d)| (d, v));
+        degs.truncate(k);
+        degs
+    }
+
+    /// Edges missing for S to be γ-feasible at its current size
+    /// (0 if already feasible).
+    pub fn edge_deficit(&self, gamma: f64) -> usize {
+        required_edges(self.size, gamma).saturating_sub(self.edge_count)
+    }
+
+    /// Edges S could lose at its current size and stay γ-feasible
+    /// (0 if infeasible).
+    pub fn edge_surplus(&self, gamma: f64) -> usize {
+        self.edge_count.saturating_sub(required_edges(self.size, gamma))
+    }
+
+    /// Lower bound on the number of vertex swaps (same |S|) needed to reach
+    /// γ-feasibility; `Some(0)` if feasible, `None` if no swap sequence
+    /// within the graph can close the deficit.
+    ///
+    /// Swapping out t members loses at least (Σ of their t smallest internal
+    /// degrees − C(t,2)) edges; t newcomers gain at most t(s−t) + C(t,2).
+    pub fn min_swaps_bound(&self, gamma: f64) -> Option<usize> {
+        let need = required_edges(self.size, gamma);
+        if self.edge_count >= need {
+            return Some(0);
+        }
+        let mut degs: Vec<usize> = self.vertices
+            .iter_ones()
+            .map(|v| self.deg_in(v))
+            .collect();
+        degs.sort_unstable();
+
+        let s = self.size;
+        let outside = self.graph.n() - s;
+        let mut lost_sum = 0usize;
+        for t in 1..=s.min(outside) {
+            lost_sum += degs[t - 1];
+            let pairs = t * (t - 1) / 2;
+            let lost = lost_sum.saturating_sub(pairs);
+            let best = self.edge_count - lost.min(self.edge_count) + t * (s - t) + pairs;
+            if best >= need {
+                return Some(t);
+            }
+        }
+        None
+    }
+
+    /// Subgraph induced by S; new vertex `i` is `map[i]` (ascending).
+    pub fn induced_graph(&self) -> (Graph, Vec<usize>) {
+        let map: Vec<usize> = self.vertices.iter_ones().collect();
+        (self.graph.induced_subgraph(&map), map)
+    }
+
     /* mutators */
 
     /// Add vertex *v* (no-op if already present).
     pub fn add(&mut self, v: usize) {
         if self.vertices[v] { return; }
-        let added = self.graph.neigh_row(v)
-            .iter_ones()
-            .filter(|&j| self.vertices[j])
-            .count();
-        self.vertices.set(v, true);
-        self.size       += 1;
-        self.edge_count += added;
+        self.insert_raw(v);
+        self.record(v);
     }
 
     /// Remove vertex *v* (no-op if absent).
     pub fn remove(&mut self, v: usize) {
         if !self.vertices[v] { return; }
-        let removed = self.graph.neigh_row(v)
-            .iter_ones()
-            .filter(|&j| self.vertices[j])
-            .count();
-        self.vertices.set(v, false);
-        self.size       -= 1;
-        self.edge_count -= removed;
+        self.remove_raw(v);
+        self.record(v);
     }
 
     /// Toggle membership; returns `true` if *v* is in the set afterwards.
@@ -100,10 +287,233 @@ impl<'g> Solution<'g> {
 
     /// Clear S completely.
     pub fn clear(&mut self) {
+        if !self.marks.is_empty() {
+            self.journal.extend(self.vertices.iter_ones());
+        }
         self.vertices.fill(false);
         self.size = 0;
         self.edge_count = 0;
+        self.hash = 0;
+        if let Some(f) = &mut self.frontier {
+            f.deg.fill(0);
+            f.list.clear();
+            f.pos.fill(Frontier::ABSENT);
+        }
+    }
+
+    /// Start maintaining internal degrees incrementally.
+    /// O(n + Σ_{v∈S} deg(v)) once; afterwards [`deg_in`] is O(1) and
+    /// add/remove pay O(deg(v)) on top of their O(n/64).  No-op if already
+    /// tracking.
+    ///
+    /// [`deg_in`]: Solution::deg_in
+    pub fn track_degrees(&mut self) {
+        if self.frontier.is_some() { return; }
+        let mut deg = vec![0; self.graph.n()];
+        for v in self.vertices.iter_ones() {
+            for w in self.graph.neighbors(v) {
+                deg[w] += 1;
+            }
+        }
+        self.frontier = Some(Frontier { deg, list: Vec::new(), pos: Vec::new(), listed: false });
+    }
+
+    /// [`Solution::track_degrees`], plus the frontier (outsiders adjacent
+    /// to S).  Upgrades plain degree tracking; no-op if already tracking
+    /// the frontier.
+    pub fn track_frontier(&mut self) {
+        self.track_degrees();
+        let f = self.frontier.as_mut().unwrap();
+        if f.listed { return; }
+        f.listed = true;
+        f.pos = vec![Frontier::ABSENT; f.deg.len()];
+        for v in 0..f.deg.len() {
+            if f.deg[v] > 0 && !self.vertices[v] {
+                f.push(v);
+            }
+        }
+    }
+
+    /* journal */
+
+    /// Open a checkpoint; later changes can be undone with [`rollback`].
+    /// Checkpoints nest.
+    ///
+    /// [`rollback`]: Solution::rollback
+    pub fn checkpoint(&mut self) {
+        self.marks.push(self.journal.len());
+    }
+
+    /// Undo every change since the innermost open checkpoint and close it.
+    /// Returns `false` (and does nothing) if no checkpoint is open.
+    pub fn rollback(&mut self) -> bool {
+        let Some(mark) = self.marks.pop() else { return false };
+        while self.journal.len() > mark {
+            let v = self.journal.pop().unwrap();
+            if self.vertices[v] { self.remove_raw(v) } else { self.insert_raw(v) }
+        }
+        true
     }
+
+    /// Keep the changes since the innermost open checkpoint and close it.
+    /// Returns `false` if no checkpoint is open.
+    pub fn commit(&mut self) -> bool {
+        if self.marks.pop().is_none() { return false; }
+        if self.marks.is_empty() { self.journal.clear(); }
+        true
+    }
+
+    /// Number of open checkpoints.
+    #[inline] pub fn depth(&self) -> usize { self.marks.len() }
+
+    /* internals */
+
+    #[inline]
+    fn record(&mut self, v: usize) {
+        if !self.marks.is_empty() { self.journal.push(v); }
+    }
+
+    fn insert_raw(&mut self, v: usize) {
+        let added = self.deg_in(v);
+        self.vertices.set(v, true);
+        if let Some(f) = &mut self.frontier {
+            f.remove(v);
+            for w in self.graph.neighbors(v) {
+                f.deg[w] += 1;
+                if !self.vertices[w] { f.push(w); }
+            }
+        }
+        self.size       += 1;
+        self.edge_count += added;
+        self.hash       ^= zobrist_key(v);
+    }
+
+    fn remove_raw(&mut self, v: usize) {
+        self.vertices.set(v, false);
+        let removed = self.deg_in(v);
+        if let Some(f) = &mut self.frontier {
+            for w in self.graph.neighbors(v) {
+                f.deg[w] -= 1;
+                if f.deg[w] == 0 { f.remove(w); }
+            }
+            if f.deg[v] > 0 { f.push(v); }
+        }
+        self.size       -= 1;
+        self.edge_count -= removed;
+        self.hash       ^= zobrist_key(v);
+    }
+}
+
+/*──────────────────── free-standing evaluation ────────────────────*/
+
+/// Edges a k-vertex set needs to be γ-feasible: ⌈γ·C(k,2)⌉.
+#[inline]
+pub fn required_edges(k: usize, gamma: f64) -> usize {
+    let clique_edges = k.saturating_mul(k.saturating_sub(1)) / 2;
+    (gamma * clique_edges as f64).ceil() as usize
+}
+
+/// Internal degree every member of a k-vertex set needs under the
+/// minimum-degree ratio θ: ⌈θ·(k−1)⌉.
+#[inline]
+pub fn required_degree(k: usize, theta: f64) -> usize {
+    (theta * k.saturating_sub(1) as f64 - 1e-9).ceil() as usize
+}
+
+/// m(S) for an arbitrary vertex list (duplicates are ignored).
+///
+/// Panics if a vertex is out of range.
+pub fn edges_of(graph: &Graph, vertices: &[usize]) -> usize {
+    let mut mask = bitvec![0; graph.n()];
+    for &v in vertices {
+        mask.set(v, true);
+    }
+    let mut e = 0usize;
+    for v in mask.iter_ones() {
+        e += graph.neighbors(v).filter(|&j| j > v && mask[j]).count();
+    }
+    e
+}
+
+/// Density 2 m(S) / (|S|·(|S|−1)) of an arbitrary vertex list
+/// (duplicates are ignored); 0 for fewer than two distinct vertices.
+pub fn density_of(graph: &Graph, vertices: &[usize]) -> f64 {
+    let mut distinct = vertices.to_vec();
+    distinct.sort_unstable();
+    distinct.dedup();
+    let k = distinct.len();
+    if k < 2 { 0.0 }
+    else { 2.0 * edges_of(graph, &distinct) as f64 / (k * (k - 1)) as f64 }
+}
+
+/// How feasible a fixed vertex set is, across every γ at once.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Spectrum {
+    /// Distinct vertices.
+    pub size:      usize,
+    /// Induced edges m(S).
+    pub edges:     usize,
+    /// Vertex pairs |S|·(|S|−1)/2; the density is exactly `edges / pairs`.
+    pub pairs:     usize,
+    pub density:   f64,
+    /// Largest γ at which the set is feasible (the density, or 1 for
+    /// fewer than two vertices, which need no edge at any γ).
+    pub max_gamma: f64,
+}
+
+impl Spectrum {
+    /// Edges to add inside the set for it to reach density γ (0 if it
+    /// already does).
+    pub fn edges_needed(&self, gamma: f64) -> usize {
+        let need = (gamma * self.pairs as f64 - 1e-9).ceil().max(0.0) as usize;
+        need.saturating_sub(self.edges)
+    }
+}
+
+/// [`Spectrum`] of an arbitrary vertex list (duplicates are ignored).
+///
+/// Panics if a vertex is out of range.
+pub fn feasibility_spectrum(graph: &Graph, vertices: &[usize]) -> Spectrum {
+    let mut distinct = vertices.to_vec();
+    distinct.sort_unstable();
+    distinct.dedup();
+    let size = distinct.len();
+    let edges = edges_of(graph, &distinct);
+    let pairs = size * size.saturating_sub(1) / 2;
+    let density = if pairs == 0 { 0.0 } else { edges as f64 / pairs as f64 };
+    Spectrum { size, edges, pairs, density, max_gamma: if pairs == 0 { 1.0 } else { density } }
+}
+
+/// The fewest missing internal pairs whose addition lifts the vertex set
+/// to density γ ([`Spectrum::edges_needed`] of them; none if it is
+/// already there), as `(u, v)` with u < v.  Pairs with more common
+/// neighbours inside the set come first (the links most likely missing
+/// from a near-community), then pairs of weaker members, then by id.
+///
+/// Panics if a vertex is out of range.
+pub fn missing_links(graph: &Graph, vertices: &[usize], gamma: f64) -> Vec<(usize, usize)> {
+    let needed = feasibility_spectrum(graph, vertices).edges_needed(gamma);
+    if needed == 0 {
+        return Vec::new();
+    }
+    let mut sol = Solution::new(graph);
+    for &v in vertices {
+        if !sol.bitset()[v] {
+            sol.add(v);
+        }
+    }
+    let members: Vec<usize> = sol.bitset().iter_ones().collect();
+    let mut scored = Vec::new();
+    for (i, &u) in members.iter().enumerate() {
+        for &v in &members[i + 1..] {
+            if !graph.has_edge(u, v) {
+                let common = graph.neighbors_in(u, &sol).filter(|&w| graph.has_edge(v, w)).count();
+                scored.push((std::cmp::Reverse(common), sol.deg_in(u) + sol.deg_in(v), u, v));
+            }
+        }
+    }
+    scored.sort_unstable();
+    scored.into_iter().take(needed).map(|(_, _, u, v)| (u, v)).collect()
 }
 
 /*───────────────────────── tests ─────────────────────────*/
@@ -132,4 +542,178 @@ mod tests {
         assert_eq!(sol.size(), 2);
         assert_eq!(sol.edges(), 1);
     }
+
+    #[test]
+    fn spectrum_of_a_set() {
+        // C4 plus the chord 0–2: 5 of 6 pairs
+        let g = Graph::from_edge_list(5, &[(0, 1), (1, 2), (2, 3), (0, 3), (0, 2), (3, 4)]);
+        let s = feasibility_spectrum(&g, &[3, 0, 1, 2, 0]);
+        assert_eq!((s.size, s.edges, s.pairs), (4, 5, 6));
+        approx::assert_relative_eq!(s.max_gamma, 5.0 / 6.0);
+        assert_eq!(s.edges_needed(s.max_gamma), 0);
+        assert_eq!(s.edges_needed(0.5), 0);
+        assert_eq!(s.edges_needed(1.0), 1);
+
+        let empty = feasibility_spectrum(&g, &[1, 4]);
+        assert_eq!((empty.edges, empty.edges_needed(0.9)), (0, 1));
+        assert_eq!(feasibility_spectrum(&g, &[4]).max_gamma, 1.0);
+    }
+
+    #[test]
+    fn missing_links_close_triangles_first() {
+        // K4 {0..4} minus 0–1, plus 4 attached to 3 only
+        let g = Graph::from_edge_list(5, &[(0, 2), (0, 3), (1, 2), (1, 3), (2, 3), (3, 4)]);
+        let set = [0, 1, 2, 3, 4];
+        // 6 of 10 pairs; γ = 0.8 needs 8
+        // 0–1 closes two triangles; 4 then links to a weakest member
+        let links = missing_links(&g, &set, 0.8);
+        assert_eq!(links, vec![(0, 1), (0, 4)]);
+        assert!(missing_links(&g, &set, 0.6).is_empty());
+
+        let mut edges = g.edge_list();
+        edges.extend(&links);
+        assert!(density_of(&Graph::from_edge_list(5, &edges), &set) >= 0.8);
+    }
+
+    #[test]
+    fn checkpoint_rollback() {
+        let g = Graph::from_edge_list(4, &[(0, 1), (1, 2), (0, 2), (2, 3)]);
+        let mut sol = Solution::new(&g);
+        sol.add(0);
+        sol.add(1);
+        let before = sol.bitset().clone();
+
+        sol.checkpoint();
+        sol.remove(0);
+        sol.add(2);
+        sol.checkpoint();
+        sol.add(3);
+        assert!(sol.commit());
+        sol.clear();
+        assert!(sol.rollback());
+        assert_eq!(sol.bitset(), &before);
+        assert_eq!((sol.size(), sol.edges()), (2, 1));
+        assert!(!sol.rollback());
+    }
+
+    #[test]
+    fn induced_graph_of_solution() {
+        let g = Graph::from_edge_list(5, &[(0, 1), (1, 2), (0, 2), (2, 3), (3, 4)]);
+        let mut sol = Solution::new(&g);
+        for v in [3, 2, 0] { sol.add(v); }
+        let (sub, map) = sol.induced_graph();
+        assert_eq!(map, vec![0, 2, 3]);
+        assert_eq!(sub.edge_list(), vec![(0, 1), (1, 2)]);
+        assert_eq!(sub.m(), sol.edges());
+    }
+
+    #[test]
+    fn gamma_margins() {
+        // triangle 0-1-2 plus pendant 3 and isolated 4
+        let g = Graph::from_edge_list(5, &[(0, 1), (1, 2), (0, 2), (2, 3)]);
+        let mut sol = Solution::new(&g);
+        for v in [0, 1, 3] { sol.add(v); }
+        assert_eq!(required_edges(3, 1.0), 3);
+        assert_eq!(sol.edge_deficit(1.0), 2);
+        assert_eq!(sol.edge_surplus(0.3), 0);
+        assert_eq!(sol.min_swaps_bound(1.0), Some(1)); // swap 3 for 2
+
+        sol.remove(3);
+        sol.add(2);
+        assert_eq!(sol.edge_surplus(0.6), 1);
+        assert_eq!(sol.min_swaps_bound(1.0), Some(0));
+
+        let mut iso = Solution::new(&g);
+        for v in [0, 1, 2, 3, 4] { iso.add(v); }
+        assert_eq!(iso.min_swaps_bound(1.0), None);
+    }
+
+    #[test]
+    fn min_degree_floor() {
+        // K4 on 0..4 plus 4, attached to 0 only: dense overall, 4 is loose
+        let g = Graph::from_edge_list(5, &[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3), (0, 4)]);
+        let mut sol = Solution::new(&g);
+        for v in 0..5 { sol.add(v); }
+        assert_eq!(sol.min_deg_in(), 1);
+        assert_eq!(required_degree(5, 0.5), 2);
+        assert_eq!(required_degree(11, 0.7), 7);
+        assert_eq!(required_degree(5, 0.0), 0);
+        assert!(sol.is_feasible(0.7, 0.0));
+        assert!(!sol.is_feasible(0.7, 0.5));
+        sol.remove(4);
+        assert!(sol.is_feasible(0.7, 1.0));
+    }
+
+    #[test]
+    fn frontier_tracks_neighbourhood() {
+        // path 0-1-2-3-4-5
+        let g = Graph::from_edge_list(6, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5)]);
+        let mut sol = Solution::new(&g);
+        sol.add(2);
+        sol.track_frontier();
+        sol.add(3);
+        let sorted = |s: &Solution| {
+            let mut f = s.frontier().unwrap().to_vec();
+            f.sort_unstable();
+            f
+        };
+        assert_eq!(sorted(&sol), vec![1, 4]);
+
+        sol.checkpoint();
+        sol.remove(2);
+        sol.add(5);
+        assert_eq!(sorted(&sol), vec![2, 4]);
+        let untracked = Solution::from_bitset(&g, sol.bitset());
+        assert!(untracked.batch_degrees().is_none()); // 5 edges on 6 vertices is dense
+        for v in 0..6 {
+            assert_eq!(sol.deg_in(v), g.deg_into(v, sol.bitset()));
+            assert_eq!(untracked.internal_degrees()[v], sol.internal_degrees()[v]);
+        }
+        assert!(sol.rollback());
+        assert_eq!(sorted(&sol), vec![1, 4]);
+        assert_eq!(sol.outsiders_to_scan().count(), 2);
+
+        sol.clear();
+        assert!(sol.frontier().unwrap().is_empty());
+        assert_eq!(sol.outsiders_to_scan().count(), 6);
+    }
+
+    #[test]
+    fn degree_tracking_without_frontier() {
+        // path 0-1-2-3-4-5
+        let g = Graph::from_edge_list(6, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5)]);
+        let mut sol = Solution::new(&g);
+        sol.add(2);
+        sol.track_degrees();
+        sol.add(3);
+        sol.remove(2);
+        sol.add(5);
+        assert!(sol.frontier().is_none());
+        assert_eq!(sol.degrees().unwrap(), g.degrees_into(sol.bitset()).as_slice());
+        assert_eq!(sol.outsiders_to_scan().collect::<Vec<_>>(), vec![0, 1, 2, 4]);
+
+        sol.track_frontier();
+        let mut front = sol.frontier().unwrap().to_vec();
+        front.sort_unstable();
+        assert_eq!(front, vec![2, 4]);
+    }
+
+    #[test]
+    fn weakest_members() {
+        let g = Graph::from_edge_list(4, &[(0, 1), (1, 2), (0, 2), (2, 3)]);
+        let mut sol = Solution::new(&g);
+        for v in 0..4 { sol.add(v); }
+        assert_eq!(sol.weakest(2), vec![(3, 1), (0, 2)]);
+        assert_eq!(sol.weakest(10).len(), 4);
+        assert_eq!(sol.weakest(10)[3], (2, 3));
+    }
+
+    #[test]
+    fn free_evaluation_matches_solution() {
+        let g = Graph::from_edge_list(4, &[(0, 1), (1, 2), (0, 2), (2, 3)]);
+        assert_eq!(edges_of(&g, &[0, 1, 2, 3]), 4);
+        approx::assert_relative_eq!(density_of(&g, &[3, 2, 1, 0]), 4.0 / 6.0);
+        approx::assert_relative_eq!(density_of(&g, &[0, 1, 1, 2]), 1.0);
+        assert_eq!(density_of(&g, &[2]), 0.0);
+    }
 }
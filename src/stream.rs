@@ -0,0 +1,232 @@
+// src/stream.rs
+//! Solving DIMACS files whose graph would not fit in memory.
+//!
+//! Only per-vertex arrays are kept; the edges are streamed from disk
+//! ([`parse::for_each_dimacs_edge`]) once per pass:
+//!
+//! • pass 1 counts degrees and keeps the `max_candidates` vertices of
+//!   highest degree (ties by id); the counts are sized from the `p` line
+//!   (ids beyond it are rejected) or else from the largest id, and
+//!   memory-checked either way;
+//! • each peel pass recounts degrees inside the candidate set and drops
+//!   the vertices below t = ⌈m_C / |C|⌉.  A graph with m edges on n
+//!   vertices has a subgraph of minimum degree > m/n, so the t-core is
+//!   never empty, and every γ-quasi-clique of minimum degree ≥ t among the
+//!   candidates survives;
+//! • the last pass materialises the subgraph induced by the candidates
+//!   (memory-checked, see [`crate::memory`]).
+//!
+//! The search then runs on that subgraph alone.  Duplicate edges in the
+//! file count twice in the degrees (the candidate choice is a heuristic;
+//! the final graph is deduplicated).
+
+use crate::{builder::GraphBuilder, graph::Graph, maxk::solve_maxk, memory, params::Params, parse};
+use rand::Rng;
+use std::io;
+use std::path::Path;
+
+/// Peel passes made by [`solve_streaming`] (and the Python binding).
+pub const PEEL_PASSES: usize = 2;
+
+/// Marks a vertex outside the candidate set in the id map.
+const NONE: u32 = u32::MAX;
+
+/// Subgraph induced by the candidate core of a streamed file.
+#[derive(Debug)]
+pub struct StreamCore {
+    pub graph:     Graph,
+    /// Original id of each vertex of `graph`, ascending.
+    pub map:       Vec<usize>,
+    /// Last peel threshold (0 when no peel pass ran).
+    pub threshold: usize,
+    /// Passes made over the file.
+    pub passes:    usize,
+}
+
+/// Position of each candidate in `cand` (ascending), [`NONE`] elsewhere.
+fn index_of(n: usize, cand: &[usize]) -> Vec<u32> {
+    let mut index = vec![NONE; n];
+    for (i, &v) in cand.iter().enumerate() {
+        index[v] = i as u32;
+    }
+    index
+}
+
+/// Resize the degree counts to `len` vertices, checked by
+/// [`memory::check_bytes`] first (the ids come from the file).
+fn grow_degrees(deg: &mut Vec<u32>, len: usize) -> io::Result<()> {
+    memory::check_bytes(len.saturating_mul(std::mem::size_of::<u32>()), "degree array")?;
+    deg.resize(len, 0);
+    Ok(())
+}
+
+/// Candidate core of the DIMACS file at `path`: at most `max_candidates`
+/// vertices, chosen by degree and then refined by up to `peel_passes`
+/// peel passes (fewer once a pass removes nothing).
+pub fn stream_core<P: AsRef<Path>>(path: P, max_candidates: usize, peel_passes: usize) -> io::Result<StreamCore> {
+    let path = path.as_ref();
+    if max_candidates == 0 || u32::try_from(max_candidates).is_err() {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "max_candidates must be in 1..u32::MAX"));
+    }
+
+    // pass 1: degrees, sized from the `p` line when it precedes the edges
+    let declared = parse::peek_dimacs_header(path)?.map(|(n, _)| n);
+    let mut deg: Vec<u32> = Vec::new();
+    if let Some(n) = declared {
+        grow_degrees(&mut deg, n)?;
+    }
+    let mut top = 0;
+    let mut failed = None;
+    let header = parse::for_each_dimacs_edge(path, |u, v| {
+        if failed.is_some() {
+            return;
+        }
+        let hi = u.max(v);
+        if hi >= deg.len() {
+            // amortised doubling, so the memory check runs O(log n) times
+            let len = (hi + 1).max(2 * deg.len());
+            let grown = match declared {
+                Some(n) => Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("vertex id {} exceeds the declared n = {n}", hi + 1),
+                )),
+                None => grow_degrees(&mut deg, len),
+            };
+            if let Err(e) = grown {
+                failed = Some(e);
+                return;
+            }
+        }
+        top = top.max(hi + 1);
+        if u != v {
+            deg[u] = deg[u].saturating_add(1);
+            deg[v] = deg[v].saturating_add(1);
+        }
+    })?;
+    if let Some(e) = failed {
+        return Err(e);
+    }
+    let n = declared.unwrap_or_else(|| header.map_or(0, |(n, _)| n).max(top));
+    if n > deg.len() {
+        grow_degrees(&mut deg, n)?;
+    }
+    deg.truncate(n);
+    let mut passes = 1;
+
+    let mut cand: Vec<usize> = (0..n).collect();
+    if n > max_candidates {
+        cand.select_nth_unstable_by_key(max_candidates, |&v| (std::cmp::Reverse(deg[v]), v));
+        cand.truncate(max_candidates);
+    }
+    cand.sort_unstable();
+    drop(deg);
+
+    // peel passes
+    let mut threshold = 0;
+    for _ in 0..peel_passes {
+        let index = index_of(n, &cand);
+        let mut inner = vec![0usize; cand.len()];
+        let mut m = 0usize;
+        parse::for_each_dimacs_edge(path, |u, v| {
+            let (i, j) = (index[u], index[v]);
+            if u != v && i != NONE && j != NONE {
+                inner[i as usize] += 1;
+                inner[j as usize] += 1;
+                m += 1;
+            }
+        })?;
+        passes += 1;
+        threshold = threshold.max(m.div_ceil(cand.len().max(1)));
+        let before = cand.len();
+        let mut i = 0;
+        cand.retain(|_| {
+            i += 1;
+            inner[i - 1] >= threshold
+        });
+        if cand.len() == before {
+            break;
+        }
+    }
+
+    // last pass: the induced subgraph
+    let index = index_of(n, &cand);
+    let mut builder = GraphBuilder::new();
+    builder.vertices(cand.len());
+    parse::for_each_dimacs_edge(path, |u, v| {
+        let (i, j) = (index[u], index[v]);
+        if i != NONE && j != NONE {
+            builder.add_edge(i as usize, j as usize);
+        }
+    })?;
+    passes += 1;
+
+    Ok(StreamCore { graph: builder.try_build()?, map: cand, threshold, passes })
+}
+
+/// Largest γ-quasi-clique (per `p`) found in the candidate core of the
+/// DIMACS file at `path` (see the module docs), as ascending original ids.
+pub fn solve_streaming<P, R>(path: P, max_candidates: usize, rng: &mut R, p: &Params) -> io::Result<Vec<usize>>
+where
+    P: AsRef<Path>,
+    R: Rng + ?Sized,
+{
+    let core = stream_core(path, max_candidates, PEEL_PASSES)?;
+    if core.graph.n() < 2 {
+        return Ok(core.map);
+    }
+    let sol = solve_maxk(&core.graph, rng, p);
+    Ok(sol.bitset().iter_ones().map(|v| core.map[v]).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testgraphs::clique_edges;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn peeling_keeps_the_clique() {
+        // K6 {0..6} and a star with hub 6 and leaves 7..27: the hub has the
+        // highest degree but only one neighbour among the candidates
+        let mut text = String::from("c test\np edge 27 35\n");
+        for (u, v) in clique_edges(1..7) {
+            text.push_str(&format!("e {u} {v}\n"));
+        }
+        for leaf in 8..=27 {
+            text.push_str(&format!("e 7 {leaf}\n"));
+        }
+        let path = std::env::temp_dir().join(format!("tsqc-stream-test-{}.dimacs", std::process::id()));
+        std::fs::write(&path, text).unwrap();
+
+        let core = stream_core(&path, 8, 0).unwrap();
+        assert_eq!(core.map, vec![0, 1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!((core.graph.m(), core.passes), (16, 2));
+
+        let core = stream_core(&path, 8, 5).unwrap();
+        assert_eq!(core.map, (0..6).collect::<Vec<_>>());
+        assert_eq!((core.threshold, core.passes), (3, 4));
+
+        let p = Params { gamma_target: 1.0, max_iter: 2_000, ..Params::default() };
+        let found = solve_streaming(&path, 8, &mut ChaCha8Rng::seed_from_u64(0), &p).unwrap();
+        assert_eq!(found, (0..6).collect::<Vec<_>>());
+        assert!(stream_core(&path, 0, 0).is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn ids_beyond_the_header_are_rejected() {
+        let path = std::env::temp_dir().join(format!("tsqc-stream-ids-{}.dimacs", std::process::id()));
+        std::fs::write(&path, "p edge 3 2\ne 1 2\ne 1 4000000000\n").unwrap();
+        let err = stream_core(&path, 8, 0).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err.to_string().contains("exceeds the declared n = 3"), "{err}");
+
+        // without a header, the largest id sizes the counts
+        std::fs::write(&path, "e 1 2\ne 2 3\ne 5 5\n").unwrap();
+        let core = stream_core(&path, 8, 0).unwrap();
+        assert_eq!(core.map, (0..5).collect::<Vec<_>>());
+        assert_eq!(core.graph.m(), 2);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
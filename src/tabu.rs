@@ -8,9 +8,29 @@
 //! After each move (successful or not), tenures Tu/Tv are recomputed based
 //! on the current deficit from the γ-target (capped at 10) plus a random
 //! component, preventing cycling and encouraging diversification.
+//!
+//! The deterministic part `l` can instead scale with √n or with k (see
+//! [`TenureSchedule`]), which avoids very short tenures when k is large
+//! relative to the deficit cap.
+//!
+//! Marks are stored as expiry iterations, so [`DualTabu::reset`] is O(1):
+//! it advances the clock past the latest expiry instead of clearing the
+//! vectors.
 
 use rand::Rng;
 
+/// How the deterministic part `l` of the tenures is chosen.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum TenureSchedule {
+    /// `l = min(deficit, 10)` (§3.4.3).
+    #[default]
+    Deficit,
+    /// `l = ⌈factor·√n⌉`.
+    SqrtN(f64),
+    /// `l = ⌈factor·k⌉`, k = |S|.
+    ProportionalK(f64),
+}
+
 #[derive(Clone, Debug)]
 pub struct DualTabu {
     expiry_u: Vec<usize>, // when each vertex may next be re-added
@@ -18,6 +38,8 @@ pub struct DualTabu {
     iter:     usize,      // global iteration counter
     tu:       usize,      // current tabu tenure for re-addition
     tv:       usize,      // current tabu tenure for removal
+    horizon:  usize,      // latest expiry handed out so far
+    schedule: TenureSchedule,
 }
 
 impl DualTabu {
@@ -29,16 +51,24 @@ impl DualTabu {
             iter:     0,
             tu:       initial_tu.max(1),
             tv:       initial_tv.max(1),
+            horizon:  0,
+            schedule: TenureSchedule::Deficit,
         }
     }
 
+    /// Use `schedule` for subsequent [`DualTabu::update_tenures`] calls.
+    pub fn with_schedule(mut self, schedule: TenureSchedule) -> Self {
+        self.schedule = schedule;
+        self
+    }
+
     /// Recompute *Tu* and *Tv* based on current size `size_s`, edge count `edges`,
     /// target density `gamma`, and randomness from `rng`.
     ///
     /// 1. `clique_edges = size_s*(size_s-1)/2`  
     /// 2. `target_edges = ceil(γ * clique_edges)`  
     /// 3. `deficit = max(target_edges - edges, 0)`  
-    /// 4. `l = min(deficit, 10)`  
+    /// 4. `l = min(deficit, 10)` (or per [`TenureSchedule`])  
     /// 5. `C = max(size_s/40, 6)`  
     /// 6. `Tu = (l+1) + rand(0..C)`  
     /// 7. `Tv = floor(0.6*(l+1)) + rand(0..floor(0.6*C))`  
@@ -59,9 +89,19 @@ impl DualTabu {
         // 2) Required edges to meet γ (rounded up):
         let target_edges = (gamma * (clique_edges as f64)).ceil() as usize;
 
-        // 3) How many edges short, capped at 10:
-        let deficit = target_edges.saturating_sub(edges);
-        let l = deficit.min(10);
+        // 3) How many edges short
+        self.update_tenures_for_deficit(size_s, target_edges.saturating_sub(edges), rng);
+    }
+
+    /// [`DualTabu::update_tenures`] from steps 4–7, given the deficit in
+    /// whatever unit the score uses (arcs, weight, …).
+    pub fn update_tenures_for_deficit<R: Rng + ?Sized>(&mut self, size_s: usize, deficit: usize, rng: &mut R) {
+        // capped at 10 (or the scaled schedules):
+        let l = match self.schedule {
+            TenureSchedule::Deficit => deficit.min(10),
+            TenureSchedule::SqrtN(f) => (f * (self.expiry_u.len() as f64).sqrt()).ceil() as usize,
+            TenureSchedule::ProportionalK(f) => (f * size_s as f64).ceil() as usize,
+        };
 
         // 4) Base C = max(size_s/40, 6):
         let c = (size_s / 40).max(6);
@@ -104,21 +144,69 @@ impl DualTabu {
     #[inline]
     pub fn forbid_u(&mut self, v: usize) {
         self.expiry_u[v] = self.iter + self.tu;
+        self.horizon = self.horizon.max(self.expiry_u[v]);
     }
 
     /// Forbid removing `v` for the next `tv` iterations.
     #[inline]
     pub fn forbid_v(&mut self, v: usize) {
         self.expiry_v[v] = self.iter + self.tv;
+        self.horizon = self.horizon.max(self.expiry_v[v]);
+    }
+
+    /// Forbid re-adding `v` for the next `tenure` iterations.
+    #[inline]
+    pub fn forbid_u_for(&mut self, v: usize, tenure: usize) {
+        self.expiry_u[v] = self.iter + tenure;
+        self.horizon = self.horizon.max(self.expiry_u[v]);
+    }
+
+    /// Forbid removing `v` for the next `tenure` iterations.
+    #[inline]
+    pub fn forbid_v_for(&mut self, v: usize, tenure: usize) {
+        self.expiry_v[v] = self.iter + tenure;
+        self.horizon = self.horizon.max(self.expiry_v[v]);
+    }
+
+    /// Current tenures (Tu, Tv).
+    #[inline]
+    pub fn tenures(&self) -> (usize, usize) {
+        (self.tu, self.tv)
     }
 
-    /// Clear all tabu marks (used after a heavy/mild perturbation).
+    /// Number of vertices currently tabu for (re-addition, removal).  O(n).
+    pub fn active_counts(&self) -> (usize, usize) {
+        let u = self.expiry_u.iter().filter(|&&e| e > self.iter).count();
+        let v = self.expiry_v.iter().filter(|&&e| e > self.iter).count();
+        (u, v)
+    }
+
+    /// Moves until each vertex may be re-added and removed again (0 = not
+    /// tabu).  O(n).
+    pub fn remaining(&self) -> (Vec<usize>, Vec<usize>) {
+        let left = |e: &Vec<usize>| e.iter().map(|&e| e.saturating_sub(self.iter)).collect();
+        (left(&self.expiry_u), left(&self.expiry_v))
+    }
+
+    /// Clear all tabu marks (used after a heavy/mild perturbation).  O(1):
+    /// jumps the iteration clock to the latest expiry.
     pub fn reset(&mut self) {
-        self.expiry_u.fill(0);
-        self.expiry_v.fill(0);
+        self.iter = self.iter.max(self.horizon);
     }
 }
 
+/// Tenure adjusted for a move that changed m(S) by `delta`: scaled by
+/// (1 + `scale`) for deteriorating moves and by (1 − `scale`) for improving
+/// ones (never below 1).  `scale = 0` returns `tenure` unchanged.
+pub fn scale_tenure(tenure: usize, delta: isize, scale: f64) -> usize {
+    let f = match delta.signum() {
+        -1 => 1.0 + scale,
+        1 => 1.0 - scale,
+        _ => return tenure,
+    };
+    ((tenure as f64 * f).round() as usize).max(1)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -149,4 +237,42 @@ mod tests {
         assert!(!t.is_tabu_u(1));
         assert!(!t.is_tabu_v(2));
     }
+
+    #[test]
+    fn reset_is_lazy() {
+        let mut t = DualTabu::new(3, 5, 7);
+        t.forbid_u(0);
+        t.forbid_v(1);
+        t.reset();
+        assert_eq!(t.active_counts(), (0, 0));
+        t.forbid_u(2);
+        assert!(t.is_tabu_u(2));
+        assert!(!t.is_tabu_u(0) && !t.is_tabu_v(1));
+    }
+
+    #[test]
+    fn delta_scaled_tenures() {
+        assert_eq!(scale_tenure(10, -3, 0.5), 15);
+        assert_eq!(scale_tenure(10, 2, 0.5), 5);
+        assert_eq!(scale_tenure(10, 0, 0.5), 10);
+        assert_eq!(scale_tenure(1, 1, 0.9), 1);
+        assert_eq!(scale_tenure(7, -1, 0.0), 7);
+    }
+
+    #[test]
+    fn scaled_schedules() {
+        use rand::SeedableRng;
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+
+        // n = 400 → l = ⌈0.5·20⌉ = 10, so Tu ∈ [11, 17)
+        let mut t = DualTabu::new(400, 1, 1).with_schedule(TenureSchedule::SqrtN(0.5));
+        t.update_tenures(50, 0, 0.9, &mut rng);
+        assert!((11..17).contains(&t.tenures().0));
+
+        // k = 200, l = 40 even though the deficit is 0
+        let mut t = DualTabu::new(400, 1, 1).with_schedule(TenureSchedule::ProportionalK(0.2));
+        t.update_tenures(200, 200 * 199 / 2, 0.9, &mut rng);
+        let (tu, tv) = t.tenures();
+        assert!(tu >= 41 && tv >= 24);
+    }
 }
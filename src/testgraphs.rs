@@ -0,0 +1,30 @@
+// src/testgraphs.rs
+//! Small graphs shared by the unit tests (and, through `#[path]`, by
+//! `tests/smoke.rs`).
+
+use crate::Graph;
+use std::ops::Range;
+
+/// Every pair of `vs`.
+pub fn clique_edges(vs: Range<usize>) -> Vec<(usize, usize)> {
+    vs.clone().flat_map(|u| (u + 1..vs.end).map(move |v| (u, v))).collect()
+}
+
+/// The cycle 0-1-…-(n−1)-0.
+pub fn cycle_edges(n: usize) -> Vec<(usize, usize)> {
+    (0..n).map(|v| (v, (v + 1) % n)).collect()
+}
+
+/// K5 on 0..5 with a pendant path 4-5-6.
+pub fn k5_with_tail() -> Graph {
+    let mut edges = clique_edges(0..5);
+    edges.extend([(4, 5), (5, 6)]);
+    Graph::from_edge_list(7, &edges)
+}
+
+/// 40 vertices, u–v unless u·v + u + v ≡ 0 (mod 3): dense, but with no
+/// clique above the greedy bound.
+pub fn mod3_graph() -> Graph {
+    let edges: Vec<_> = clique_edges(0..40).into_iter().filter(|&(u, v)| (u * v + u + v) % 3 != 0).collect();
+    Graph::from_edge_list(40, &edges)
+}
@@ -0,0 +1,81 @@
+// src/threads.rs
+//! The rayon pools behind the crate's parallel features (parallel starts,
+//! constructor evaluation, chunked DIMACS parsing).
+//!
+//! The crate never runs on rayon's global pool, so applications that
+//! manage their own parallelism keep control of it:
+//!
+//! • [`set_threads`] sizes the shared pool; by default it has one thread
+//!   per physical core.
+//! • `Params::threads` > 0 runs one solve's parallel work on a pool of
+//!   that size instead (one pool per size, built on first use).
+//! • [`install`] runs a closure on either, e.g. to spread seeds.
+
+use rayon::{ThreadPool, ThreadPoolBuildError, ThreadPoolBuilder};
+use std::sync::{Arc, Mutex, RwLock};
+
+static SHARED: RwLock<Option<Arc<ThreadPool>>> = RwLock::new(None);
+static SIZED: Mutex<Vec<Arc<ThreadPool>>> = Mutex::new(Vec::new());
+
+/// Pool of `n` threads (0 = physical cores).
+fn build(n: usize) -> Result<ThreadPool, ThreadPoolBuildError> {
+    let n = if n == 0 { num_cpus::get_physical() } else { n };
+    ThreadPoolBuilder::new().num_threads(n).thread_name(|i| format!("tsqc-{i}")).build()
+}
+
+/// Replace the shared pool by one of `n` threads (0 = one per physical
+/// core).  Work already running keeps its old pool.
+pub fn set_threads(n: usize) -> Result<(), ThreadPoolBuildError> {
+    let pool = Arc::new(build(n)?);
+    *SHARED.write().unwrap() = Some(pool);
+    Ok(())
+}
+
+/// Threads in the shared pool.
+pub fn threads() -> usize {
+    pool(0).current_num_threads()
+}
+
+/// The pool for `threads` (0 = the shared pool).
+pub fn pool(threads: usize) -> Arc<ThreadPool> {
+    if threads == 0 {
+        if let Some(p) = SHARED.read().unwrap().as_ref() {
+            return Arc::clone(p);
+        }
+        let mut shared = SHARED.write().unwrap();
+        return Arc::clone(shared.get_or_insert_with(|| Arc::new(build(0).expect("building the default pool"))));
+    }
+    let mut sized = SIZED.lock().unwrap();
+    if let Some(p) = sized.iter().find(|p| p.current_num_threads() == threads) {
+        return Arc::clone(p);
+    }
+    let p = Arc::new(build(threads).expect("building a sized pool"));
+    sized.push(Arc::clone(&p));
+    p
+}
+
+/// Run `op` on the pool for `threads` (0 = the shared pool); rayon
+/// calls inside it use that pool.  With 0, work already on a pool's
+/// worker stays there, so nested parallel features share the outer pool.
+pub fn install<OP, R>(threads: usize, op: OP) -> R
+where
+    OP: FnOnce() -> R + Send,
+    R: Send,
+{
+    if threads == 0 && rayon::current_thread_index().is_some() {
+        return op();
+    }
+    pool(threads).install(op)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sized_pools_are_reused() {
+        assert_eq!(install(3, rayon::current_num_threads), 3);
+        assert!(Arc::ptr_eq(&pool(3), &pool(3)));
+        assert!(threads() >= 1);
+    }
+}
@@ -0,0 +1,70 @@
+// src/traverse.rs
+//! Breadth-first utilities on [`Graph`].
+//!
+//! • `bfs_distances` – hop distance from a source (bounded or full).
+//! • `ball`          – vertices within a given radius.
+//! • `ego_network`   – induced subgraph of a ball, with index mapping.
+
+use crate::graph::Graph;
+use std::collections::VecDeque;
+
+impl Graph {
+    /// Hop distance from `src` to every vertex (`None` if unreachable),
+    /// exploring at most `max_depth` levels.
+    pub fn bfs_distances_within(&self, src: usize, max_depth: usize) -> Vec<Option<usize>> {
+        let mut dist = vec![None; self.n()];
+        let mut queue = VecDeque::new();
+        dist[src] = Some(0);
+        queue.push_back(src);
+        while let Some(v) = queue.pop_front() {
+            let d = dist[v].unwrap();
+            if d == max_depth {
+                continue;
+            }
+            for w in self.neighbors(v) {
+                if dist[w].is_none() {
+                    dist[w] = Some(d + 1);
+                    queue.push_back(w);
+                }
+            }
+        }
+        dist
+    }
+
+    /// Hop distance from `src` to every vertex (`None` if unreachable).
+    pub fn bfs_distances(&self, src: usize) -> Vec<Option<usize>> {
+        self.bfs_distances_within(src, usize::MAX)
+    }
+
+    /// All vertices at distance ≤ `radius` from `v` (including `v`), ascending.
+    pub fn ball(&self, v: usize, radius: usize) -> Vec<usize> {
+        self.bfs_distances_within(v, radius)
+            .iter()
+            .enumerate()
+            .filter_map(|(w, d)| d.map(|_| w))
+            .collect()
+    }
+
+    /// Induced subgraph of [`Graph::ball`]; `map[i]` is the original index
+    /// of new vertex `i`.
+    pub fn ego_network(&self, v: usize, radius: usize) -> (Graph, Vec<usize>) {
+        let verts = self.ball(v, radius);
+        (self.induced_subgraph(&verts), verts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distances_and_balls_on_path() {
+        // path 0-1-2-3 plus isolated 4
+        let g = Graph::from_edge_list(5, &[(0, 1), (1, 2), (2, 3)]);
+        assert_eq!(g.bfs_distances(0), vec![Some(0), Some(1), Some(2), Some(3), None]);
+        assert_eq!(g.ball(1, 1), vec![0, 1, 2]);
+        let (ego, map) = g.ego_network(3, 2);
+        assert_eq!(map, vec![1, 2, 3]);
+        assert_eq!(ego.m(), 2);
+    }
+}
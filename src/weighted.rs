@@ -0,0 +1,326 @@
+// src/weighted.rs
+//! Quasi-cliques in edge-weighted graphs (e.g. co-expression networks
+//! with edge confidences).
+//!
+//! A [`WeightedGraph`] keeps the unweighted [`Graph`] of its edges plus a
+//! weight per edge.  The weighted density of a set S is
+//!
+//! ```text
+//! ρ_w(S) = W(S) / C(|S|,2)          W(S) = Σ w(u,v) over edges inside S
+//! ```
+//!
+//! and must reach γ.  With weights in [0, 1], ρ_w ≤ ρ, and unit weights
+//! give the plain γ-quasi-clique.
+//!
+//! • [`solve_weighted_k`]: the tabu search of `setsearch` on W, where a
+//!   vertex weighs the sum of its edge weights into S.
+//! • [`solve_weighted_maxk`]: grows k until more than `p.maxk_lookahead`
+//!   consecutive sizes fail.
+
+use crate::{
+    graph::Graph,
+    observer::{NoObserver, Observer, StopReason},
+    params::Params,
+    setsearch::{self, ScoredSet},
+    solution::Solution,
+};
+use rand::Rng;
+use std::io::{self, BufRead};
+use std::path::Path;
+
+/// Undirected graph whose edges carry a non-negative weight.
+#[derive(Clone, Debug)]
+pub struct WeightedGraph {
+    graph: Graph,
+    /// Per vertex, `(neighbour, weight)` ascending by neighbour.
+    adj:   Vec<Vec<(u32, f64)>>,
+}
+
+impl WeightedGraph {
+    /// Graph on `n` vertices from `(u, v, weight)` triples.  Self-loops and
+    /// zero weights are ignored; a repeated edge keeps its largest weight.
+    ///
+    /// Panics if a weight is negative or not finite.
+    pub fn from_weighted_edges(n: usize, edges: &[(usize, usize, f64)]) -> Self {
+        let mut adj: Vec<Vec<(u32, f64)>> = vec![Vec::new(); n];
+        for &(u, v, w) in edges {
+            assert!(w.is_finite() && w >= 0.0, "edge weight must be finite and non-negative");
+            if u != v && w > 0.0 {
+                adj[u].push((v as u32, w));
+                adj[v].push((u as u32, w));
+            }
+        }
+        let mut plain = Vec::new();
+        for (u, row) in adj.iter_mut().enumerate() {
+            // largest weight first among equal neighbours, then dedup keeps it
+            row.sort_unstable_by(|a, b| a.0.cmp(&b.0).then(b.1.total_cmp(&a.1)));
+            row.dedup_by_key(|e| e.0);
+            plain.extend(row.iter().filter(|e| e.0 as usize > u).map(|e| (u, e.0 as usize)));
+        }
+        Self { graph: Graph::from_edge_list(n, &plain), adj }
+    }
+
+    /// Parse a whitespace-separated `u v weight` edge list (0-based ids).
+    /// Lines starting with `#` or `%` are comments; n is the largest id + 1.
+    pub fn parse_edge_list<R: BufRead>(reader: R) -> io::Result<Self> {
+        let invalid = |line: &str| {
+            io::Error::new(io::ErrorKind::InvalidData, format!("malformed weighted edge: '{line}'"))
+        };
+        let mut edges = Vec::new();
+        let mut n = 0;
+        for line in reader.lines() {
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with('%') {
+                continue;
+            }
+            let mut it = line.split_whitespace();
+            let (u, v, w) = match (it.next(), it.next(), it.next()) {
+                (Some(u), Some(v), Some(w)) => (u, v, w),
+                _ => return Err(invalid(line)),
+            };
+            let u: usize = u.parse().map_err(|_| invalid(line))?;
+            let v: usize = v.parse().map_err(|_| invalid(line))?;
+            let w: f64 = w.parse().map_err(|_| invalid(line))?;
+            if !w.is_finite() || w < 0.0 {
+                return Err(invalid(line));
+            }
+            n = n.max(u + 1).max(v + 1);
+            edges.push((u, v, w));
+        }
+        Ok(Self::from_weighted_edges(n, &edges))
+    }
+
+    /// [`WeightedGraph::parse_edge_list`] on a file.
+    pub fn read_edge_list<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::parse_edge_list(io::BufReader::new(std::fs::File::open(path)?))
+    }
+
+    pub fn n(&self) -> usize {
+        self.graph.n()
+    }
+
+    /// The edges without weights; solver results are [`Solution`]s over
+    /// this graph.
+    pub fn underlying(&self) -> &Graph {
+        &self.graph
+    }
+
+    /// w(u, v), 0 without an edge.
+    pub fn weight(&self, u: usize, v: usize) -> f64 {
+        let row = &self.adj[u];
+        row.binary_search_by_key(&(v as u32), |e| e.0).map_or(0.0, |i| row[i].1)
+    }
+
+    /// W(S) of an arbitrary vertex list (duplicates are ignored).
+    pub fn weight_of(&self, vertices: &[usize]) -> f64 {
+        let mut distinct = vertices.to_vec();
+        distinct.sort_unstable();
+        distinct.dedup();
+        let mut total = 0.0;
+        for &u in &distinct {
+            total += self.adj[u]
+                .iter()
+                .filter(|e| e.0 as usize > u && distinct.binary_search(&(e.0 as usize)).is_ok())
+                .map(|e| e.1)
+                .sum::<f64>();
+        }
+        total
+    }
+
+    /// ρ_w(S) = W(S) / C(|S|,2); 0 for fewer than two vertices.
+    pub fn weighted_density(&self, vertices: &[usize]) -> f64 {
+        let mut distinct = vertices.to_vec();
+        distinct.sort_unstable();
+        distinct.dedup();
+        let k = distinct.len();
+        if k < 2 { 0.0 }
+        else { 2.0 * self.weight_of(&distinct) / (k * (k - 1)) as f64 }
+    }
+}
+
+impl Solution<'_> {
+    /// ρ_w of the members under the weights of `graph` (a graph over the
+    /// same vertices, normally the one whose
+    /// [`underlying`](WeightedGraph::underlying) this solution lives on).
+    pub fn weighted_density(&self, graph: &WeightedGraph) -> f64 {
+        graph.weighted_density(&self.bitset().iter_ones().collect::<Vec<_>>())
+    }
+}
+
+/// Vertex set with W(S) and the weight of every vertex into it.
+#[derive(Clone)]
+struct Weighted<'g> {
+    graph: &'g WeightedGraph,
+    sol:   Solution<'g>,
+    into:  Vec<f64>,
+    total: f64,
+}
+
+impl<'g> Weighted<'g> {
+    fn new(graph: &'g WeightedGraph) -> Self {
+        Self { graph, sol: Solution::new(&graph.graph), into: vec![0.0; graph.n()], total: 0.0 }
+    }
+
+    fn shift(&mut self, v: usize, sign: f64) {
+        for &(u, w) in &self.graph.adj[v] {
+            self.into[u as usize] += sign * w;
+        }
+    }
+}
+
+impl<'g> ScoredSet<'g> for Weighted<'g> {
+    fn n(&self) -> usize {
+        self.graph.n()
+    }
+
+    fn contains(&self, v: usize) -> bool {
+        self.sol.bitset()[v]
+    }
+
+    fn size(&self) -> usize {
+        self.sol.size()
+    }
+
+    fn add(&mut self, v: usize) {
+        self.total += self.into[v];
+        self.sol.add(v);
+        self.shift(v, 1.0);
+    }
+
+    fn remove(&mut self, v: usize) {
+        self.sol.remove(v);
+        self.shift(v, -1.0);
+        self.total -= self.into[v];
+        if self.sol.size() < 2 {
+            // no edge left inside: drop the rounding drift
+            self.total = 0.0;
+        }
+    }
+
+    fn score(&self) -> f64 {
+        self.total
+    }
+
+    /// Σ w(v, u) over the members u.
+    fn weight(&self, v: usize) -> f64 {
+        self.into[v]
+    }
+
+    fn pair(&self, u: usize, v: usize) -> f64 {
+        self.graph.weight(u, v)
+    }
+
+    fn solution(&self) -> &Solution<'g> {
+        &self.sol
+    }
+}
+
+/// W(S) a k-set needs for weighted density γ.
+fn target(k: usize, gamma: f64) -> f64 {
+    gamma * (k * k.saturating_sub(1) / 2) as f64
+}
+
+/// Best set of size `k` by total edge weight; returned as a [`Solution`]
+/// over [`WeightedGraph::underlying`] (empty if k is out of range).
+pub fn solve_weighted_k<'g, R>(graph: &'g WeightedGraph, k: usize, rng: &mut R, p: &Params) -> Solution<'g>
+where
+    R: Rng + ?Sized,
+{
+    solve_weighted_k_with(graph, k, rng, p, &mut NoObserver)
+}
+
+/// [`solve_weighted_k`] reporting incumbents, honouring `should_stop` and
+/// reporting the stop reason to `obs`.
+pub fn solve_weighted_k_with<'g, R, O>(
+    graph: &'g WeightedGraph,
+    k: usize,
+    rng: &mut R,
+    p: &Params,
+    obs: &mut O,
+) -> Solution<'g>
+where
+    R: Rng + ?Sized,
+    O: Observer + ?Sized,
+{
+    if k < 2 || k > graph.n() {
+        obs.on_finish(StopReason::Infeasible);
+        return Solution::new(&graph.graph);
+    }
+    setsearch::solve_k(&Weighted::new(graph), k, target(k, p.gamma_target), rng, p, obs).sol
+}
+
+/// Largest set whose weighted density reaches γ.
+pub fn solve_weighted_maxk<'g, R>(graph: &'g WeightedGraph, rng: &mut R, p: &Params) -> Solution<'g>
+where
+    R: Rng + ?Sized,
+{
+    solve_weighted_maxk_with(graph, rng, p, &mut NoObserver)
+}
+
+/// [`solve_weighted_maxk`] with an observer (feasible incumbents only).
+pub fn solve_weighted_maxk_with<'g, R, O>(graph: &'g WeightedGraph, rng: &mut R, p: &Params, obs: &mut O) -> Solution<'g>
+where
+    R: Rng + ?Sized,
+    O: Observer + ?Sized,
+{
+    setsearch::solve_maxk(&Weighted::new(graph), |k| target(k, p.gamma_target), rng, p, obs).sol
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testgraphs::clique_edges;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn weights_decide_between_cliques() {
+        // two K5s, {0..5} with confident edges and {5..10} with weak ones
+        let mut edges: Vec<(usize, usize, f64)> = [(0..5, 0.9), (5..10, 0.3)]
+            .into_iter()
+            .flat_map(|(vs, w)| clique_edges(vs).into_iter().map(move |(u, v)| (u, v, w)))
+            .collect();
+        edges.push((4, 5, 1.0));
+        let g = WeightedGraph::from_weighted_edges(10, &edges);
+        assert_eq!(g.underlying().m(), 21);
+        assert_eq!(g.weight(5, 4), 1.0);
+        approx::assert_relative_eq!(g.weighted_density(&[0, 1, 2]), 0.9);
+
+        let p = Params { gamma_target: 0.8, max_iter: 5_000, stagnation_iter: 20, ..Params::default() };
+        let sol = solve_weighted_maxk(&g, &mut ChaCha8Rng::seed_from_u64(0), &p);
+        assert_eq!(sol.bitset().iter_ones().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4]);
+        approx::assert_relative_eq!(sol.weighted_density(&g), 0.9);
+
+        let sol = solve_weighted_k(&g, 5, &mut ChaCha8Rng::seed_from_u64(0), &Params { gamma_target: 1.0, ..p });
+        assert_eq!(sol.bitset().iter_ones().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn time_limit_bounds_the_search() {
+        // weak edges only: no pair reaches γ, every size runs to the limit
+        let edges: Vec<(usize, usize, f64)> = clique_edges(0..30).into_iter().map(|(u, v)| (u, v, 0.1)).collect();
+        let g = WeightedGraph::from_weighted_edges(30, &edges);
+        let p = Params {
+            gamma_target: 0.9,
+            max_iter: usize::MAX,
+            time_limit: Some(std::time::Duration::from_millis(50)),
+            ..Params::default()
+        };
+        let started = std::time::Instant::now();
+        let sol = solve_weighted_maxk(&g, &mut ChaCha8Rng::seed_from_u64(0), &p);
+        assert_eq!(sol.size(), 0);
+        assert!(started.elapsed() < std::time::Duration::from_secs(5));
+    }
+
+    #[test]
+    fn parse_weighted_edge_list() {
+        let text = "# u v w\n0 1 0.5\n1 2 0.25\n1 0 0.75\n2 2 1\n";
+        let g = WeightedGraph::parse_edge_list(text.as_bytes()).unwrap();
+        assert_eq!((g.n(), g.underlying().m()), (3, 2));
+        assert_eq!(g.weight(0, 1), 0.75);
+        assert_eq!(g.weight(0, 2), 0.0);
+        assert!(WeightedGraph::parse_edge_list("0 1\n".as_bytes()).is_err());
+        assert!(WeightedGraph::parse_edge_list("0 1 -1\n".as_bytes()).is_err());
+    }
+}
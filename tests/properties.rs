@@ -0,0 +1,154 @@
+//! Property tests: parser round-trips and `Solution` bookkeeping against
+//! brute-force recounts, on random graphs and random move sequences.
+
+use proptest::prelude::*;
+use std::io::Cursor;
+use tsqc::{density_of, edges_of, npy, parse, Graph, GraphBuilder, Layout, Solution};
+
+/// Random graph: n ≤ 40 and an edge list that may repeat edges, reverse
+/// them or contain self-loops.
+fn graphs() -> impl Strategy<Value = (usize, Vec<(usize, usize)>)> {
+    (1usize..40).prop_flat_map(|n| (Just(n), prop::collection::vec((0..n, 0..n), 0..200)))
+}
+
+/// Distinct non-loop edges as (min, max), ascending.
+fn normalised(edges: &[(usize, usize)]) -> Vec<(usize, usize)> {
+    let mut e: Vec<_> = edges.iter().filter(|e| e.0 != e.1).map(|&(u, v)| (u.min(v), u.max(v))).collect();
+    e.sort_unstable();
+    e.dedup();
+    e
+}
+
+fn dimacs(n: usize, edges: &[(usize, usize)]) -> String {
+    let mut text = format!("c random\np edge {n} {}\n", edges.len());
+    for &(u, v) in edges {
+        text.push_str(&format!("e {} {}\n", u + 1, v + 1));
+    }
+    text
+}
+
+#[derive(Clone, Debug)]
+enum Move {
+    Add(usize),
+    Remove(usize),
+    Toggle(usize),
+    Clear,
+    Checkpoint,
+    Rollback,
+    Commit,
+    TrackFrontier,
+}
+
+fn moves(n: usize) -> impl Strategy<Value = Vec<Move>> {
+    let mv = prop_oneof![
+        4 => (0..n).prop_map(Move::Add),
+        3 => (0..n).prop_map(Move::Remove),
+        3 => (0..n).prop_map(Move::Toggle),
+        1 => Just(Move::Clear),
+        2 => Just(Move::Checkpoint),
+        2 => Just(Move::Rollback),
+        1 => Just(Move::Commit),
+        1 => Just(Move::TrackFrontier),
+    ];
+    prop::collection::vec(mv, 0..120)
+}
+
+/// Every cached quantity of `sol` equals its recount from the bitset.
+fn assert_consistent(sol: &Solution<'_>) {
+    let g = sol.graph();
+    let members: Vec<usize> = sol.bitset().iter_ones().collect();
+    assert_eq!(sol.size(), members.len());
+    assert_eq!(sol.edges(), edges_of(g, &members));
+    assert_eq!(sol.density(), density_of(g, &members));
+
+    let fresh = Solution::from_bitset(g, sol.bitset());
+    assert_eq!(sol.edges(), fresh.edges());
+    assert_eq!(sol.zobrist(), fresh.zobrist());
+
+    let degrees = sol.internal_degrees();
+    for (v, &d) in degrees.iter().enumerate() {
+        let brute = members.iter().filter(|&&u| g.has_edge(u, v)).count();
+        assert_eq!(sol.deg_in(v), brute);
+        assert_eq!(d as usize, brute);
+    }
+    if let Some(front) = sol.frontier() {
+        let mut front = front.to_vec();
+        front.sort_unstable();
+        let brute: Vec<usize> = (0..g.n()).filter(|&v| !sol.bitset()[v] && degrees[v] > 0).collect();
+        assert_eq!(front, brute);
+    }
+}
+
+proptest! {
+    #[test]
+    fn dimacs_round_trip((n, edges) in graphs()) {
+        let want = normalised(&edges);
+        let text = dimacs(n, &edges);
+
+        let seq = Graph::parse_dimacs(Cursor::new(text.as_bytes())).unwrap();
+        prop_assert_eq!(seq.n(), n);
+        prop_assert_eq!(seq.edge_list(), want.clone());
+        prop_assert_eq!(seq.m(), want.len());
+
+        for layout in [Layout::Rows, Layout::Triangular] {
+            let par = parse::parse_dimacs_bytes_with(text.as_bytes(), layout).unwrap();
+            prop_assert_eq!(par.n(), n);
+            prop_assert_eq!(par.edge_list(), want.clone());
+            for v in 0..n {
+                prop_assert_eq!(par.degree(v), seq.degree(v));
+            }
+        }
+
+        // the written list parses back to the same graph
+        let again = parse::parse_dimacs_bytes(dimacs(n, &seq.edge_list()).as_bytes()).unwrap();
+        prop_assert_eq!(again.edge_list(), want);
+    }
+
+    #[test]
+    fn npz_round_trip((n, edges) in graphs()) {
+        let g = Graph::from_edge_list(n, &normalised(&edges));
+        let mut buf = Cursor::new(Vec::new());
+        npy::write_npz(&g, &mut buf).unwrap();
+        buf.set_position(0);
+        let back = npy::read_npz(buf).unwrap();
+        prop_assert_eq!(back.n(), n);
+        prop_assert_eq!(back.edge_list(), g.edge_list());
+    }
+
+    #[test]
+    fn builder_matches_edge_list((n, edges) in graphs()) {
+        let mut b = GraphBuilder::new();
+        b.vertices(n).extend(edges.iter().copied());
+        let g = b.build();
+        prop_assert_eq!(g.edge_list(), normalised(&edges));
+        prop_assert_eq!(g.m(), Graph::from_edge_list(n, &normalised(&edges)).m());
+    }
+
+    #[test]
+    fn solution_bookkeeping(((n, edges), ops) in graphs().prop_flat_map(|(n, e)| (Just((n, e)), moves(n)))) {
+        let g = Graph::from_edge_list(n, &normalised(&edges));
+        let mut sol = Solution::new(&g);
+        // shadow copy of the bitset, replaying the journal semantics
+        let mut stack: Vec<Vec<bool>> = Vec::new();
+        let mut shadow = vec![false; n];
+        for op in ops {
+            match op {
+                Move::Add(v) => { sol.add(v); shadow[v] = true; }
+                Move::Remove(v) => { sol.remove(v); shadow[v] = false; }
+                Move::Toggle(v) => { prop_assert_eq!(sol.toggle(v), !shadow[v]); shadow[v] = !shadow[v]; }
+                Move::Clear => { sol.clear(); shadow.fill(false); }
+                Move::Checkpoint => { sol.checkpoint(); stack.push(shadow.clone()); }
+                Move::Rollback => {
+                    let open = stack.pop();
+                    prop_assert_eq!(sol.rollback(), open.is_some());
+                    if let Some(saved) = open { shadow = saved; }
+                }
+                Move::Commit => { prop_assert_eq!(sol.commit(), stack.pop().is_some()); }
+                Move::TrackFrontier => sol.track_frontier(),
+            }
+            prop_assert_eq!(sol.depth(), stack.len());
+            prop_assert!(sol.bitset().iter().by_vals().eq(shadow.iter().copied()));
+            assert_consistent(&sol);
+        }
+    }
+}
@@ -2,6 +2,11 @@ use tsqc::{Graph, Params, solve_fixed_k};
 use rand_chacha::ChaCha8Rng;
 use rand::SeedableRng;
 
+#[path = "../src/testgraphs.rs"]
+#[allow(dead_code)]
+mod testgraphs;
+use testgraphs::{clique_edges, cycle_edges};
+
 #[test]
 fn smoke_fixed_k() {
     // 5-vertex complete graph minus one edge (2-3 missing)
@@ -19,4 +24,177 @@ fn smoke_fixed_k() {
     // Solver should reach at least the original 0.9 density,
     // and may reach 1.0 after improving the edge set.
     assert!(sol.density() >= 0.9);
+
+    // same instance with frontier-restricted scans
+    let p = Params { track_frontier: true, ..Params::default() };
+    let sol = solve_fixed_k(&g, 5, &mut rng, &p);
+    assert!(sol.density() >= 0.9);
+}
+
+#[test]
+fn smoke_restart_records() {
+    use tsqc::observer::{Observer, RestartRecord, StopReason};
+
+    #[derive(Default)]
+    struct Restarts(Vec<RestartRecord>, Option<StopReason>);
+    impl Observer for Restarts {
+        fn on_restart(&mut self, rec: &RestartRecord) { self.0.push(rec.clone()); }
+        fn on_finish(&mut self, reason: StopReason) { self.1 = Some(reason); }
+    }
+
+    let edges = vec![(0,1),(0,2),(1,2),(2,3),(3,4)];
+    let g = Graph::from_edge_list(5, &edges);
+    let mut rng = ChaCha8Rng::seed_from_u64(3);
+    let mut obs = Restarts::default();
+    let sol = tsqc::solve_fixed_k_with(&g, 3, &mut rng, &Params::default(), &mut obs);
+
+    assert!(sol.density() >= 0.9);
+    let last = obs.0.last().expect("at least one restart record");
+    assert!(last.seed_vertex < 5);
+    assert!(last.best_density >= last.start_density);
+    assert_eq!(obs.1, Some(StopReason::Feasible));
+}
+
+#[test]
+fn smoke_restart_bests() {
+    // path 0-1-2-3-4-5: no 4-vertex 0.9-quasi-clique, so every restart runs
+    let edges = vec![(0,1),(1,2),(2,3),(3,4),(4,5)];
+    let g = Graph::from_edge_list(6, &edges);
+    let p = Params { stagnation_iter: 10, max_iter: 2_000, restart_bests: 3, ..Params::default() };
+    let mut rng = ChaCha8Rng::seed_from_u64(7);
+    let (best, bests) = tsqc::solve_fixed_k_all(&g, 4, &mut rng, &p);
+
+    assert_eq!(bests.len(), 3);
+    for s in &bests {
+        assert_eq!(s.size(), 4);
+        assert!(s.density() <= best.density());
+    }
+}
+
+#[test]
+fn smoke_interrupt() {
+    use tsqc::observer::{Observer, StopReason};
+
+    // stops after 50 polls; records the stop reason
+    struct Budget(usize, Option<StopReason>);
+    impl Observer for Budget {
+        fn should_stop(&mut self) -> bool {
+            self.0 = self.0.saturating_sub(1);
+            self.0 == 0
+        }
+        fn on_finish(&mut self, reason: StopReason) { self.1 = Some(reason); }
+    }
+
+    let edges = vec![(0,1),(1,2),(2,3),(3,4),(4,5)];
+    let g = Graph::from_edge_list(6, &edges);
+    let mut rng = ChaCha8Rng::seed_from_u64(7);
+    let mut obs = Budget(50, None);
+    let sol = tsqc::solve_fixed_k_with(&g, 4, &mut rng, &Params::default(), &mut obs);
+    assert_eq!(sol.size(), 4);
+    assert_eq!(obs.1, Some(StopReason::Interrupted));
+
+    let mut obs = Budget(50, None);
+    tsqc::solve_maxk_with(&g, &mut rng, &Params::default(), &mut obs);
+    assert!(matches!(obs.1, Some(StopReason::Interrupted | StopReason::Bound)));
+}
+
+#[test]
+fn smoke_refine_from_set() {
+    // triangle 0-1-2 plus pendant 3; start from the sparse set {0, 3, 2}
+    let g = Graph::from_edge_list(4, &[(0,1),(1,2),(0,2),(2,3)]);
+    let mut start = tsqc::Solution::new(&g);
+    for v in [0, 2, 3] {
+        start.add(v);
+    }
+    let mut rng = ChaCha8Rng::seed_from_u64(2);
+    let p = Params { gamma_target: 1.0, ..Params::default() };
+    let sol = tsqc::solve_fixed_k_from(start, &mut rng, &p, &mut tsqc::observer::NoObserver);
+    assert_eq!(sol.size(), 3);
+    assert_eq!(sol.edges(), 3);
+}
+
+#[test]
+fn smoke_min_degree_floor() {
+    // 0..6: K6 minus a perfect matching (all degrees 4);
+    // 6..11: K5, plus 11 hanging off 6 (dense overall, 11 nearly isolated)
+    let mut edges: Vec<_> = clique_edges(0..6).into_iter().filter(|&(u, v)| v != u + 3).collect();
+    edges.extend(clique_edges(6..11));
+    edges.push((6, 11));
+    let g = Graph::from_edge_list(12, &edges);
+    let p = Params {
+        gamma_target: 0.7,
+        min_degree_ratio: 0.6,
+        stagnation_iter: 100,
+        ..Params::default()
+    };
+    for seed in 0..4 {
+        let sol = solve_fixed_k(&g, 6, &mut ChaCha8Rng::seed_from_u64(seed), &p);
+        assert!(sol.is_feasible(0.7, 0.6));
+        assert!(sol.min_deg_in() >= 3);
+    }
+}
+
+#[test]
+fn smoke_u1_interval() {
+    use tsqc::observer::{Observer, RestartRecord};
+
+    #[derive(Default)]
+    struct Restarts(Vec<RestartRecord>);
+    impl Observer for Restarts {
+        fn on_restart(&mut self, rec: &RestartRecord) { self.0.push(rec.clone()); }
+    }
+
+    // star: no 4-set has more than 3 edges, so U1 ends runs early; without
+    // it every run lasts until stagnation
+    let edges = vec![(0,1),(0,2),(0,3),(0,4),(0,5)];
+    let g = Graph::from_edge_list(6, &edges);
+    let moves = |u1_interval| {
+        let p = Params { gamma_target: 0.9, stagnation_iter: 50, max_iter: 500, u1_interval, ..Params::default() };
+        let mut obs = Restarts::default();
+        tsqc::solve_fixed_k_with(&g, 4, &mut ChaCha8Rng::seed_from_u64(1), &p, &mut obs);
+        obs.0[0].iterations
+    };
+    assert!(moves(1) < 50);
+    assert!(moves(8) >= 8);
+    assert!(moves(0) >= 50);
+}
+
+#[test]
+fn smoke_parallel_starts() {
+    // K6 on 0..6 hanging off a 30-cycle
+    let mut edges: Vec<_> = cycle_edges(30).into_iter().map(|(u, v)| (u + 6, v + 6)).collect();
+    edges.extend(clique_edges(0..6));
+    edges.extend((0..6).map(|u| (u, 6 + 5 * u)));
+    let g = Graph::from_edge_list(36, &edges);
+    let p = Params { gamma_target: 1.0, max_iter: 20_000, threads: 4, ..Params::default() };
+    let sol = tsqc::solve_fixed_k_parallel(&g, 6, 8, 3, &p);
+    assert_eq!(sol.bitset().iter_ones().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4, 5]);
+
+    // no triangle in the cycle: every search runs out, the densest 3-set wins
+    let ring = Graph::from_edge_list(6, &cycle_edges(6));
+    let p = Params { gamma_target: 1.0, max_iter: 200, ..Params::default() };
+    let sol = tsqc::solve_fixed_k_parallel(&ring, 3, 4, 0, &p);
+    assert_eq!((sol.size(), sol.edges()), (3, 2));
+}
+
+#[test]
+fn smoke_time_limit() {
+    // no triangle in the ring: without the limit the search runs 10⁸ moves
+    let ring = Graph::from_edge_list(6, &cycle_edges(6));
+    let p = Params { gamma_target: 1.0, time_limit: Some(std::time::Duration::from_millis(50)), ..Params::default() };
+    let started = std::time::Instant::now();
+    let sol = solve_fixed_k(&ring, 3, &mut ChaCha8Rng::seed_from_u64(0), &p);
+    assert_eq!((sol.size(), sol.edges()), (3, 2));
+    assert!(started.elapsed() < std::time::Duration::from_secs(10));
+}
+
+#[test]
+fn smoke_csr_layout() {
+    // K5 {0..5} in a 40-cycle, stored as neighbour lists
+    let mut edges = cycle_edges(40);
+    edges.extend(clique_edges(0..5));
+    let g = Graph::from_edge_list_with(40, &edges, tsqc::Layout::Csr);
+    let p = Params { gamma_target: 1.0, max_iter: 2_000, ..Params::default() };
+    let sol = tsqc::solve_maxk(&g, &mut ChaCha8Rng::seed_from_u64(0), &p);
+    assert_eq!(sol.bitset().iter_ones().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4]);
 }
@@ -4,7 +4,7 @@ Python façade for the native Rust extension.
 Import order:
 1.   import tsqc         → this file
 2.   this file imports tsqc._native (compiled pyd/so)
-3.   re-exports the three PyO3 functions at top level
+3.   re-exports the PyO3 functions at top level
 """
 
 from importlib import import_module, metadata as _md
@@ -15,12 +15,62 @@ _native = import_module("tsqc._native")
 # re-export selected symbols so callers can do:  from tsqc import solve_k_py
 solve_k_py      = _native.solve_k_py
 solve_max_py    = _native.solve_max_py
+solve_clique_py = _native.solve_clique_py
+solve_k_dense_py      = _native.solve_k_dense_py
+solve_max_dense_py    = _native.solve_max_dense_py
+solve_max_files       = _native.solve_max_files
+run_k_py              = _native.run_k_py
+run_max_py            = _native.run_max_py
+RunResult             = _native.RunResult
+Solution              = _native.Solution
+Graph                 = _native.Graph
+Solver                = _native.Solver
 parse_dimacs_py = _native.parse_dimacs_py
+sorted_degrees_py     = _native.sorted_degrees_py
+degree_percentiles_py = _native.degree_percentiles_py
+density_of_py         = _native.density_of_py
+edges_of_py           = _native.edges_of_py
+feasibility_spectrum_py = _native.feasibility_spectrum_py
+missing_links_py      = _native.missing_links_py
+estimate_stats_py     = _native.estimate_stats_py
+gamma_profile_py      = _native.gamma_profile_py
+densest_for_sizes_py  = _native.densest_for_sizes_py
+grow_profile_py       = _native.grow_profile_py
+solve_ego_py          = _native.solve_ego_py
+membership_scores_py  = _native.membership_scores_py
+solve_max_streaming_py = _native.solve_max_streaming_py
+set_threads_py        = _native.set_threads_py
+set_memory_limit_py   = _native.set_memory_limit_py
 
 __all__ = [
     "solve_k_py",
     "solve_max_py",
+    "solve_clique_py",
+    "solve_k_dense_py",
+    "solve_max_dense_py",
+    "solve_max_files",
+    "run_k_py",
+    "run_max_py",
+    "RunResult",
+    "Solution",
+    "Graph",
+    "Solver",
     "parse_dimacs_py",
+    "sorted_degrees_py",
+    "degree_percentiles_py",
+    "density_of_py",
+    "edges_of_py",
+    "feasibility_spectrum_py",
+    "missing_links_py",
+    "estimate_stats_py",
+    "gamma_profile_py",
+    "densest_for_sizes_py",
+    "grow_profile_py",
+    "solve_ego_py",
+    "membership_scores_py",
+    "solve_max_streaming_py",
+    "set_threads_py",
+    "set_memory_limit_py",
 ]
 
 __version__ = _md.version("tsqc")
@@ -51,11 +51,8 @@ def main():
         seed = args.seed + run_idx - 1
         start = time.perf_counter()
         try:
-            rho = solve_k_py(str(inst_path), args.k, args.gamma, seed)
-            # solve_k_py returns density only; for fixed-k runs size==k
-            size = args.k
-            # we need edges = density * (k*(k-1)/2) rounded
-            edges = int(round(rho * (size * (size - 1) / 2)))
+            sol = solve_k_py(str(inst_path), args.k, args.gamma, seed)
+            size, edges, rho = sol.size, sol.edges, sol.density
             elapsed = time.perf_counter() - start
 
             # print one row